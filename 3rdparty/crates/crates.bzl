@@ -339,6 +339,7 @@ _NORMAL_DEPENDENCIES = {
             "serde_json": Label("@twitter_text//serde_json-1.0.151"),
             "serde_yaml_ng": Label("@twitter_text//serde_yaml_ng-0.10.0"),
             "unicode-normalization": Label("@twitter_text//unicode-normalization-0.1.25"),
+            "unicode-segmentation": Label("@twitter_text//unicode-segmentation-1.13.3"),
             "unicode_categories": Label("@twitter_text//unicode_categories-0.1.1"),
             "wasm-bindgen": Label("@twitter_text//wasm-bindgen-0.2.121"),
         },
@@ -1833,6 +1834,16 @@ def crate_repositories():
         build_file = Label("//3rdparty/crates:BUILD.unicode-normalization-0.1.25.bazel"),
     )
 
+    maybe(
+        http_archive,
+        name = "twitter_text__unicode-segmentation-1.13.3",
+        sha256 = "c6f5d3c3b1bf09027a88a6bc961fc00497d651009560b5463668dc81b0fa87a8",
+        type = "tar.gz",
+        urls = ["https://static.crates.io/crates/unicode-segmentation/1.13.3/download"],
+        strip_prefix = "unicode-segmentation-1.13.3",
+        build_file = Label("//3rdparty/crates:BUILD.unicode-segmentation-1.13.3.bazel"),
+    )
+
     maybe(
         http_archive,
         name = "twitter_text__unicode-width-0.2.2",
@@ -2088,6 +2099,7 @@ def crate_repositories():
         struct(repo = "twitter_text__serde_json-1.0.151", is_dev_dep = False),
         struct(repo = "twitter_text__serde_yaml_ng-0.10.0", is_dev_dep = False),
         struct(repo = "twitter_text__unicode-normalization-0.1.25", is_dev_dep = False),
+        struct(repo = "twitter_text__unicode-segmentation-1.13.3", is_dev_dep = False),
         struct(repo = "twitter_text__unicode_categories-0.1.1", is_dev_dep = False),
         struct(repo = "twitter_text__wasm-bindgen-0.2.121", is_dev_dep = False),
     ]
@@ -0,0 +1,162 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::Configuration;
+use std::collections::HashMap;
+use std::ops::Deref;
+
+const BLOCK_BITS: u32 = 8;
+const BLOCK_SIZE: usize = 1 << BLOCK_BITS;
+const BLOCK_COUNT: usize = (char::MAX as usize + 1) >> BLOCK_BITS;
+// The extractor gives code points up to here the first range's weight.
+const FAST_PATH_END: i32 = 4351;
+
+/// A [Configuration] with its weighted ranges compiled into a two-level lookup
+/// table, so the weight of any code point is found in constant time.
+///
+/// The table splits the code space into blocks of 256 code points. Blocks with
+/// the same weights share storage, so a typical configuration compiles to a
+/// handful of blocks. Build it once and reuse it across calls.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledConfiguration {
+    config: Configuration,
+    index: Box<[u16]>,
+    blocks: Vec<[i32; BLOCK_SIZE]>,
+}
+
+impl CompiledConfiguration {
+    pub fn new(config: &Configuration) -> CompiledConfiguration {
+        let mut index = Vec::with_capacity(BLOCK_COUNT);
+        let mut blocks = Vec::new();
+        let mut block_ids: HashMap<[i32; BLOCK_SIZE], u16> = HashMap::new();
+        for block_number in 0..BLOCK_COUNT {
+            let block = compile_block(config, (block_number * BLOCK_SIZE) as i32);
+            let id = *block_ids.entry(block).or_insert_with(|| {
+                blocks.push(block);
+                (blocks.len() - 1) as u16
+            });
+            index.push(id);
+        }
+        CompiledConfiguration {
+            config: config.clone(),
+            index: index.into_boxed_slice(),
+            blocks,
+        }
+    }
+
+    /// The configuration this table was compiled from.
+    pub fn configuration(&self) -> &Configuration {
+        &self.config
+    }
+
+    /// The weight of a code point: the weight of the first range containing
+    /// it, or the default weight.
+    #[inline]
+    pub fn weight(&self, c: char) -> i32 {
+        let code_point = c as usize;
+        let block = self.index[code_point >> BLOCK_BITS] as usize;
+        self.blocks[block][code_point & (BLOCK_SIZE - 1)]
+    }
+
+    /// The number of distinct blocks in the table.
+    pub fn block_count(&self) -> usize {
+        self.blocks.len()
+    }
+}
+
+impl From<&Configuration> for CompiledConfiguration {
+    fn from(config: &Configuration) -> Self {
+        CompiledConfiguration::new(config)
+    }
+}
+
+impl Deref for CompiledConfiguration {
+    type Target = Configuration;
+
+    fn deref(&self) -> &Configuration {
+        &self.config
+    }
+}
+
+// The weights for the 256 code points starting at `start`, matching the
+// extractor's scan. Ranges only apply when the first one starts at 0; it then
+// covers code points 0-4351, and above that earlier ranges win, as in a linear
+// scan of `ranges`. Otherwise every code point gets the default weight.
+fn compile_block(config: &Configuration, start: i32) -> [i32; BLOCK_SIZE] {
+    let end = start + BLOCK_SIZE as i32 - 1;
+    let mut block = [config.default_weight; BLOCK_SIZE];
+    let first = match config.ranges.first() {
+        Some(first) if first.range.start() == 0 => first,
+        _ => return block,
+    };
+    for range in config.ranges.iter().rev() {
+        let from = range.range.start().max(start);
+        let to = range.range.end().min(end);
+        for code_point in from..=to {
+            block[(code_point - start) as usize] = range.weight;
+        }
+    }
+    for code_point in start..=end.min(FAST_PATH_END) {
+        block[(code_point - start) as usize] = first.weight;
+    }
+    block
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WeightedRange;
+
+    fn linear_weight(config: &Configuration, c: char) -> i32 {
+        match config.ranges.first() {
+            Some(first) if first.range.start() == 0 && c as i32 <= FAST_PATH_END => first.weight,
+            Some(first) if first.range.start() == 0 => config
+                .ranges
+                .iter()
+                .find(|r| r.contains(c as i32))
+                .map_or(config.default_weight, |r| r.weight),
+            _ => config.default_weight,
+        }
+    }
+
+    #[test]
+    fn test_matches_linear_scan() {
+        let custom = Configuration {
+            ranges: vec![
+                WeightedRange::new(0, 0x7f, 50),
+                WeightedRange::new(0x3040, 0x30ff, 150),
+                WeightedRange::new(0x1f300, 0x1f5ff, 300),
+                WeightedRange::new(0x10fff0, 0x10ffff, 0),
+            ],
+            ..Configuration::default()
+        };
+        let not_at_zero = Configuration {
+            ranges: custom.ranges[1..].to_vec(),
+            ..Configuration::default()
+        };
+        for config in [
+            crate::config_v1(),
+            crate::config_v3(),
+            &custom,
+            &not_at_zero,
+        ] {
+            let compiled = CompiledConfiguration::new(config);
+            for c in ('\0'..=char::MAX).step_by(7).chain(['\u{10ffff}', 'あ']) {
+                assert_eq!(compiled.weight(c), linear_weight(config, c), "{:?}", c);
+            }
+        }
+    }
+
+    #[test]
+    fn test_blocks_are_shared() {
+        let compiled = CompiledConfiguration::new(crate::config_v3());
+        // Weight 100, weight 200, and the block split at 8192..8447.
+        assert_eq!(compiled.block_count(), 3);
+        assert_eq!(compiled.weight('a'), 100);
+        assert_eq!(compiled.weight('\u{2010}'), 100);
+        assert_eq!(compiled.weight('\u{2030}'), 200);
+        assert_eq!(compiled.weight('中'), 200);
+        assert_eq!(compiled.max_weighted_tweet_length, 280);
+    }
+}
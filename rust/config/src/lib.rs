@@ -6,12 +6,19 @@ use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 use serde_derive::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::error::Error;
 use std::fmt;
-use std::fs::File;
-use std::io::Read;
-use std::path::PathBuf;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
+mod compiled;
+mod overlay;
+pub use compiled::CompiledConfiguration;
+pub use overlay::{ConfigurationOverlay, RangesMode};
+
 pub const DEFAULT_VERSION: i32 = 3;
 pub const DEFAULT_WEIGHTED_LENGTH: i32 = 280;
 pub const DEFAULT_SCALE: i32 = 100;
@@ -29,6 +36,8 @@ static CONFIG_V1: LazyLock<Configuration> = LazyLock::new(|| Configuration {
     transformed_url_length: 23,
     ranges: vec![],
     emoji_parsing_enabled: false,
+    length_mode: LengthMode::Weighted,
+    entity_weights: None,
 });
 
 static CONFIG_V2: LazyLock<Configuration> = LazyLock::new(|| Configuration {
@@ -44,6 +53,8 @@ static CONFIG_V2: LazyLock<Configuration> = LazyLock::new(|| Configuration {
         WeightedRange::new(8242, 8247, 100),
     ],
     emoji_parsing_enabled: false,
+    length_mode: LengthMode::Weighted,
+    entity_weights: None,
 });
 
 static CONFIG_V3: LazyLock<Configuration> = LazyLock::new(|| Configuration {
@@ -59,6 +70,8 @@ static CONFIG_V3: LazyLock<Configuration> = LazyLock::new(|| Configuration {
         WeightedRange::new(8242, 8247, 100),
     ],
     emoji_parsing_enabled: true,
+    length_mode: LengthMode::Weighted,
+    entity_weights: None,
 });
 
 pub extern "C" fn config_v1() -> &'static Configuration {
@@ -89,6 +102,54 @@ pub struct Configuration {
     pub ranges: Vec<WeightedRange>,
     #[serde(default)]
     pub emoji_parsing_enabled: bool,
+    #[serde(default, skip_serializing_if = "LengthMode::is_weighted")]
+    pub length_mode: LengthMode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_weights: Option<EntityWeights>,
+}
+
+/// Weights for particular kinds of entity, overriding the defaults of a
+/// [Configuration].
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityWeights {
+    /// The length of a URL by its lowercase scheme, such as "https". URLs with
+    /// other schemes, or none, count `transformedURLLength`.
+    #[serde(
+        rename = "transformedURLLengthByScheme",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub transformed_url_length_by_scheme: BTreeMap<String, i32>,
+    /// The weight of an emoji, scaled like `defaultWeight`. Emoji count
+    /// `defaultWeight` when this is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji_weight: Option<i32>,
+    /// Count a federated mention such as "@user@example.social" by its local
+    /// "@user" part only, as Mastodon does.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub federated_mention_local_part_only: bool,
+}
+
+/// How a [Configuration] measures the length of text.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LengthMode {
+    /// Each code point counts its range weight, or `defaultWeight`. This is how
+    /// Twitter counts.
+    #[default]
+    Weighted,
+    /// Each extended grapheme cluster counts as one character, as on networks
+    /// that limit user-perceived characters. Ranges, `defaultWeight` and emoji
+    /// parsing don't apply; URLs still count `transformedURLLength` when URLs
+    /// are extracted.
+    Graphemes,
+}
+
+impl LengthMode {
+    pub fn is_weighted(&self) -> bool {
+        *self == LengthMode::Weighted
+    }
 }
 
 impl Default for Configuration {
@@ -101,6 +162,8 @@ impl Default for Configuration {
             ranges: Configuration::default_ranges(),
             transformed_url_length: DEFAULT_TRANSFORMED_URL_LENGTH,
             emoji_parsing_enabled: true,
+            length_mode: LengthMode::Weighted,
+            entity_weights: None,
         }
     }
 }
@@ -115,16 +178,197 @@ impl Configuration {
         ]
     }
 
+    /// Load a configuration from a JSON file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file can't be read or the configuration is invalid. Use
+    /// [Configuration::try_from_path] to handle errors.
     pub fn configuration_from_path(path: &PathBuf) -> Configuration {
-        let mut f = File::open(path).expect("Config file not found");
-        let mut contents = String::new();
-        f.read_to_string(&mut contents)
-            .expect("Error reading config file");
-        Configuration::configuration_from_json(&contents)
+        Configuration::try_from_path(path).unwrap_or_else(|e| panic!("{}", e))
     }
 
+    /// Parse a configuration from JSON.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the JSON can't be parsed or the configuration is invalid. Use
+    /// [Configuration::try_from_json] to handle errors.
     pub fn configuration_from_json(json: &str) -> Configuration {
-        serde_json::from_str(json).expect("Error parsing json")
+        Configuration::try_from_json(json).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Load and validate a configuration from a JSON file.
+    pub fn try_from_path<P: AsRef<Path>>(path: P) -> Result<Configuration, ConfigError> {
+        let contents = fs::read_to_string(path)?;
+        Configuration::try_from_json(&contents)
+    }
+
+    /// Parse and validate a configuration from JSON.
+    pub fn try_from_json(json: &str) -> Result<Configuration, ConfigError> {
+        let config: Configuration = serde_json::from_str(json)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// The length a URL counts, in characters: its scheme's entry in
+    /// [EntityWeights::transformed_url_length_by_scheme], or
+    /// `transformed_url_length`.
+    pub fn url_length(&self, url: &str) -> i32 {
+        let scheme = match url.find("://") {
+            Some(end) => &url[..end],
+            None => return self.transformed_url_length,
+        };
+        self.entity_weights
+            .as_ref()
+            .and_then(|weights| {
+                weights
+                    .transformed_url_length_by_scheme
+                    .iter()
+                    .find(|(s, _)| s.eq_ignore_ascii_case(scheme))
+            })
+            .map_or(self.transformed_url_length, |(_, length)| *length)
+    }
+
+    /// The weight of an emoji, scaled like `default_weight`.
+    pub fn emoji_weight(&self) -> i32 {
+        self.entity_weights
+            .as_ref()
+            .and_then(|weights| weights.emoji_weight)
+            .unwrap_or(self.default_weight)
+    }
+
+    /// Whether federated mentions count by their local part only.
+    pub fn federated_mention_local_part_only(&self) -> bool {
+        self.entity_weights
+            .as_ref()
+            .is_some_and(|weights| weights.federated_mention_local_part_only)
+    }
+
+    /// Check that the configuration can be used to weigh text: the scale and
+    /// maximum length are positive, weights are non-negative, and the ranges
+    /// are sorted and don't overlap.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.scale <= 0 {
+            return Err(ConfigError::NonPositiveScale(self.scale));
+        }
+        if self.max_weighted_tweet_length <= 0 {
+            return Err(ConfigError::NonPositiveMaxLength(
+                self.max_weighted_tweet_length,
+            ));
+        }
+        if self.default_weight < 0 {
+            return Err(ConfigError::NegativeWeight(self.default_weight));
+        }
+        let mut previous: Option<&WeightedRange> = None;
+        for range in &self.ranges {
+            if range.range.start() > range.range.end() {
+                return Err(ConfigError::InvalidRange(range.range));
+            }
+            if range.weight < 0 {
+                return Err(ConfigError::NegativeWeight(range.weight));
+            }
+            if let Some(previous) = previous {
+                if range.range.start() < previous.range.start() {
+                    return Err(ConfigError::UnsortedRanges(previous.range, range.range));
+                }
+                if range.range.start() <= previous.range.end() {
+                    return Err(ConfigError::OverlappingRanges(previous.range, range.range));
+                }
+            }
+            previous = Some(range);
+        }
+        if let Some(weights) = &self.entity_weights {
+            let lengths = weights.transformed_url_length_by_scheme.values();
+            for &weight in lengths.chain(weights.emoji_weight.iter()) {
+                if weight < 0 {
+                    return Err(ConfigError::NegativeWeight(weight));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Errors from loading a [Configuration].
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file couldn't be read.
+    Io(io::Error),
+    /// The JSON couldn't be parsed into a configuration.
+    Json(serde_json::Error),
+    /// `scale` must be greater than zero.
+    NonPositiveScale(i32),
+    /// `maxWeightedTweetLength` must be greater than zero.
+    NonPositiveMaxLength(i32),
+    /// `defaultWeight` and range weights must not be negative.
+    NegativeWeight(i32),
+    /// A range ends before it starts.
+    InvalidRange(Range),
+    /// A range starts before the one listed ahead of it.
+    UnsortedRanges(Range, Range),
+    /// Two ranges share code points.
+    OverlappingRanges(Range, Range),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "error reading config file: {}", e),
+            ConfigError::Json(e) => write!(f, "error parsing config json: {}", e),
+            ConfigError::NonPositiveScale(scale) => {
+                write!(f, "scale must be greater than 0, got {}", scale)
+            }
+            ConfigError::NonPositiveMaxLength(length) => write!(
+                f,
+                "maxWeightedTweetLength must be greater than 0, got {}",
+                length
+            ),
+            ConfigError::NegativeWeight(weight) => {
+                write!(f, "weights must not be negative, got {}", weight)
+            }
+            ConfigError::InvalidRange(r) => {
+                write!(f, "range {}-{} ends before it starts", r.start(), r.end())
+            }
+            ConfigError::UnsortedRanges(a, b) => write!(
+                f,
+                "range {}-{} is listed after range {}-{}",
+                b.start(),
+                b.end(),
+                a.start(),
+                a.end()
+            ),
+            ConfigError::OverlappingRanges(a, b) => write!(
+                f,
+                "range {}-{} overlaps range {}-{}",
+                b.start(),
+                b.end(),
+                a.start(),
+                a.end()
+            ),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io(e) => Some(e),
+            ConfigError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for ConfigError {
+    fn from(e: serde_json::Error) -> Self {
+        ConfigError::Json(e)
     }
 }
 
@@ -273,3 +517,139 @@ impl PartialOrd for Range {
         Some(self.cmp(other))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_json() {
+        for json in [V1_JSON, V2_JSON, V3_JSON] {
+            assert!(Configuration::try_from_json(json).is_ok());
+        }
+        assert_eq!(Configuration::try_from_json(V3_JSON).unwrap(), *config_v3());
+        assert!(matches!(
+            Configuration::try_from_json("{"),
+            Err(ConfigError::Json(_))
+        ));
+        assert!(matches!(
+            Configuration::try_from_path("no/such/config.json"),
+            Err(ConfigError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_length_mode_json() {
+        // v1-v3 configs don't name a mode, and still serialize without one.
+        let config = Configuration::try_from_json(V2_JSON).unwrap();
+        assert_eq!(config.length_mode, LengthMode::Weighted);
+        assert!(!serde_json::to_string(&config)
+            .unwrap()
+            .contains("lengthMode"));
+
+        let json = r#"{"version": 3, "maxWeightedTweetLength": 300, "scale": 1,
+            "defaultWeight": 1, "transformedURLLength": 23, "ranges": [],
+            "lengthMode": "graphemes"}"#;
+        let config = Configuration::try_from_json(json).unwrap();
+        assert_eq!(config.length_mode, LengthMode::Graphemes);
+        let round_trip = serde_json::to_string(&config).unwrap();
+        assert!(round_trip.contains(r#""lengthMode":"graphemes""#));
+        assert_eq!(Configuration::try_from_json(&round_trip).unwrap(), config);
+    }
+
+    #[test]
+    fn test_entity_weights_json() {
+        let config = Configuration::try_from_json(V3_JSON).unwrap();
+        assert_eq!(config.entity_weights, None);
+        assert_eq!(config.url_length("https://example.com"), 23);
+        assert_eq!(config.emoji_weight(), 200);
+        assert!(!config.federated_mention_local_part_only());
+
+        let json = r#"{"version": 3, "maxWeightedTweetLength": 500, "scale": 100,
+            "defaultWeight": 100, "transformedURLLength": 23, "ranges": [],
+            "entityWeights": {"transformedURLLengthByScheme": {"gemini": 30},
+            "emojiWeight": 100, "federatedMentionLocalPartOnly": true}}"#;
+        let config = Configuration::try_from_json(json).unwrap();
+        assert_eq!(config.url_length("GEMINI://example.com"), 30);
+        assert_eq!(config.url_length("https://example.com"), 23);
+        assert_eq!(config.url_length("example.com"), 23);
+        assert_eq!(config.emoji_weight(), 100);
+        assert!(config.federated_mention_local_part_only());
+        let round_trip = serde_json::to_string(&config).unwrap();
+        assert!(round_trip.contains(r#""transformedURLLengthByScheme":{"gemini":30}"#));
+        assert_eq!(Configuration::try_from_json(&round_trip).unwrap(), config);
+
+        let json = r#"{"version": 3, "maxWeightedTweetLength": 500, "scale": 100,
+            "defaultWeight": 100, "transformedURLLength": 23, "ranges": [],
+            "entityWeights": {}}"#;
+        let config = Configuration::try_from_json(json).unwrap();
+        assert_eq!(config.entity_weights, Some(EntityWeights::default()));
+        assert!(serde_json::to_string(&config)
+            .unwrap()
+            .contains(r#""entityWeights":{}"#));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Configuration::default().validate().is_ok());
+        assert!(config_v1().validate().is_ok());
+
+        let config = Configuration {
+            scale: 0,
+            ..Configuration::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::NonPositiveScale(0))
+        ));
+
+        let config = Configuration {
+            max_weighted_tweet_length: -1,
+            ..Configuration::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::NonPositiveMaxLength(-1))
+        ));
+
+        let mut config = Configuration::default();
+        config.ranges[1].weight = -100;
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::NegativeWeight(-100))
+        ));
+
+        let config = Configuration {
+            entity_weights: Some(EntityWeights {
+                emoji_weight: Some(-1),
+                ..EntityWeights::default()
+            }),
+            ..Configuration::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::NegativeWeight(-1))
+        ));
+
+        let mut config = Configuration::default();
+        config.ranges.swap(0, 1);
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::UnsortedRanges(_, _))
+        ));
+
+        let mut config = Configuration::default();
+        config.ranges.insert(2, WeightedRange::new(8200, 8207, 100));
+        let err = config.validate().unwrap_err();
+        assert_eq!(err.to_string(), "range 8200-8207 overlaps range 8192-8205");
+
+        let config = Configuration {
+            ranges: vec![WeightedRange::new(10, 0, 100)],
+            ..Configuration::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidRange(_))
+        ));
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::{ConfigError, Configuration, EntityWeights, LengthMode, WeightedRange};
+use serde_derive::{Deserialize, Serialize};
+
+/// How the ranges of a [ConfigurationOverlay] combine with the ranges of the
+/// base configuration.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RangesMode {
+    /// The overlay's ranges replace the base ranges entirely.
+    #[default]
+    Replace,
+    /// The overlay's ranges are laid over the base ranges. Where they
+    /// overlap, the overlay's weight wins and the base range is trimmed or
+    /// split around it. Base ranges that don't overlap are kept.
+    Merge,
+}
+
+impl RangesMode {
+    pub fn is_replace(&self) -> bool {
+        *self == RangesMode::Replace
+    }
+}
+
+/// A partial [Configuration], applied on top of a base configuration.
+///
+/// Each field that is set replaces the base value; unset fields keep it.
+/// `entityWeights` replaces the base section as a whole. `ranges` combine
+/// with the base ranges according to `rangesMode`. Unknown fields are an
+/// error, so a misspelled key doesn't silently leave the base value in place.
+///
+/// ```
+/// use twitter_text_config::{config_v3, Configuration};
+///
+/// let dm = config_v3()
+///     .with_overlay_json(r#"{"maxWeightedTweetLength": 10000}"#)
+///     .unwrap();
+/// assert_eq!(dm.max_weighted_tweet_length, 10000);
+/// assert_eq!(dm.ranges, config_v3().ranges);
+/// ```
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConfigurationOverlay {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_weighted_tweet_length: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_weight: Option<i32>,
+    #[serde(
+        rename = "transformedURLLength",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub transformed_url_length: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ranges: Option<Vec<WeightedRange>>,
+    #[serde(default, skip_serializing_if = "RangesMode::is_replace")]
+    pub ranges_mode: RangesMode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji_parsing_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length_mode: Option<LengthMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_weights: Option<EntityWeights>,
+}
+
+impl ConfigurationOverlay {
+    /// Parse an overlay from JSON.
+    pub fn try_from_json(json: &str) -> Result<ConfigurationOverlay, ConfigError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Returns true if the overlay changes nothing.
+    pub fn is_empty(&self) -> bool {
+        *self == ConfigurationOverlay::default()
+    }
+
+    /// Apply the overlay to `config` in place, without validating the result.
+    pub fn apply_to(&self, config: &mut Configuration) {
+        fn set<T: Clone>(field: &mut T, value: &Option<T>) {
+            if let Some(value) = value {
+                *field = value.clone();
+            }
+        }
+        set(&mut config.version, &self.version);
+        set(
+            &mut config.max_weighted_tweet_length,
+            &self.max_weighted_tweet_length,
+        );
+        set(&mut config.scale, &self.scale);
+        set(&mut config.default_weight, &self.default_weight);
+        set(
+            &mut config.transformed_url_length,
+            &self.transformed_url_length,
+        );
+        set(
+            &mut config.emoji_parsing_enabled,
+            &self.emoji_parsing_enabled,
+        );
+        set(&mut config.length_mode, &self.length_mode);
+        if self.entity_weights.is_some() {
+            config.entity_weights = self.entity_weights.clone();
+        }
+        if let Some(ranges) = &self.ranges {
+            config.ranges = match self.ranges_mode {
+                RangesMode::Replace => ranges.clone(),
+                RangesMode::Merge => merge_ranges(&config.ranges, ranges),
+            };
+        }
+    }
+}
+
+impl Configuration {
+    /// A copy of this configuration with `overlay` applied. The result is
+    /// validated with [Configuration::validate].
+    pub fn with_overlay(
+        &self,
+        overlay: &ConfigurationOverlay,
+    ) -> Result<Configuration, ConfigError> {
+        let mut config = self.clone();
+        overlay.apply_to(&mut config);
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// A copy of this configuration with an overlay parsed from JSON applied.
+    pub fn with_overlay_json(&self, json: &str) -> Result<Configuration, ConfigError> {
+        self.with_overlay(&ConfigurationOverlay::try_from_json(json)?)
+    }
+
+    /// The overlay that turns `base` into this configuration. Differing
+    /// ranges are listed in full, replacing the base ranges.
+    pub fn diff(&self, base: &Configuration) -> ConfigurationOverlay {
+        fn changed<T: Clone + PartialEq>(value: &T, base: &T) -> Option<T> {
+            if value != base {
+                Some(value.clone())
+            } else {
+                None
+            }
+        }
+        ConfigurationOverlay {
+            version: changed(&self.version, &base.version),
+            max_weighted_tweet_length: changed(
+                &self.max_weighted_tweet_length,
+                &base.max_weighted_tweet_length,
+            ),
+            scale: changed(&self.scale, &base.scale),
+            default_weight: changed(&self.default_weight, &base.default_weight),
+            transformed_url_length: changed(
+                &self.transformed_url_length,
+                &base.transformed_url_length,
+            ),
+            ranges: changed(&self.ranges, &base.ranges),
+            ranges_mode: RangesMode::Replace,
+            emoji_parsing_enabled: changed(
+                &self.emoji_parsing_enabled,
+                &base.emoji_parsing_enabled,
+            ),
+            length_mode: changed(&self.length_mode, &base.length_mode),
+            entity_weights: if self.entity_weights != base.entity_weights {
+                // An overlay can't remove the base section, so an absent one
+                // is written as empty weights, which count the same.
+                Some(self.entity_weights.clone().unwrap_or_default())
+            } else {
+                None
+            },
+        }
+    }
+}
+
+// Lay `overlay` over `base`: base ranges are cut around the overlay ranges,
+// and the pieces are sorted together with the overlay ranges.
+fn merge_ranges(base: &[WeightedRange], overlay: &[WeightedRange]) -> Vec<WeightedRange> {
+    let mut merged: Vec<WeightedRange> = overlay.to_vec();
+    for range in base {
+        let mut pieces = vec![(range.range.start(), range.range.end())];
+        for cut in overlay {
+            let (cut_start, cut_end) = (cut.range.start(), cut.range.end());
+            pieces = pieces
+                .into_iter()
+                .flat_map(|(start, end)| {
+                    if end < cut_start || start > cut_end {
+                        return vec![(start, end)];
+                    }
+                    let mut kept = Vec::new();
+                    if start < cut_start {
+                        kept.push((start, cut_start - 1));
+                    }
+                    if end > cut_end {
+                        kept.push((cut_end + 1, end));
+                    }
+                    kept
+                })
+                .collect();
+        }
+        merged.extend(
+            pieces
+                .into_iter()
+                .map(|(start, end)| WeightedRange::new(start, end, range.weight)),
+        );
+    }
+    merged.sort_by_key(|r| (r.range.start(), r.range.end()));
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config_v3;
+
+    #[test]
+    fn test_scalar_overlay() {
+        let long_form = config_v3()
+            .with_overlay_json(r#"{"maxWeightedTweetLength": 25000}"#)
+            .unwrap();
+        assert_eq!(long_form.max_weighted_tweet_length, 25000);
+        assert_eq!(long_form.ranges, config_v3().ranges);
+        assert_eq!(long_form.scale, config_v3().scale);
+
+        // An empty overlay changes nothing.
+        assert_eq!(config_v3().with_overlay_json("{}").unwrap(), *config_v3());
+    }
+
+    #[test]
+    fn test_overlay_errors() {
+        assert!(matches!(
+            config_v3().with_overlay_json(r#"{"maxWeightedTweetLenght": 1}"#),
+            Err(ConfigError::Json(_))
+        ));
+        assert!(matches!(
+            config_v3().with_overlay_json(r#"{"scale": 0}"#),
+            Err(ConfigError::NonPositiveScale(0))
+        ));
+        // Replacing with overlapping ranges fails validation.
+        let json = r#"{"ranges": [{"start": 0, "end": 10, "weight": 100},
+            {"start": 5, "end": 20, "weight": 100}]}"#;
+        assert!(matches!(
+            config_v3().with_overlay_json(json),
+            Err(ConfigError::OverlappingRanges(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_ranges_replace() {
+        let json = r#"{"ranges": [{"start": 0, "end": 127, "weight": 100}]}"#;
+        let config = config_v3().with_overlay_json(json).unwrap();
+        assert_eq!(config.ranges, vec![WeightedRange::new(0, 127, 100)]);
+    }
+
+    #[test]
+    fn test_ranges_merge() {
+        // Discount kana, and split the 8192-8205 range around a heavier 8200-8210.
+        let json = r#"{"rangesMode": "merge", "ranges": [
+            {"start": 8200, "end": 8210, "weight": 300},
+            {"start": 12352, "end": 12543, "weight": 100}]}"#;
+        let config = config_v3().with_overlay_json(json).unwrap();
+        assert_eq!(
+            config.ranges,
+            vec![
+                WeightedRange::new(0, 4351, 100),
+                WeightedRange::new(8192, 8199, 100),
+                WeightedRange::new(8200, 8210, 300),
+                WeightedRange::new(8211, 8223, 100),
+                WeightedRange::new(8242, 8247, 100),
+                WeightedRange::new(12352, 12543, 100),
+            ]
+        );
+
+        // A range that swallows a base range removes it.
+        let overlay = ConfigurationOverlay {
+            ranges: Some(vec![WeightedRange::new(8000, 8300, 0)]),
+            ranges_mode: RangesMode::Merge,
+            ..ConfigurationOverlay::default()
+        };
+        let config = config_v3().with_overlay(&overlay).unwrap();
+        assert_eq!(
+            config.ranges,
+            vec![
+                WeightedRange::new(0, 4351, 100),
+                WeightedRange::new(8000, 8300, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        assert!(config_v3().diff(config_v3()).is_empty());
+
+        let json = r#"{"maxWeightedTweetLength": 10000, "rangesMode": "merge",
+            "ranges": [{"start": 12352, "end": 12543, "weight": 100}],
+            "entityWeights": {"emojiWeight": 100}}"#;
+        let dm = config_v3().with_overlay_json(json).unwrap();
+        let diff = dm.diff(config_v3());
+        assert_eq!(diff.max_weighted_tweet_length, Some(10000));
+        assert_eq!(diff.scale, None);
+        assert_eq!(diff.ranges.as_ref().map(Vec::len), Some(5));
+        assert_eq!(config_v3().with_overlay(&diff).unwrap(), dm);
+
+        // The diff serializes as an overlay, listing only what changed.
+        let diff_json = serde_json::to_string(&diff).unwrap();
+        assert!(!diff_json.contains("scale"));
+        assert_eq!(config_v3().with_overlay_json(&diff_json).unwrap(), dm);
+
+        // Dropping entity weights diffs as empty weights.
+        let diff = config_v3().diff(&dm);
+        assert_eq!(diff.entity_weights, Some(EntityWeights::default()));
+    }
+}
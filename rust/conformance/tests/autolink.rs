@@ -106,6 +106,7 @@ fn autolink() {
                 list_slug: "",
                 display_url: "",
                 expanded_url: "",
+                host_span: None,
             });
         }
         for h in &entities_json.hashtags {
@@ -117,6 +118,7 @@ fn autolink() {
                 list_slug: "",
                 display_url: "",
                 expanded_url: "",
+                host_span: None,
             });
         }
         for u in &entities_json.urls {
@@ -128,6 +130,7 @@ fn autolink() {
                 list_slug: "",
                 display_url: u.display_url.as_deref().unwrap_or(""),
                 expanded_url: u.expanded_url.as_deref().unwrap_or(""),
+                host_span: None,
             });
         }
         entities.sort_by_key(|e| e.start);
@@ -7,10 +7,21 @@ import sys
 
 import yaml
 
+# tld_lib.yml category keys, in order of precedence. IDN ccTLDs are listed
+# under both "country" and "generic"; the country category wins.
+KINDS = [("country", "Country"), ("generic", "Generic")]
+
+# Sponsored TLDs are listed under "generic" in tld_lib.yml. This list follows
+# the "sponsored" type in the IANA Root Zone Database.
+SPONSORED = {
+    "aero", "asia", "cat", "coop", "edu", "gov", "int", "jobs", "mil",
+    "museum", "post", "tel", "travel", "xxx",
+}
+
 
 def main():
     parser = argparse.ArgumentParser(
-        description="Generate TLD PHF hash set for Rust"
+        description="Generate TLD PHF hash map for Rust"
     )
     parser.add_argument(
         "--input", required=True, help="Input YAML file containing TLDs"
@@ -27,13 +38,21 @@ def main():
             print(exc, file=sys.stderr)
             sys.exit(1)
 
-    tlds = set()
-    for kind in tld_structure.values():
-        for element in kind:
+    unknown = set(tld_structure.keys()) - {key for key, _ in KINDS}
+    if unknown:
+        print(f"Unknown TLD categories: {sorted(unknown)}", file=sys.stderr)
+        sys.exit(1)
+
+    tlds = {}
+    for key, kind in KINDS:
+        for element in tld_structure.get(key) or []:
+            element_kind = kind
+            if kind == "Generic" and element.lower() in SPONSORED:
+                element_kind = "Sponsored"
             idna = element.encode("idna").decode("ascii")
             if idna != element:
-                tlds.add(idna)
-            tlds.add(element.lower())
+                tlds.setdefault(idna, element_kind)
+            tlds.setdefault(element.lower(), element_kind)
 
     # Separate into ASCII, unicode, and punycode for organized output
     ascii_tlds = sorted(t for t in tlds if all(ord(c) < 128 for c in t) and not t.startswith("xn--"))
@@ -43,14 +62,14 @@ def main():
     with open(args.output, "w", encoding="utf-8") as out:
         out.write(FILE_HEADER)
 
-        # Write phf_set entries
+        # Write phf_map entries
         for tld in ascii_tlds:
-            out.write(f'    "{tld}",\n')
+            out.write(f'    "{tld}" => TldKind::{tlds[tld]},\n')
         for tld in unicode_tlds:
-            out.write(f'    "{tld}",\n')
+            out.write(f'    "{tld}" => TldKind::{tlds[tld]},\n')
         out.write("    // Punycode TLDs (IDN TLDs in ASCII-compatible encoding)\n")
         for tld in punycode_tlds:
-            out.write(f'    "{tld}",\n')
+            out.write(f'    "{tld}" => TldKind::{tlds[tld]},\n')
 
         out.write(FILE_FOOTER)
 
@@ -75,11 +94,23 @@ FILE_HEADER = """\
 //! This file is generated by tld_gen_phf.py from tld_lib.yml.
 //! Do not edit by hand.
 
-use phf::phf_set;
+use phf::phf_map;
+
+/// The category of a TLD, following the groups in tld_lib.yml and the
+/// IANA Root Zone Database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TldKind {
+    /// Country-code TLDs, including IDN ccTLDs such as "рф".
+    Country,
+    /// Generic TLDs.
+    Generic,
+    /// Sponsored generic TLDs, such as "edu" and "gov".
+    Sponsored,
+}
 
-/// A compile-time perfect hash set of all valid TLDs.
+/// A compile-time perfect hash map of all valid TLDs to their category.
 /// Case-insensitive matching is handled by lowercasing input before lookup.
-pub static TLDS: phf::Set<&'static str> = phf_set! {
+pub static TLDS: phf::Map<&'static str, TldKind> = phf_map! {
 """
 
 FILE_FOOTER = """\
@@ -89,7 +120,7 @@ FILE_FOOTER = """\
 /// The input should be lowercase.
 #[inline]
 pub fn is_valid_tld(tld: &str) -> bool {
-    TLDS.contains(tld)
+    TLDS.contains_key(tld)
 }
 
 /// Maximum TLD length in bytes (longest is "சிங்கப்பூர்" at 33 bytes)
@@ -99,9 +130,16 @@ const MAX_TLD_LEN: usize = 64;
 /// Uses a stack-allocated buffer for lowercase conversion to avoid heap allocations.
 #[inline]
 pub fn is_valid_tld_case_insensitive(tld: &str) -> bool {
+    tld_kind(tld).is_some()
+}
+
+/// Look up the category of a TLD, case-insensitively.
+/// Returns None if the string is not a valid TLD.
+#[inline]
+pub fn tld_kind(tld: &str) -> Option<TldKind> {
     // Fast path: if already lowercase ASCII, check directly
     if tld.bytes().all(|b| !b.is_ascii_uppercase()) {
-        return TLDS.contains(tld);
+        return TLDS.get(tld).copied();
     }
 
     // Need to lowercase - use stack buffer if small enough
@@ -113,7 +151,7 @@ pub fn is_valid_tld_case_insensitive(tld: &str) -> bool {
                 let len = lc.len_utf8();
                 if i + len > MAX_TLD_LEN {
                     // Overflow - fall back to heap allocation
-                    return TLDS.contains(&tld.to_lowercase());
+                    return TLDS.get(tld.to_lowercase().as_str()).copied();
                 }
                 lc.encode_utf8(&mut buf[i..]);
                 i += len;
@@ -121,10 +159,10 @@ pub fn is_valid_tld_case_insensitive(tld: &str) -> bool {
         }
         // Safety: we only wrote valid UTF-8 chars
         let lowered = unsafe { std::str::from_utf8_unchecked(&buf[..i]) };
-        TLDS.contains(lowered)
+        TLDS.get(lowered).copied()
     } else {
         // TLD too long - fall back to heap allocation (shouldn't happen with valid TLDs)
-        TLDS.contains(&tld.to_lowercase())
+        TLDS.get(tld.to_lowercase().as_str()).copied()
     }
 }
 
@@ -155,6 +193,17 @@ mod tests {
         assert!(is_valid_tld("xn--mgbaam7a8h")); // .امارات (UAE)
     }
 
+    #[test]
+    fn test_tld_kinds() {
+        assert_eq!(tld_kind("com"), Some(TldKind::Generic));
+        assert_eq!(tld_kind("CO"), Some(TldKind::Country));
+        assert_eq!(tld_kind("edu"), Some(TldKind::Sponsored));
+        assert_eq!(tld_kind("рф"), Some(TldKind::Country));
+        assert_eq!(tld_kind("xn--p1ai"), Some(TldKind::Country));
+        assert_eq!(tld_kind("みんな"), Some(TldKind::Generic));
+        assert_eq!(tld_kind("notarealtld"), None);
+    }
+
     #[test]
     fn test_invalid_tlds() {
         assert!(!is_valid_tld("invalid"));
@@ -29,6 +29,14 @@ TEST(TwitterTextConfigurationTest, Path) {
   ASSERT_EQ(wr.weight, 200);
 }
 
+TEST(TwitterTextConfigurationTest, Errors) {
+  ASSERT_THROW(TwitterTextConfiguration::configurationFromPath("rust/cpp-bindings/test_data/no_such_config.json"), rust::Error);
+  ASSERT_THROW(TwitterTextConfiguration::configurationFromJson("{"), rust::Error);
+  ASSERT_THROW(TwitterTextConfiguration::configurationFromJson(
+    "{\"version\": 3, \"maxWeightedTweetLength\": 280, \"scale\": 0, "
+    "\"defaultWeight\": 200, \"transformedURLLength\": 23, \"ranges\": []}"), rust::Error);
+}
+
 TEST(TwitterTextConfigurationTest, Json) {
   std::ifstream t("rust/cpp-bindings/test_data/test_config.json");
   std::string str((std::istreambuf_iterator<char>(t)),
@@ -96,6 +104,39 @@ TEST(TwitterTextConfigurationTest, EmojiParsingEnabled) {
   delete config;
 }
 
+TEST(TwitterTextConfigurationTest, EntityWeights) {
+  TwitterTextConfiguration *config = new TwitterTextConfiguration();
+  ASSERT_FALSE(config->hasEntityWeights());
+  ASSERT_EQ(TwitterTextParser::parse("😀", *config, true).weighted_length, 2);
+
+  EntityWeights weights = config->getEntityWeights();
+  weights.has_emoji_weight = true;
+  weights.emoji_weight = 100;
+  config->setEntityWeights(weights);
+  ASSERT_TRUE(config->hasEntityWeights());
+  ASSERT_EQ(TwitterTextParser::parse("😀", *config, true).weighted_length, 1);
+
+  config->clearEntityWeights();
+  ASSERT_EQ(TwitterTextParser::parse("😀", *config, true).weighted_length, 2);
+  delete config;
+}
+
+TEST(TwitterTextConfigurationTest, EntityWeightsJson) {
+  auto config = TwitterTextConfiguration::configurationFromJson(
+    "{\"version\": 3, \"maxWeightedTweetLength\": 280, \"scale\": 100, "
+    "\"defaultWeight\": 200, \"transformedURLLength\": 23, \"ranges\": [], "
+    "\"entityWeights\": {\"transformedURLLengthByScheme\": {\"https\": 30}, "
+    "\"federatedMentionLocalPartOnly\": true}}");
+  ASSERT_TRUE(config->hasEntityWeights());
+  EntityWeights weights = config->getEntityWeights();
+  ASSERT_EQ(weights.transformed_url_length_by_scheme.size(), 1);
+  ASSERT_EQ(std::string(weights.transformed_url_length_by_scheme[0].scheme), "https");
+  ASSERT_EQ(weights.transformed_url_length_by_scheme[0].length, 30);
+  ASSERT_FALSE(weights.has_emoji_weight);
+  ASSERT_TRUE(weights.federated_mention_local_part_only);
+  ASSERT_EQ(TwitterTextParser::parse("https://example.com", *config, true).weighted_length, 30);
+}
+
 TEST(TwitterTextConfigurationTest, Ranges) {
   TwitterTextConfiguration *config = new TwitterTextConfiguration();
   std::vector<WeightedRange> stdv = config->getRanges();
@@ -68,6 +68,31 @@ public:
     config->emoji_parsing_enabled = enabled;
   }
 
+  LengthMode getLengthMode() {
+    return config->length_mode;
+  }
+
+  void setLengthMode(LengthMode mode) {
+    config->length_mode = mode;
+  }
+
+  bool hasEntityWeights() {
+    return config->has_entity_weights;
+  }
+
+  EntityWeights getEntityWeights() {
+    return config->entity_weights;
+  }
+
+  void setEntityWeights(EntityWeights weights) {
+    config->has_entity_weights = true;
+    config->entity_weights = weights;
+  }
+
+  void clearEntityWeights() {
+    config->has_entity_weights = false;
+  }
+
   static TwitterTextConfiguration* configV1()  {
     return new TwitterTextConfiguration(config_v1());
   }
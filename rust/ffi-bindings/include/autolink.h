@@ -7,6 +7,13 @@
 
 typedef struct TwitterTextAutolinker TwitterTextAutolinker;
 
+/* How emoji between entities are written */
+typedef enum {
+    TWITTER_TEXT_EMOJI_RENDERING_NONE = 0,
+    TWITTER_TEXT_EMOJI_RENDERING_IMAGE = 1,
+    TWITTER_TEXT_EMOJI_RENDERING_SPAN = 2
+} TwitterTextEmojiRendering;
+
 /* Autolinker API */
 TwitterTextAutolinker* twitter_text_autolinker_new(bool no_follow);
 void twitter_text_autolinker_free(TwitterTextAutolinker* autolinker);
@@ -72,6 +79,47 @@ void twitter_text_autolinker_set_cashtag_url_base(
     const char* cashtag_url_base
 );
 
+/* The href template for @user@domain mentions; {domain} and {user} are
+ * replaced. An empty template leaves federated mentions as plain text. */
+void twitter_text_autolinker_set_federated_mention_url_template(
+    TwitterTextAutolinker* autolinker,
+    const char* federated_mention_url_template
+);
+
+void twitter_text_autolinker_set_federated_mention_class(
+    TwitterTextAutolinker* autolinker,
+    const char* federated_mention_class
+);
+
+/* Wrap federated mention links in a Mastodon-style h-card span */
+void twitter_text_autolinker_set_federated_mention_h_card(
+    TwitterTextAutolinker* autolinker,
+    bool federated_mention_h_card
+);
+
+/* Escape all text and drop unsafe attributes, hrefs and symbol tags */
+void twitter_text_autolinker_set_strict(
+    TwitterTextAutolinker* autolinker,
+    bool strict
+);
+
+void twitter_text_autolinker_set_emoji_rendering(
+    TwitterTextAutolinker* autolinker,
+    TwitterTextEmojiRendering emoji_rendering
+);
+
+void twitter_text_autolinker_set_emoji_class(
+    TwitterTextAutolinker* autolinker,
+    const char* emoji_class
+);
+
+/* The <img> src template; {codepoints} is replaced with the emoji's code
+ * points in lowercase hex, joined by "-". */
+void twitter_text_autolinker_set_emoji_image_url_template(
+    TwitterTextAutolinker* autolinker,
+    const char* emoji_image_url_template
+);
+
 void twitter_text_autolinker_set_invisible_tag_attrs(
     TwitterTextAutolinker* autolinker,
     const char* invisible_tag_attrs
@@ -119,6 +167,12 @@ char* twitter_text_autolinker_autolink_cashtags(
     const char* text
 );
 
+/* Auto-link all entities, including federated @user@domain mentions */
+char* twitter_text_autolinker_autolink_federated(
+    TwitterTextAutolinker* autolinker,
+    const char* text
+);
+
 /* Free function for returned strings */
 void twitter_text_string_free(char* str);
 
@@ -148,4 +202,6 @@ extern const char* TWITTER_TEXT_DEFAULT_USERNAME_URL_BASE;
 extern const char* TWITTER_TEXT_DEFAULT_LIST_URL_BASE;
 extern const char* TWITTER_TEXT_DEFAULT_HASHTAG_URL_BASE;
 extern const char* TWITTER_TEXT_DEFAULT_CASHTAG_URL_BASE;
+extern const char* TWITTER_TEXT_DEFAULT_FEDERATED_MENTION_CLASS;
+extern const char* TWITTER_TEXT_DEFAULT_FEDERATED_MENTION_URL_TEMPLATE;
 extern const char* TWITTER_TEXT_DEFAULT_INVISIBLE_TAG_ATTRS;
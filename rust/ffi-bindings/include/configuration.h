@@ -11,6 +11,46 @@ TwitterTextConfiguration* twitter_text_config_v1(void);
 TwitterTextConfiguration* twitter_text_config_v2(void);
 TwitterTextConfiguration* twitter_text_config_v3(void);
 TwitterTextConfiguration* twitter_text_config_from_json(const char* json);
+
+/* Result codes for the fallible configuration loaders */
+typedef enum {
+    TWITTER_TEXT_CONFIG_OK = 0,
+    TWITTER_TEXT_CONFIG_ERROR_NULL_ARGUMENT = 1,
+    TWITTER_TEXT_CONFIG_ERROR_INVALID_UTF8 = 2,
+    TWITTER_TEXT_CONFIG_ERROR_IO = 3,
+    TWITTER_TEXT_CONFIG_ERROR_JSON = 4,
+    TWITTER_TEXT_CONFIG_ERROR_INVALID = 5
+} TwitterTextConfigError;
+
+/* Parse or load a configuration and validate it. On success, *out receives
+ * a configuration to release with twitter_text_config_free. */
+TwitterTextConfigError twitter_text_config_try_from_json(
+    const char* json,
+    TwitterTextConfiguration** out
+);
+TwitterTextConfigError twitter_text_config_try_from_path(
+    const char* path,
+    TwitterTextConfiguration** out
+);
+
+/* Apply a partial JSON overlay to a base configuration, such as
+ * {"maxWeightedTweetLength": 10000}. Set fields replace the base values;
+ * "ranges" replace the base ranges, or are laid over them when
+ * "rangesMode" is "merge". The result is validated; on success, *out
+ * receives a new configuration and base is unchanged. */
+TwitterTextConfigError twitter_text_config_try_with_overlay_json(
+    TwitterTextConfiguration* base,
+    const char* json,
+    TwitterTextConfiguration** out
+);
+
+/* The overlay that turns base into config, as JSON. Free the result with
+ * twitter_text_string_free. */
+char* twitter_text_config_diff_json(
+    TwitterTextConfiguration* config,
+    TwitterTextConfiguration* base
+);
+
 void twitter_text_config_free(TwitterTextConfiguration* config);
 
 /* Range struct - matches Rust Range */
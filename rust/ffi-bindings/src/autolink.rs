@@ -1,6 +1,6 @@
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
-use twitter_text::autolinker::Autolinker;
+use twitter_text::autolinker::{Autolinker, EmojiRendering};
 use twitter_text::entity;
 
 /* ============================================================================
@@ -29,6 +29,24 @@ impl From<TwitterTextEntityType> for entity::Type {
     }
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum TwitterTextEmojiRendering {
+    NONE = 0,
+    IMAGE = 1,
+    SPAN = 2,
+}
+
+impl From<TwitterTextEmojiRendering> for EmojiRendering {
+    fn from(r: TwitterTextEmojiRendering) -> Self {
+        match r {
+            TwitterTextEmojiRendering::NONE => EmojiRendering::None,
+            TwitterTextEmojiRendering::IMAGE => EmojiRendering::Image,
+            TwitterTextEmojiRendering::SPAN => EmojiRendering::Span,
+        }
+    }
+}
+
 /* ============================================================================
  * Basic Autolinker API
  * ========================================================================= */
@@ -304,6 +322,108 @@ pub extern "C" fn twitter_text_autolinker_set_cashtag_url_base(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn twitter_text_autolinker_set_federated_mention_class(
+    autolinker: *mut Autolinker<'static>,
+    federated_mention_class: *const c_char,
+) {
+    if autolinker.is_null() || federated_mention_class.is_null() {
+        return;
+    }
+
+    let c_str = unsafe { CStr::from_ptr(federated_mention_class) };
+    if let Ok(s) = c_str.to_str() {
+        unsafe {
+            (*autolinker).federated_mention_class = Box::leak(s.to_string().into_boxed_str());
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn twitter_text_autolinker_set_federated_mention_url_template(
+    autolinker: *mut Autolinker<'static>,
+    federated_mention_url_template: *const c_char,
+) {
+    if autolinker.is_null() || federated_mention_url_template.is_null() {
+        return;
+    }
+
+    let c_str = unsafe { CStr::from_ptr(federated_mention_url_template) };
+    if let Ok(s) = c_str.to_str() {
+        unsafe {
+            (*autolinker).federated_mention_url_template =
+                Box::leak(s.to_string().into_boxed_str());
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn twitter_text_autolinker_set_federated_mention_h_card(
+    autolinker: *mut Autolinker,
+    federated_mention_h_card: bool,
+) {
+    if !autolinker.is_null() {
+        unsafe {
+            (*autolinker).federated_mention_h_card = federated_mention_h_card;
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn twitter_text_autolinker_set_strict(autolinker: *mut Autolinker, strict: bool) {
+    if !autolinker.is_null() {
+        unsafe {
+            (*autolinker).strict = strict;
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn twitter_text_autolinker_set_emoji_rendering(
+    autolinker: *mut Autolinker,
+    emoji_rendering: TwitterTextEmojiRendering,
+) {
+    if !autolinker.is_null() {
+        unsafe {
+            (*autolinker).emoji_rendering = emoji_rendering.into();
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn twitter_text_autolinker_set_emoji_class(
+    autolinker: *mut Autolinker<'static>,
+    emoji_class: *const c_char,
+) {
+    if autolinker.is_null() || emoji_class.is_null() {
+        return;
+    }
+
+    let c_str = unsafe { CStr::from_ptr(emoji_class) };
+    if let Ok(s) = c_str.to_str() {
+        unsafe {
+            (*autolinker).emoji_class = Box::leak(s.to_string().into_boxed_str());
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn twitter_text_autolinker_set_emoji_image_url_template(
+    autolinker: *mut Autolinker<'static>,
+    emoji_image_url_template: *const c_char,
+) {
+    if autolinker.is_null() || emoji_image_url_template.is_null() {
+        return;
+    }
+
+    let c_str = unsafe { CStr::from_ptr(emoji_image_url_template) };
+    if let Ok(s) = c_str.to_str() {
+        unsafe {
+            (*autolinker).emoji_image_url_template = Box::leak(s.to_string().into_boxed_str());
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn twitter_text_autolinker_set_invisible_tag_attrs(
     autolinker: *mut Autolinker<'static>,
@@ -412,6 +532,26 @@ pub extern "C" fn twitter_text_autolinker_autolink_cashtags(
     CString::new(result).unwrap_or_default().into_raw()
 }
 
+#[no_mangle]
+pub extern "C" fn twitter_text_autolinker_autolink_federated(
+    autolinker: *mut Autolinker,
+    text: *const c_char,
+) -> *mut c_char {
+    if autolinker.is_null() || text.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let autolinker_ref = unsafe { &*autolinker };
+    let c_str = unsafe { CStr::from_ptr(text) };
+    let text_str = match c_str.to_str() {
+        Ok(s) => s,
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    let result = autolinker_ref.autolink_federated(text_str);
+    CString::new(result).unwrap_or_default().into_raw()
+}
+
 /* ============================================================================
  * Default constants
  * ========================================================================= */
@@ -447,6 +587,14 @@ pub static TWITTER_TEXT_DEFAULT_HASHTAG_URL_BASE: &str =
 pub static TWITTER_TEXT_DEFAULT_CASHTAG_URL_BASE: &str =
     twitter_text::autolinker::DEFAULT_CASHTAG_URL_BASE;
 
+#[no_mangle]
+pub static TWITTER_TEXT_DEFAULT_FEDERATED_MENTION_CLASS: &str =
+    twitter_text::autolinker::DEFAULT_FEDERATED_MENTION_CLASS;
+
+#[no_mangle]
+pub static TWITTER_TEXT_DEFAULT_FEDERATED_MENTION_URL_TEMPLATE: &str =
+    twitter_text::autolinker::DEFAULT_FEDERATED_MENTION_URL_TEMPLATE;
+
 #[no_mangle]
 pub static TWITTER_TEXT_DEFAULT_INVISIBLE_TAG_ATTRS: &str =
     twitter_text::autolinker::DEFAULT_INVISIBLE_TAG_ATTRS;
@@ -1,6 +1,6 @@
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
-use twitter_text_config::{Configuration, Range};
+use twitter_text_config::{ConfigError, Configuration, Range};
 
 /* ============================================================================
  * C-compatible types matching the C header
@@ -123,12 +123,119 @@ pub extern "C" fn twitter_text_config_from_json(json: *const c_char) -> *mut Con
         Err(_) => return std::ptr::null_mut(),
     };
 
-    match serde_json::from_str::<Configuration>(json_str) {
+    match Configuration::try_from_json(json_str) {
         Ok(config) => Box::into_raw(Box::new(config)),
         Err(_) => std::ptr::null_mut(),
     }
 }
 
+/// Result codes for the fallible configuration loaders.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwitterTextConfigError {
+    Ok = 0,
+    NullArgument = 1,
+    InvalidUtf8 = 2,
+    Io = 3,
+    Json = 4,
+    Invalid = 5,
+}
+
+impl From<&ConfigError> for TwitterTextConfigError {
+    fn from(e: &ConfigError) -> Self {
+        match e {
+            ConfigError::Io(_) => TwitterTextConfigError::Io,
+            ConfigError::Json(_) => TwitterTextConfigError::Json,
+            _ => TwitterTextConfigError::Invalid,
+        }
+    }
+}
+
+fn config_result(
+    result: Result<Configuration, ConfigError>,
+    out: *mut *mut Configuration,
+) -> TwitterTextConfigError {
+    match result {
+        Ok(config) => {
+            unsafe {
+                *out = Box::into_raw(Box::new(config));
+            }
+            TwitterTextConfigError::Ok
+        }
+        Err(e) => TwitterTextConfigError::from(&e),
+    }
+}
+
+/// Parse and validate a configuration from JSON. On success, stores a new
+/// configuration in `out`; otherwise `out` is left untouched.
+#[no_mangle]
+pub extern "C" fn twitter_text_config_try_from_json(
+    json: *const c_char,
+    out: *mut *mut Configuration,
+) -> TwitterTextConfigError {
+    if json.is_null() || out.is_null() {
+        return TwitterTextConfigError::NullArgument;
+    }
+    let json_str = match unsafe { CStr::from_ptr(json) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return TwitterTextConfigError::InvalidUtf8,
+    };
+    config_result(Configuration::try_from_json(json_str), out)
+}
+
+/// Load and validate a configuration from a JSON file. On success, stores a
+/// new configuration in `out`; otherwise `out` is left untouched.
+#[no_mangle]
+pub extern "C" fn twitter_text_config_try_from_path(
+    path: *const c_char,
+    out: *mut *mut Configuration,
+) -> TwitterTextConfigError {
+    if path.is_null() || out.is_null() {
+        return TwitterTextConfigError::NullArgument;
+    }
+    let path_str = match unsafe { CStr::from_ptr(path) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return TwitterTextConfigError::InvalidUtf8,
+    };
+    config_result(Configuration::try_from_path(path_str), out)
+}
+
+/// Apply a partial JSON overlay to `base` and validate the result. On
+/// success, stores a new configuration in `out`; `base` is not modified.
+#[no_mangle]
+pub extern "C" fn twitter_text_config_try_with_overlay_json(
+    base: *mut Configuration,
+    json: *const c_char,
+    out: *mut *mut Configuration,
+) -> TwitterTextConfigError {
+    if base.is_null() || json.is_null() || out.is_null() {
+        return TwitterTextConfigError::NullArgument;
+    }
+    let json_str = match unsafe { CStr::from_ptr(json) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return TwitterTextConfigError::InvalidUtf8,
+    };
+    let base = unsafe { &*base };
+    config_result(base.with_overlay_json(json_str), out)
+}
+
+/// The overlay that turns `base` into `config`, as JSON. Free the result with
+/// twitter_text_string_free.
+#[no_mangle]
+pub extern "C" fn twitter_text_config_diff_json(
+    config: *mut Configuration,
+    base: *mut Configuration,
+) -> *mut c_char {
+    if config.is_null() || base.is_null() {
+        return std::ptr::null_mut();
+    }
+    let (config, base) = unsafe { (&*config, &*base) };
+    match serde_json::to_string(&config.diff(base)) {
+        Ok(json) => CString::new(json).unwrap_or_default().into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn twitter_text_config_free(config: *mut Configuration) {
     if !config.is_null() {
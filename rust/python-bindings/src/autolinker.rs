@@ -1,33 +1,16 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use std::sync::Arc;
 use twitter_text::autolinker::{
-    AddAttributeModifier as RustAddAttributeModifier, Autolinker as RustAutolinker,
-    ReplaceClassModifier as RustReplaceClassModifier, DEFAULT_CASHTAG_CLASS,
-    DEFAULT_CASHTAG_URL_BASE, DEFAULT_HASHTAG_CLASS, DEFAULT_HASHTAG_URL_BASE,
-    DEFAULT_INVISIBLE_TAG_ATTRS, DEFAULT_LIST_CLASS, DEFAULT_LIST_URL_BASE, DEFAULT_USERNAME_CLASS,
-    DEFAULT_USERNAME_URL_BASE,
+    AddAttributeModifier as RustAddAttributeModifier, AutolinkOptions,
+    Autolinker as RustAutolinker, EmojiRendering, ReplaceClassModifier as RustReplaceClassModifier,
 };
 use twitter_text::entity;
-use twitter_text::extractor::{Extract, Extractor};
+use twitter_text::segment::Segment as RustSegment;
 
 #[pyclass]
 pub struct Autolinker {
-    no_follow: bool,
-    url_class: String,
-    url_target: String,
-    symbol_tag: String,
-    text_with_symbol_tag: String,
-    list_class: String,
-    username_class: String,
-    hashtag_class: String,
-    cashtag_class: String,
-    username_url_base: String,
-    list_url_base: String,
-    hashtag_url_base: String,
-    cashtag_url_base: String,
-    invisible_tag_attrs: String,
-    username_include_symbol: bool,
-    include_data_screen_name: bool,
+    options: AutolinkOptions,
     add_attribute_modifier: Option<AddAttributeModifier>,
     replace_class_modifier: Option<ReplaceClassModifier>,
     link_text_modifier: Option<LinkTextModifier>,
@@ -38,154 +21,217 @@ impl Autolinker {
     #[new]
     fn new() -> Self {
         Autolinker {
-            no_follow: false,
-            url_class: String::new(),
-            url_target: String::new(),
-            symbol_tag: String::new(),
-            text_with_symbol_tag: String::new(),
-            list_class: DEFAULT_LIST_CLASS.to_string(),
-            username_class: DEFAULT_USERNAME_CLASS.to_string(),
-            hashtag_class: DEFAULT_HASHTAG_CLASS.to_string(),
-            cashtag_class: DEFAULT_CASHTAG_CLASS.to_string(),
-            username_url_base: DEFAULT_USERNAME_URL_BASE.to_string(),
-            list_url_base: DEFAULT_LIST_URL_BASE.to_string(),
-            hashtag_url_base: DEFAULT_HASHTAG_URL_BASE.to_string(),
-            cashtag_url_base: DEFAULT_CASHTAG_URL_BASE.to_string(),
-            invisible_tag_attrs: DEFAULT_INVISIBLE_TAG_ATTRS.to_string(),
-            username_include_symbol: false,
-            include_data_screen_name: false,
+            options: AutolinkOptions::default(),
             add_attribute_modifier: None,
             replace_class_modifier: None,
             link_text_modifier: None,
         }
     }
 
+    /// An Autolinker with options from JSON, using camelCase keys.
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        let options = AutolinkOptions::try_from_json(json)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(Autolinker {
+            options,
+            add_attribute_modifier: None,
+            replace_class_modifier: None,
+            link_text_modifier: None,
+        })
+    }
+
+    /// The options as JSON, without the modifiers.
+    fn to_json(&self) -> String {
+        self.options.to_json()
+    }
+
     fn get_no_follow(&self) -> bool {
-        self.no_follow
+        self.options.no_follow
     }
 
     fn set_no_follow(&mut self, no_follow: bool) {
-        self.no_follow = no_follow;
+        self.options.no_follow = no_follow;
     }
 
     fn get_url_class(&self) -> &str {
-        &self.url_class
+        &self.options.url_class
     }
 
     fn set_url_class(&mut self, url_class: String) {
-        self.url_class = url_class;
+        self.options.url_class = url_class;
     }
 
     fn get_url_target(&self) -> &str {
-        &self.url_target
+        &self.options.url_target
     }
 
     fn set_url_target(&mut self, url_target: String) {
-        self.url_target = url_target;
+        self.options.url_target = url_target;
     }
 
     fn get_symbol_tag(&self) -> &str {
-        &self.symbol_tag
+        &self.options.symbol_tag
     }
 
     fn set_symbol_tag(&mut self, symbol_tag: String) {
-        self.symbol_tag = symbol_tag;
+        self.options.symbol_tag = symbol_tag;
     }
 
     fn get_text_with_symbol_tag(&self) -> &str {
-        &self.text_with_symbol_tag
+        &self.options.text_with_symbol_tag
     }
 
     fn set_text_with_symbol_tag(&mut self, tag: String) {
-        self.text_with_symbol_tag = tag;
+        self.options.text_with_symbol_tag = tag;
     }
 
     fn get_list_class(&self) -> &str {
-        &self.list_class
+        &self.options.list_class
     }
 
     fn set_list_class(&mut self, list_class: String) {
-        self.list_class = list_class;
+        self.options.list_class = list_class;
     }
 
     fn get_username_class(&self) -> &str {
-        &self.username_class
+        &self.options.username_class
     }
 
     fn set_username_class(&mut self, username_class: String) {
-        self.username_class = username_class;
+        self.options.username_class = username_class;
     }
 
     fn get_hashtag_class(&self) -> &str {
-        &self.hashtag_class
+        &self.options.hashtag_class
     }
 
     fn set_hashtag_class(&mut self, hashtag_class: String) {
-        self.hashtag_class = hashtag_class;
+        self.options.hashtag_class = hashtag_class;
     }
 
     fn get_cashtag_class(&self) -> &str {
-        &self.cashtag_class
+        &self.options.cashtag_class
     }
 
     fn set_cashtag_class(&mut self, cashtag_class: String) {
-        self.cashtag_class = cashtag_class;
+        self.options.cashtag_class = cashtag_class;
     }
 
     fn get_username_url_base(&self) -> &str {
-        &self.username_url_base
+        &self.options.username_url_base
     }
 
     fn set_username_url_base(&mut self, base: String) {
-        self.username_url_base = base;
+        self.options.username_url_base = base;
     }
 
     fn get_list_url_base(&self) -> &str {
-        &self.list_url_base
+        &self.options.list_url_base
     }
 
     fn set_list_url_base(&mut self, base: String) {
-        self.list_url_base = base;
+        self.options.list_url_base = base;
     }
 
     fn get_hashtag_url_base(&self) -> &str {
-        &self.hashtag_url_base
+        &self.options.hashtag_url_base
     }
 
     fn set_hashtag_url_base(&mut self, base: String) {
-        self.hashtag_url_base = base;
+        self.options.hashtag_url_base = base;
     }
 
     fn get_cashtag_url_base(&self) -> &str {
-        &self.cashtag_url_base
+        &self.options.cashtag_url_base
     }
 
     fn set_cashtag_url_base(&mut self, base: String) {
-        self.cashtag_url_base = base;
+        self.options.cashtag_url_base = base;
+    }
+
+    fn get_federated_mention_class(&self) -> &str {
+        &self.options.federated_mention_class
+    }
+
+    fn set_federated_mention_class(&mut self, class: String) {
+        self.options.federated_mention_class = class;
+    }
+
+    fn get_federated_mention_url_template(&self) -> &str {
+        &self.options.federated_mention_url_template
+    }
+
+    fn set_federated_mention_url_template(&mut self, template: String) {
+        self.options.federated_mention_url_template = template;
+    }
+
+    fn get_federated_mention_h_card(&self) -> bool {
+        self.options.federated_mention_h_card
+    }
+
+    fn set_federated_mention_h_card(&mut self, h_card: bool) {
+        self.options.federated_mention_h_card = h_card;
+    }
+
+    fn get_strict(&self) -> bool {
+        self.options.strict
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.options.strict = strict;
+    }
+
+    /// "none", "image" or "span".
+    fn get_emoji_rendering(&self) -> &str {
+        self.options.emoji_rendering.name()
+    }
+
+    fn set_emoji_rendering(&mut self, rendering: &str) -> PyResult<()> {
+        self.options.emoji_rendering = EmojiRendering::from_name(rendering).ok_or_else(|| {
+            PyValueError::new_err(format!("Unknown emoji rendering: {}", rendering))
+        })?;
+        Ok(())
+    }
+
+    fn get_emoji_class(&self) -> &str {
+        &self.options.emoji_class
+    }
+
+    fn set_emoji_class(&mut self, emoji_class: String) {
+        self.options.emoji_class = emoji_class;
+    }
+
+    fn get_emoji_image_url_template(&self) -> &str {
+        &self.options.emoji_image_url_template
+    }
+
+    fn set_emoji_image_url_template(&mut self, template: String) {
+        self.options.emoji_image_url_template = template;
     }
 
     fn get_invisible_tag_attrs(&self) -> &str {
-        &self.invisible_tag_attrs
+        &self.options.invisible_tag_attrs
     }
 
     fn set_invisible_tag_attrs(&mut self, attrs: String) {
-        self.invisible_tag_attrs = attrs;
+        self.options.invisible_tag_attrs = attrs;
     }
 
     fn get_username_include_symbol(&self) -> bool {
-        self.username_include_symbol
+        self.options.username_include_symbol
     }
 
     fn set_username_include_symbol(&mut self, include: bool) {
-        self.username_include_symbol = include;
+        self.options.username_include_symbol = include;
     }
 
     fn get_include_data_screen_name(&self) -> bool {
-        self.include_data_screen_name
+        self.options.include_data_screen_name
     }
 
     fn set_include_data_screen_name(&mut self, include: bool) {
-        self.include_data_screen_name = include;
+        self.options.include_data_screen_name = include;
     }
 
     fn set_add_attribute_modifier(&mut self, modifier: AddAttributeModifier) {
@@ -220,6 +266,10 @@ impl Autolinker {
         self.to_rust_autolinker().autolink_cashtags(text)
     }
 
+    fn autolink_federated(&self, text: &str) -> String {
+        self.to_rust_autolinker().autolink_federated(text)
+    }
+
     fn autolink_entities(&self, text: &str, entities: Vec<Entity>) -> String {
         // Keep the owned entities alive while we use them
         let owned = entities;
@@ -233,19 +283,25 @@ impl Autolinker {
                 list_slug: &e.list_slug,
                 display_url: &e.display_url,
                 expanded_url: &e.expanded_url,
+                host_span: None,
             })
             .collect();
 
         self.to_rust_autolinker()
             .autolink_entities(text, &rust_entities)
     }
+
+    fn segments(&self, text: &str) -> Vec<Segment> {
+        self.to_rust_autolinker()
+            .segments(text)
+            .iter()
+            .map(Segment::from)
+            .collect()
+    }
 }
 
 impl Autolinker {
     fn to_rust_autolinker(&self) -> RustAutolinker<'_> {
-        let mut extractor = Extractor::new();
-        extractor.set_extract_url_without_protocol(false);
-
         let link_attribute_modifier: Option<
             Box<dyn twitter_text::autolinker::LinkAttributeModifier>,
         > = if let Some(ref modifier) = self.add_attribute_modifier {
@@ -271,27 +327,10 @@ impl Autolinker {
                 None
             };
 
-        RustAutolinker {
-            no_follow: self.no_follow,
-            url_class: &self.url_class,
-            url_target: &self.url_target,
-            symbol_tag: &self.symbol_tag,
-            text_with_symbol_tag: &self.text_with_symbol_tag,
-            list_class: &self.list_class,
-            username_class: &self.username_class,
-            hashtag_class: &self.hashtag_class,
-            cashtag_class: &self.cashtag_class,
-            username_url_base: &self.username_url_base,
-            list_url_base: &self.list_url_base,
-            hashtag_url_base: &self.hashtag_url_base,
-            cashtag_url_base: &self.cashtag_url_base,
-            invisible_tag_attrs: &self.invisible_tag_attrs,
-            username_include_symbol: self.username_include_symbol,
-            include_data_screen_name: self.include_data_screen_name,
-            extractor,
-            link_attribute_modifier,
-            link_text_modifier,
-        }
+        let mut autolinker = self.options.autolinker();
+        autolinker.link_attribute_modifier = link_attribute_modifier;
+        autolinker.link_text_modifier = link_text_modifier;
+        autolinker
     }
 }
 
@@ -318,6 +357,7 @@ impl AddAttributeModifier {
                 "HASHTAG" => Some(entity::Type::HASHTAG),
                 "MENTION" => Some(entity::Type::MENTION),
                 "CASHTAG" => Some(entity::Type::CASHTAG),
+                "FEDERATEDMENTION" => Some(entity::Type::FEDERATEDMENTION),
                 _ => None,
             })
             .collect();
@@ -439,6 +479,7 @@ impl Entity {
             "HASHTAG" => entity::Type::HASHTAG,
             "MENTION" => entity::Type::MENTION,
             "CASHTAG" => entity::Type::CASHTAG,
+            "FEDERATEDMENTION" => entity::Type::FEDERATEDMENTION,
             _ => {
                 return Err(pyo3::exceptions::PyValueError::new_err(format!(
                     "Invalid entity type: {}",
@@ -470,6 +511,7 @@ impl Entity {
             "HASHTAG" => entity::Type::HASHTAG,
             "MENTION" => entity::Type::MENTION,
             "CASHTAG" => entity::Type::CASHTAG,
+            "FEDERATEDMENTION" => entity::Type::FEDERATEDMENTION,
             _ => {
                 return Err(pyo3::exceptions::PyValueError::new_err(format!(
                     "Invalid entity type: {}",
@@ -480,3 +522,142 @@ impl Entity {
         Ok(())
     }
 }
+
+/// A piece of auto-linked text. `kind` is "text", "hashtag", "cashtag",
+/// "mention", "list", "federated_mention" or "url"; fields that don't apply to a kind are None.
+#[pyclass(skip_from_py_object)]
+#[derive(Clone, Default)]
+pub struct Segment {
+    #[pyo3(get)]
+    kind: String,
+    #[pyo3(get)]
+    text: String,
+    #[pyo3(get)]
+    start: i32,
+    #[pyo3(get)]
+    end: i32,
+    #[pyo3(get)]
+    href: Option<String>,
+    #[pyo3(get)]
+    value: Option<String>,
+    #[pyo3(get)]
+    symbol: Option<String>,
+    #[pyo3(get)]
+    list_slug: Option<String>,
+    #[pyo3(get)]
+    domain: Option<String>,
+    #[pyo3(get)]
+    include_symbol: Option<bool>,
+    #[pyo3(get)]
+    rtl: Option<bool>,
+    #[pyo3(get)]
+    display: Option<String>,
+    #[pyo3(get)]
+    expanded: Option<String>,
+    #[pyo3(get)]
+    display_before: Option<String>,
+    #[pyo3(get)]
+    display_after: Option<String>,
+    #[pyo3(get)]
+    leading_ellipsis: Option<bool>,
+    #[pyo3(get)]
+    trailing_ellipsis: Option<bool>,
+}
+
+#[pymethods]
+impl Segment {
+    fn __repr__(&self) -> String {
+        format!(
+            "Segment(kind={:?}, text={:?}, start={}, end={})",
+            self.kind, self.text, self.start, self.end
+        )
+    }
+}
+
+impl From<&RustSegment> for Segment {
+    fn from(segment: &RustSegment) -> Self {
+        let base = Segment {
+            text: segment.text().to_string(),
+            start: segment.start(),
+            end: segment.end(),
+            href: segment.href().map(str::to_string),
+            ..Segment::default()
+        };
+        match segment {
+            RustSegment::Text { .. } => Segment {
+                kind: "text".to_string(),
+                ..base
+            },
+            RustSegment::Hashtag {
+                symbol, tag, rtl, ..
+            } => Segment {
+                kind: "hashtag".to_string(),
+                value: Some(tag.clone()),
+                symbol: Some(symbol.to_string()),
+                rtl: Some(*rtl),
+                ..base
+            },
+            RustSegment::Cashtag { tag, .. } => Segment {
+                kind: "cashtag".to_string(),
+                value: Some(tag.clone()),
+                symbol: Some("$".to_string()),
+                ..base
+            },
+            RustSegment::Mention {
+                symbol,
+                screen_name,
+                include_symbol,
+                ..
+            } => Segment {
+                kind: "mention".to_string(),
+                value: Some(screen_name.clone()),
+                symbol: Some(symbol.to_string()),
+                include_symbol: Some(*include_symbol),
+                ..base
+            },
+            RustSegment::List {
+                symbol,
+                screen_name,
+                list_slug,
+                include_symbol,
+                ..
+            } => Segment {
+                kind: "list".to_string(),
+                value: Some(screen_name.clone()),
+                symbol: Some(symbol.to_string()),
+                list_slug: Some(list_slug.clone()),
+                include_symbol: Some(*include_symbol),
+                ..base
+            },
+            RustSegment::FederatedMention {
+                symbol,
+                user,
+                domain,
+                ..
+            } => Segment {
+                kind: "federated_mention".to_string(),
+                value: Some(user.clone()),
+                symbol: Some(symbol.to_string()),
+                domain: Some(domain.clone()),
+                ..base
+            },
+            RustSegment::Url {
+                href,
+                display,
+                expanded,
+                display_parts,
+                ..
+            } => Segment {
+                kind: "url".to_string(),
+                value: Some(href.clone()),
+                display: Some(display.clone()),
+                expanded: Some(expanded.clone()),
+                display_before: display_parts.as_ref().map(|p| p.before.clone()),
+                display_after: display_parts.as_ref().map(|p| p.after.clone()),
+                leading_ellipsis: display_parts.as_ref().map(|p| p.leading_ellipsis),
+                trailing_ellipsis: display_parts.as_ref().map(|p| p.trailing_ellipsis),
+                ..base
+            },
+        }
+    }
+}
@@ -1,6 +1,6 @@
+use pyo3::exceptions::{PyOSError, PyValueError};
 use pyo3::prelude::*;
-use std::path::PathBuf;
-use twitter_text_config::{Configuration, Range, WeightedRange as RustWeightedRange};
+use twitter_text_config::{ConfigError, Configuration, Range, WeightedRange as RustWeightedRange};
 
 #[pyclass(from_py_object)]
 #[derive(Clone)]
@@ -26,6 +26,14 @@ pub struct PyRange {
     pub end: i32,
 }
 
+/// Unreadable files raise OSError; bad JSON and invalid configurations raise ValueError.
+fn config_error_to_py(e: ConfigError) -> PyErr {
+    match e {
+        ConfigError::Io(_) => PyOSError::new_err(e.to_string()),
+        _ => PyValueError::new_err(e.to_string()),
+    }
+}
+
 impl From<&Range> for PyRange {
     fn from(r: &Range) -> Self {
         PyRange {
@@ -55,17 +63,16 @@ impl TwitterTextConfiguration {
 
     #[staticmethod]
     fn configuration_from_path(path: &str) -> PyResult<Self> {
-        let pathbuf = PathBuf::from(path);
-        Ok(TwitterTextConfiguration {
-            inner: Configuration::configuration_from_path(&pathbuf),
-        })
+        Configuration::try_from_path(path)
+            .map(|inner| TwitterTextConfiguration { inner })
+            .map_err(config_error_to_py)
     }
 
     #[staticmethod]
     fn configuration_from_json(json: &str) -> PyResult<Self> {
-        Ok(TwitterTextConfiguration {
-            inner: Configuration::configuration_from_json(json),
-        })
+        Configuration::try_from_json(json)
+            .map(|inner| TwitterTextConfiguration { inner })
+            .map_err(config_error_to_py)
     }
 
     #[staticmethod]
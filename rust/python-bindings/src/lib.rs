@@ -9,7 +9,7 @@ mod validator;
 
 use autolinker::{
     AddAttributeModifier, Autolinker, Entity as AutolinkEntity, LinkTextModifier,
-    ReplaceClassModifier,
+    ReplaceClassModifier, Segment,
 };
 use configuration::TwitterTextConfiguration;
 use extractor::{ExtractResult, Extractor, MentionResult, ValidatingExtractor};
@@ -34,6 +34,7 @@ fn twitter_text(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<AddAttributeModifier>()?;
     m.add_class::<ReplaceClassModifier>()?;
     m.add_class::<LinkTextModifier>()?;
+    m.add_class::<Segment>()?;
     m.add_class::<Hit>()?;
     m.add_class::<Hits>()?;
     m.add_class::<HitHighlighter>()?;
@@ -219,5 +219,100 @@ def test_autolink_entities():
     assert "blog.twitter.com/2011/05/twitte" in result
 
 
+def test_segments():
+    autolinker = twitter_text.Autolinker()
+    text = "hi @user #tag https://example.com"
+    segments = autolinker.segments(text)
+
+    assert [s.kind for s in segments] == [
+        "text",
+        "mention",
+        "text",
+        "hashtag",
+        "text",
+        "url",
+    ]
+    assert "".join(s.text for s in segments) == text
+    assert segments[0].href is None
+    assert segments[1].value == "user"
+    assert segments[1].href == "https://twitter.com/user"
+    assert (segments[1].start, segments[1].end) == (3, 8)
+    assert segments[3].symbol == "#"
+    assert segments[3].rtl is False
+    assert segments[5].display == "https://example.com"
+
+
+def test_autolink_federated():
+    autolinker = twitter_text.Autolinker()
+    assert (
+        autolinker.get_federated_mention_url_template() == "https://{domain}/@{user}"
+    )
+    assert autolinker.get_federated_mention_class() == "tweet-url federated-mention"
+
+    result = autolinker.autolink_federated("hi @alice@example.social")
+    assert result == (
+        'hi <a class="tweet-url federated-mention" '
+        'href="https://example.social/@alice">@alice@example.social</a>'
+    )
+
+    autolinker.set_federated_mention_url_template("https://{domain}/users/{user}")
+    autolinker.set_federated_mention_h_card(True)
+    result = autolinker.autolink_federated("@alice@example.social")
+    assert result.startswith('<span class="h-card"><a class="u-url mention')
+    assert 'href="https://example.social/users/alice"' in result
+    assert "@<span>alice</span></a></span>" in result
+
+
+def test_autolink_entities_federated():
+    autolinker = twitter_text.Autolinker()
+    entity = twitter_text.AutolinkEntity(
+        start=3,
+        end=22,
+        value="@a_b@example.social",
+        entity_type="FEDERATEDMENTION",
+    )
+    result = autolinker.autolink_entities("hi @a_b@example.social", [entity])
+    assert 'href="https://example.social/@a_b"' in result
+
+    autolinker.set_federated_mention_url_template("")
+    result = autolinker.autolink_entities("hi @a_b@example.social", [entity])
+    assert result == "hi @a_b@example.social"
+
+
+def test_from_json():
+    autolinker = twitter_text.Autolinker.from_json(
+        '{"noFollow": true, "hashtagClass": "tag"}'
+    )
+    assert autolinker.get_no_follow()
+    assert autolinker.get_hashtag_class() == "tag"
+    assert '"hashtagClass":"tag"' in autolinker.to_json()
+    assert 'class="tag"' in autolinker.autolink_hashtags("#rust")
+
+    with pytest.raises(ValueError):
+        twitter_text.Autolinker.from_json('{"noSuchOption": 1}')
+
+
+def test_strict():
+    autolinker = twitter_text.Autolinker()
+    assert not autolinker.get_strict()
+    autolinker.set_strict(True)
+    result = autolinker.autolink('"Tom & Jerry" #tag')
+    assert result.startswith("&quot;Tom &amp; Jerry&quot; <a ")
+
+
+def test_emoji_rendering():
+    autolinker = twitter_text.Autolinker()
+    assert autolinker.get_emoji_rendering() == "none"
+    autolinker.set_emoji_rendering("span")
+    assert autolinker.autolink("hi \U0001F600") == 'hi <span class="emoji">\U0001F600</span>'
+    autolinker.set_emoji_rendering("image")
+    autolinker.set_emoji_image_url_template("/emoji/{codepoints}.png")
+    result = autolinker.autolink("\u2764\ufe0f")
+    assert 'src="/emoji/2764.png"' in result
+    assert 'alt="\u2764\ufe0f"' in result
+    with pytest.raises(ValueError):
+        autolinker.set_emoji_rendering("svg")
+
+
 if __name__ == "__main__":
     raise SystemExit(pytest.main([__file__]))
@@ -47,6 +47,23 @@ def test_json():
         assert wr.weight == 200
 
 
+def test_missing_path():
+    with pytest.raises(OSError):
+        twitter_text.TwitterTextConfiguration.configuration_from_path(
+            "rust/cpp-bindings/test_data/no_such_config.json"
+        )
+
+
+def test_invalid_json():
+    with pytest.raises(ValueError):
+        twitter_text.TwitterTextConfiguration.configuration_from_json("{")
+    with pytest.raises(ValueError, match="scale"):
+        twitter_text.TwitterTextConfiguration.configuration_from_json(
+            '{"version": 3, "maxWeightedTweetLength": 280, "scale": 0, '
+            '"defaultWeight": 200, "transformedURLLength": 23, "ranges": []}'
+        )
+
+
 def test_accessors():
     config = twitter_text.TwitterTextConfiguration()
 
@@ -177,4 +177,40 @@ RSpec.describe Twittertext::Autolinker do
         expect(result).to match(/<a[^>]+>pre_<s>#<\/s><b>hash<\/b>_post<\/a>/)
         expect(result).to match(/<a[^>]+>pre_<s>@<\/s><b>mention<\/b>_post<\/a>/)
     end
+
+    it 'can link federated mentions' do
+        autolinker = Twittertext::Autolinker.new
+        expect(autolinker.get_federated_mention_url_template).to eq("https://{domain}/@{user}")
+
+        result = autolinker.autolink_federated("hi @alice@example.social")
+        expect(result).to eq('hi <a class="tweet-url federated-mention" href="https://example.social/@alice">@alice@example.social</a>')
+
+        autolinker.set_federated_mention_url_template("https://{domain}/users/{user}")
+        autolinker.set_federated_mention_h_card(true)
+        result = autolinker.autolink_federated("@alice@example.social")
+        expect(result).to start_with('<span class="h-card">')
+        expect(result).to include('href="https://example.social/users/alice"')
+        expect(result).to include('@<span>alice</span></a></span>')
+    end
+
+    it 'escapes all text in strict mode' do
+        autolinker = Twittertext::Autolinker.new
+        expect(autolinker.get_strict).to be false
+        autolinker.set_strict(true)
+        result = autolinker.autolink('"Tom & Jerry" #tag')
+        expect(result).to start_with('&quot;Tom &amp; Jerry&quot; <a ')
+    end
+
+    it 'wraps emoji in spans or images' do
+        autolinker = Twittertext::Autolinker.new
+        expect(autolinker.get_emoji_rendering).to eq('none')
+        autolinker.set_emoji_rendering('span')
+        expect(autolinker.autolink("hi \u{1F600}")).to eq("hi <span class=\"emoji\">\u{1F600}</span>")
+        autolinker.set_emoji_rendering('image')
+        autolinker.set_emoji_image_url_template('/emoji/{codepoints}.png')
+        result = autolinker.autolink("\u2764\uFE0F")
+        expect(result).to include('src="/emoji/2764.png"')
+        expect(result).to include("alt=\"\u2764\uFE0F\"")
+        expect { autolinker.set_emoji_rendering('svg') }.to raise_error(ArgumentError)
+    end
 end
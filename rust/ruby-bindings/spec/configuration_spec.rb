@@ -47,6 +47,23 @@ RSpec.describe Twittertext::TwitterTextConfiguration do
         end
     end
 
+    it 'raises IOError for a missing file' do
+        expect {
+            Twittertext::TwitterTextConfiguration.configuration_from_path("rust/cpp-bindings/test_data/no_such_config.json")
+        }.to raise_error(IOError)
+    end
+
+    it 'raises ArgumentError for bad json' do
+        expect {
+            Twittertext::TwitterTextConfiguration.configuration_from_json("{")
+        }.to raise_error(ArgumentError)
+        json = '{"version": 3, "maxWeightedTweetLength": 280, "scale": 0, ' \
+            '"defaultWeight": 200, "transformedURLLength": 23, "ranges": []}'
+        expect {
+            Twittertext::TwitterTextConfiguration.configuration_from_json(json)
+        }.to raise_error(ArgumentError, /scale/)
+    end
+
     it 'has working accessors' do
         config = Twittertext::TwitterTextConfiguration.new
 
@@ -1,30 +1,13 @@
-use std::cell::{Cell, RefCell};
+use std::cell::RefCell;
 use twitter_text::autolinker::{
-    AddAttributeModifier as RustAddAttributeModifier, Autolinker as RustAutolinker,
-    ReplaceClassModifier as RustReplaceClassModifier, DEFAULT_CASHTAG_CLASS,
-    DEFAULT_CASHTAG_URL_BASE, DEFAULT_HASHTAG_CLASS, DEFAULT_HASHTAG_URL_BASE,
-    DEFAULT_INVISIBLE_TAG_ATTRS, DEFAULT_LIST_CLASS, DEFAULT_LIST_URL_BASE, DEFAULT_USERNAME_CLASS,
-    DEFAULT_USERNAME_URL_BASE,
+    AddAttributeModifier as RustAddAttributeModifier, AutolinkOptions, EmojiRendering,
+    ReplaceClassModifier as RustReplaceClassModifier,
 };
 use twitter_text::entity;
 
 #[magnus::wrap(class = "Twittertext::Autolinker", free_immediately, size)]
 pub struct Autolinker {
-    no_follow: Cell<bool>,
-    url_class: RefCell<String>,
-    url_target: RefCell<String>,
-    symbol_tag: RefCell<String>,
-    text_with_symbol_tag: RefCell<String>,
-    list_class: RefCell<String>,
-    username_class: RefCell<String>,
-    hashtag_class: RefCell<String>,
-    cashtag_class: RefCell<String>,
-    username_url_base: RefCell<String>,
-    list_url_base: RefCell<String>,
-    hashtag_url_base: RefCell<String>,
-    cashtag_url_base: RefCell<String>,
-    invisible_tag_attrs: RefCell<String>,
-    username_include_symbol: Cell<bool>,
+    options: RefCell<AutolinkOptions>,
     add_attribute_modifier: RefCell<Option<AddAttributeModifier>>,
     replace_class_modifier: RefCell<Option<ReplaceClassModifier>>,
     link_text_modifier: RefCell<Option<LinkTextModifier>>,
@@ -37,20 +20,6 @@ unsafe impl Sync for Autolinker {}
 
 macro_rules! with_autolinker {
     ($self:expr, $text:expr, $method:ident) => {{
-        let url_class = $self.url_class.borrow().clone();
-        let url_target = $self.url_target.borrow().clone();
-        let symbol_tag = $self.symbol_tag.borrow().clone();
-        let text_with_symbol_tag = $self.text_with_symbol_tag.borrow().clone();
-        let list_class = $self.list_class.borrow().clone();
-        let username_class = $self.username_class.borrow().clone();
-        let hashtag_class = $self.hashtag_class.borrow().clone();
-        let cashtag_class = $self.cashtag_class.borrow().clone();
-        let username_url_base = $self.username_url_base.borrow().clone();
-        let list_url_base = $self.list_url_base.borrow().clone();
-        let hashtag_url_base = $self.hashtag_url_base.borrow().clone();
-        let cashtag_url_base = $self.cashtag_url_base.borrow().clone();
-        let invisible_tag_attrs = $self.invisible_tag_attrs.borrow().clone();
-
         let link_attribute_modifier: Option<
             Box<dyn twitter_text::autolinker::LinkAttributeModifier>,
         > = if let Some(ref modifier) = *$self.add_attribute_modifier.borrow() {
@@ -76,21 +45,8 @@ macro_rules! with_autolinker {
                 None
             };
 
-        let mut autolinker = RustAutolinker::new($self.no_follow.get());
-        autolinker.url_class = &url_class;
-        autolinker.url_target = &url_target;
-        autolinker.symbol_tag = &symbol_tag;
-        autolinker.text_with_symbol_tag = &text_with_symbol_tag;
-        autolinker.list_class = &list_class;
-        autolinker.username_class = &username_class;
-        autolinker.hashtag_class = &hashtag_class;
-        autolinker.cashtag_class = &cashtag_class;
-        autolinker.username_url_base = &username_url_base;
-        autolinker.list_url_base = &list_url_base;
-        autolinker.hashtag_url_base = &hashtag_url_base;
-        autolinker.cashtag_url_base = &cashtag_url_base;
-        autolinker.invisible_tag_attrs = &invisible_tag_attrs;
-        autolinker.username_include_symbol = $self.username_include_symbol.get();
+        let options = $self.options.borrow();
+        let mut autolinker = options.autolinker();
         autolinker.link_attribute_modifier = link_attribute_modifier;
         autolinker.link_text_modifier = link_text_modifier;
         autolinker.$method($text)
@@ -100,21 +56,7 @@ macro_rules! with_autolinker {
 impl Autolinker {
     pub fn ruby_new() -> Self {
         Autolinker {
-            no_follow: Cell::new(false),
-            url_class: RefCell::new(String::new()),
-            url_target: RefCell::new(String::new()),
-            symbol_tag: RefCell::new(String::new()),
-            text_with_symbol_tag: RefCell::new(String::new()),
-            list_class: RefCell::new(DEFAULT_LIST_CLASS.to_string()),
-            username_class: RefCell::new(DEFAULT_USERNAME_CLASS.to_string()),
-            hashtag_class: RefCell::new(DEFAULT_HASHTAG_CLASS.to_string()),
-            cashtag_class: RefCell::new(DEFAULT_CASHTAG_CLASS.to_string()),
-            username_url_base: RefCell::new(DEFAULT_USERNAME_URL_BASE.to_string()),
-            list_url_base: RefCell::new(DEFAULT_LIST_URL_BASE.to_string()),
-            hashtag_url_base: RefCell::new(DEFAULT_HASHTAG_URL_BASE.to_string()),
-            cashtag_url_base: RefCell::new(DEFAULT_CASHTAG_URL_BASE.to_string()),
-            invisible_tag_attrs: RefCell::new(DEFAULT_INVISIBLE_TAG_ATTRS.to_string()),
-            username_include_symbol: Cell::new(false),
+            options: RefCell::new(AutolinkOptions::default()),
             add_attribute_modifier: RefCell::new(None),
             replace_class_modifier: RefCell::new(None),
             link_text_modifier: RefCell::new(None),
@@ -122,123 +64,190 @@ impl Autolinker {
     }
 
     pub fn get_no_follow(&self) -> bool {
-        self.no_follow.get()
+        self.options.borrow().no_follow
     }
 
     pub fn set_no_follow(&self, no_follow: bool) {
-        self.no_follow.set(no_follow);
+        self.options.borrow_mut().no_follow = no_follow;
     }
 
     pub fn get_url_class(&self) -> String {
-        self.url_class.borrow().clone()
+        self.options.borrow().url_class.clone()
     }
 
     pub fn set_url_class(&self, url_class: String) {
-        *self.url_class.borrow_mut() = url_class;
+        self.options.borrow_mut().url_class = url_class;
     }
 
     pub fn get_url_target(&self) -> String {
-        self.url_target.borrow().clone()
+        self.options.borrow().url_target.clone()
     }
 
     pub fn set_url_target(&self, url_target: String) {
-        *self.url_target.borrow_mut() = url_target;
+        self.options.borrow_mut().url_target = url_target;
     }
 
     pub fn get_symbol_tag(&self) -> String {
-        self.symbol_tag.borrow().clone()
+        self.options.borrow().symbol_tag.clone()
     }
 
     pub fn set_symbol_tag(&self, symbol_tag: String) {
-        *self.symbol_tag.borrow_mut() = symbol_tag;
+        self.options.borrow_mut().symbol_tag = symbol_tag;
     }
 
     pub fn get_text_with_symbol_tag(&self) -> String {
-        self.text_with_symbol_tag.borrow().clone()
+        self.options.borrow().text_with_symbol_tag.clone()
     }
 
     pub fn set_text_with_symbol_tag(&self, tag: String) {
-        *self.text_with_symbol_tag.borrow_mut() = tag;
+        self.options.borrow_mut().text_with_symbol_tag = tag;
     }
 
     pub fn get_list_class(&self) -> String {
-        self.list_class.borrow().clone()
+        self.options.borrow().list_class.clone()
     }
 
     pub fn set_list_class(&self, list_class: String) {
-        *self.list_class.borrow_mut() = list_class;
+        self.options.borrow_mut().list_class = list_class;
     }
 
     pub fn get_username_class(&self) -> String {
-        self.username_class.borrow().clone()
+        self.options.borrow().username_class.clone()
     }
 
     pub fn set_username_class(&self, username_class: String) {
-        *self.username_class.borrow_mut() = username_class;
+        self.options.borrow_mut().username_class = username_class;
     }
 
     pub fn get_hashtag_class(&self) -> String {
-        self.hashtag_class.borrow().clone()
+        self.options.borrow().hashtag_class.clone()
     }
 
     pub fn set_hashtag_class(&self, hashtag_class: String) {
-        *self.hashtag_class.borrow_mut() = hashtag_class;
+        self.options.borrow_mut().hashtag_class = hashtag_class;
     }
 
     pub fn get_cashtag_class(&self) -> String {
-        self.cashtag_class.borrow().clone()
+        self.options.borrow().cashtag_class.clone()
     }
 
     pub fn set_cashtag_class(&self, cashtag_class: String) {
-        *self.cashtag_class.borrow_mut() = cashtag_class;
+        self.options.borrow_mut().cashtag_class = cashtag_class;
     }
 
     pub fn get_username_url_base(&self) -> String {
-        self.username_url_base.borrow().clone()
+        self.options.borrow().username_url_base.clone()
     }
 
     pub fn set_username_url_base(&self, base: String) {
-        *self.username_url_base.borrow_mut() = base;
+        self.options.borrow_mut().username_url_base = base;
     }
 
     pub fn get_list_url_base(&self) -> String {
-        self.list_url_base.borrow().clone()
+        self.options.borrow().list_url_base.clone()
     }
 
     pub fn set_list_url_base(&self, base: String) {
-        *self.list_url_base.borrow_mut() = base;
+        self.options.borrow_mut().list_url_base = base;
     }
 
     pub fn get_hashtag_url_base(&self) -> String {
-        self.hashtag_url_base.borrow().clone()
+        self.options.borrow().hashtag_url_base.clone()
     }
 
     pub fn set_hashtag_url_base(&self, base: String) {
-        *self.hashtag_url_base.borrow_mut() = base;
+        self.options.borrow_mut().hashtag_url_base = base;
     }
 
     pub fn get_cashtag_url_base(&self) -> String {
-        self.cashtag_url_base.borrow().clone()
+        self.options.borrow().cashtag_url_base.clone()
     }
 
     pub fn set_cashtag_url_base(&self, base: String) {
-        *self.cashtag_url_base.borrow_mut() = base;
+        self.options.borrow_mut().cashtag_url_base = base;
+    }
+
+    pub fn get_federated_mention_class(&self) -> String {
+        self.options.borrow().federated_mention_class.clone()
+    }
+
+    pub fn set_federated_mention_class(&self, class: String) {
+        self.options.borrow_mut().federated_mention_class = class;
+    }
+
+    pub fn get_federated_mention_url_template(&self) -> String {
+        self.options.borrow().federated_mention_url_template.clone()
+    }
+
+    pub fn set_federated_mention_url_template(&self, template: String) {
+        self.options.borrow_mut().federated_mention_url_template = template;
+    }
+
+    pub fn get_federated_mention_h_card(&self) -> bool {
+        self.options.borrow().federated_mention_h_card
+    }
+
+    pub fn set_federated_mention_h_card(&self, h_card: bool) {
+        self.options.borrow_mut().federated_mention_h_card = h_card;
+    }
+
+    pub fn get_strict(&self) -> bool {
+        self.options.borrow().strict
+    }
+
+    pub fn set_strict(&self, strict: bool) {
+        self.options.borrow_mut().strict = strict;
+    }
+
+    pub fn get_emoji_rendering(&self) -> String {
+        self.options.borrow().emoji_rendering.name().to_string()
+    }
+
+    pub fn set_emoji_rendering(
+        ruby: &magnus::Ruby,
+        rb_self: &Self,
+        rendering: String,
+    ) -> Result<(), magnus::Error> {
+        let rendering = EmojiRendering::from_name(&rendering).ok_or_else(|| {
+            magnus::Error::new(
+                ruby.exception_arg_error(),
+                format!("Unknown emoji rendering: {}", rendering),
+            )
+        })?;
+        rb_self.options.borrow_mut().emoji_rendering = rendering;
+        Ok(())
+    }
+
+    pub fn get_emoji_class(&self) -> String {
+        self.options.borrow().emoji_class.clone()
+    }
+
+    pub fn set_emoji_class(&self, emoji_class: String) {
+        self.options.borrow_mut().emoji_class = emoji_class;
+    }
+
+    pub fn get_emoji_image_url_template(&self) -> String {
+        self.options.borrow().emoji_image_url_template.clone()
+    }
+
+    pub fn set_emoji_image_url_template(&self, template: String) {
+        self.options.borrow_mut().emoji_image_url_template = template;
     }
 
     pub fn get_invisible_tag_attrs(&self) -> String {
-        self.invisible_tag_attrs.borrow().clone()
+        self.options.borrow().invisible_tag_attrs.clone()
     }
 
     pub fn set_invisible_tag_attrs(&self, attrs: String) {
-        *self.invisible_tag_attrs.borrow_mut() = attrs;
+        self.options.borrow_mut().invisible_tag_attrs = attrs;
     }
 
     pub fn get_username_include_symbol(&self) -> bool {
-        self.username_include_symbol.get()
+        self.options.borrow().username_include_symbol
     }
 
     pub fn set_username_include_symbol(&self, include: bool) {
-        self.username_include_symbol.set(include);
+        self.options.borrow_mut().username_include_symbol = include;
     }
 
     pub fn autolink(&self, text: String) -> String {
@@ -261,6 +270,10 @@ impl Autolinker {
         with_autolinker!(self, &text, autolink_cashtags)
     }
 
+    pub fn autolink_federated(&self, text: String) -> String {
+        with_autolinker!(self, &text, autolink_federated)
+    }
+
     pub fn set_add_attribute_modifier(&self, modifier: &AddAttributeModifier) {
         *self.add_attribute_modifier.borrow_mut() = Some(modifier.clone());
     }
@@ -295,6 +308,7 @@ impl AddAttributeModifier {
                 "HASHTAG" => Some(entity::Type::HASHTAG),
                 "MENTION" => Some(entity::Type::MENTION),
                 "CASHTAG" => Some(entity::Type::CASHTAG),
+                "FEDERATEDMENTION" => Some(entity::Type::FEDERATEDMENTION),
                 _ => None,
             })
             .collect();
@@ -1,7 +1,7 @@
-use magnus::{Error, RArray};
+use magnus::{Error, RArray, Ruby};
 use std::cell::Cell;
 use twitter_text_config::{
-    Configuration as RustConfiguration, Range, WeightedRange as RustWeightedRange,
+    ConfigError, Configuration as RustConfiguration, Range, WeightedRange as RustWeightedRange,
 };
 
 #[magnus::wrap(
@@ -89,6 +89,14 @@ impl From<&RustWeightedRange> for WeightedRange {
     }
 }
 
+/// Unreadable files raise IOError; bad JSON and invalid configurations raise ArgumentError.
+fn config_error(ruby: &Ruby, e: ConfigError) -> Error {
+    match e {
+        ConfigError::Io(_) => Error::new(ruby.exception_io_error(), e.to_string()),
+        _ => Error::new(ruby.exception_arg_error(), e.to_string()),
+    }
+}
+
 impl TwitterTextConfiguration {
     pub fn ruby_new() -> Self {
         TwitterTextConfiguration {
@@ -96,17 +104,15 @@ impl TwitterTextConfiguration {
         }
     }
 
-    pub fn configuration_from_path(path: String) -> Result<Self, Error> {
-        use std::path::PathBuf;
-        let path_buf = PathBuf::from(path);
-        let config = RustConfiguration::configuration_from_path(&path_buf);
+    pub fn configuration_from_path(ruby: &Ruby, path: String) -> Result<Self, Error> {
+        let config = RustConfiguration::try_from_path(path).map_err(|e| config_error(ruby, e))?;
         Ok(TwitterTextConfiguration {
             inner: std::cell::RefCell::new(config),
         })
     }
 
-    pub fn configuration_from_json(json: String) -> Result<Self, Error> {
-        let config = RustConfiguration::configuration_from_json(&json);
+    pub fn configuration_from_json(ruby: &Ruby, json: String) -> Result<Self, Error> {
+        let config = RustConfiguration::try_from_json(&json).map_err(|e| config_error(ruby, e))?;
         Ok(TwitterTextConfiguration {
             inner: std::cell::RefCell::new(config),
         })
@@ -274,6 +274,50 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
         "set_cashtag_url_base",
         method!(Autolinker::set_cashtag_url_base, 1),
     )?;
+    autolinker_class.define_method(
+        "get_federated_mention_class",
+        method!(Autolinker::get_federated_mention_class, 0),
+    )?;
+    autolinker_class.define_method(
+        "set_federated_mention_class",
+        method!(Autolinker::set_federated_mention_class, 1),
+    )?;
+    autolinker_class.define_method(
+        "get_federated_mention_url_template",
+        method!(Autolinker::get_federated_mention_url_template, 0),
+    )?;
+    autolinker_class.define_method(
+        "set_federated_mention_url_template",
+        method!(Autolinker::set_federated_mention_url_template, 1),
+    )?;
+    autolinker_class.define_method(
+        "get_federated_mention_h_card",
+        method!(Autolinker::get_federated_mention_h_card, 0),
+    )?;
+    autolinker_class.define_method(
+        "set_federated_mention_h_card",
+        method!(Autolinker::set_federated_mention_h_card, 1),
+    )?;
+    autolinker_class.define_method("get_strict", method!(Autolinker::get_strict, 0))?;
+    autolinker_class.define_method("set_strict", method!(Autolinker::set_strict, 1))?;
+    autolinker_class.define_method(
+        "get_emoji_rendering",
+        method!(Autolinker::get_emoji_rendering, 0),
+    )?;
+    autolinker_class.define_method(
+        "set_emoji_rendering",
+        method!(Autolinker::set_emoji_rendering, 1),
+    )?;
+    autolinker_class.define_method("get_emoji_class", method!(Autolinker::get_emoji_class, 0))?;
+    autolinker_class.define_method("set_emoji_class", method!(Autolinker::set_emoji_class, 1))?;
+    autolinker_class.define_method(
+        "get_emoji_image_url_template",
+        method!(Autolinker::get_emoji_image_url_template, 0),
+    )?;
+    autolinker_class.define_method(
+        "set_emoji_image_url_template",
+        method!(Autolinker::set_emoji_image_url_template, 1),
+    )?;
     autolinker_class.define_method(
         "get_invisible_tag_attrs",
         method!(Autolinker::get_invisible_tag_attrs, 0),
@@ -306,6 +350,10 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
         "autolink_cashtags",
         method!(Autolinker::autolink_cashtags, 1),
     )?;
+    autolinker_class.define_method(
+        "autolink_federated",
+        method!(Autolinker::autolink_federated, 1),
+    )?;
     autolinker_class.define_method(
         "set_add_attribute_modifier",
         method!(Autolinker::set_add_attribute_modifier, 1),
@@ -0,0 +1,312 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::autolinker::*;
+use crate::entity::Entity;
+use serde_derive::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+
+/// Owned settings for an [Autolinker].
+///
+/// [Autolinker] borrows its strings, so it can't outlive the values it was
+/// built from. `AutolinkOptions` owns them, can be read from JSON with
+/// camelCase keys, and is `Send + Sync`, so it can live in long-lived state
+/// and be shared between threads. Its rendering methods, like
+/// [AutolinkOptions::autolink], can be called through an `Arc` from any
+/// thread; [AutolinkOptions::autolinker] borrows an [Autolinker] from it for
+/// the rest of the API.
+///
+/// ```
+/// use twitter_text::autolinker::AutolinkOptions;
+///
+/// let options = AutolinkOptions::try_from_json(
+///     r#"{"noFollow": true, "hashtagUrlTemplate": "https://x.com/hashtag/{tag}"}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     options.autolink_hashtags("#rust"),
+///     "<a href=\"https://x.com/hashtag/rust\" title=\"#rust\" \
+///      class=\"tweet-url hashtag\" rel=\"nofollow\">#rust</a>"
+/// );
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct AutolinkOptions {
+    pub no_follow: bool,
+    pub url_class: String,
+    pub url_target: String,
+    pub symbol_tag: String,
+    pub text_with_symbol_tag: String,
+    pub list_class: String,
+    pub username_class: String,
+    pub hashtag_class: String,
+    pub cashtag_class: String,
+    pub username_url_base: String,
+    pub list_url_base: String,
+    pub hashtag_url_base: String,
+    pub cashtag_url_base: String,
+    pub hashtag_url_template: String,
+    pub cashtag_url_template: String,
+    pub username_url_template: String,
+    pub list_url_template: String,
+    pub encode_url_base_values: bool,
+    pub federated_mention_class: String,
+    pub federated_mention_url_template: String,
+    pub federated_mention_h_card: bool,
+    pub invisible_tag_attrs: String,
+    pub username_include_symbol: bool,
+    pub include_data_screen_name: bool,
+    pub strict: bool,
+    pub emoji_rendering: EmojiRendering,
+    pub emoji_class: String,
+    pub emoji_image_url_template: String,
+    #[serde(skip)]
+    pub link_attribute_modifier: Option<Arc<dyn LinkAttributeModifier + Send + Sync>>,
+    #[serde(skip)]
+    pub link_text_modifier: Option<Arc<dyn LinkTextModifier + Send + Sync>>,
+    #[serde(skip)]
+    pub entity_renderer: Option<Arc<dyn EntityRenderer + Send + Sync>>,
+}
+
+impl Default for AutolinkOptions {
+    fn default() -> Self {
+        AutolinkOptions {
+            no_follow: false,
+            url_class: String::new(),
+            url_target: String::new(),
+            symbol_tag: String::new(),
+            text_with_symbol_tag: String::new(),
+            list_class: DEFAULT_LIST_CLASS.to_string(),
+            username_class: DEFAULT_USERNAME_CLASS.to_string(),
+            hashtag_class: DEFAULT_HASHTAG_CLASS.to_string(),
+            cashtag_class: DEFAULT_CASHTAG_CLASS.to_string(),
+            username_url_base: DEFAULT_USERNAME_URL_BASE.to_string(),
+            list_url_base: DEFAULT_LIST_URL_BASE.to_string(),
+            hashtag_url_base: DEFAULT_HASHTAG_URL_BASE.to_string(),
+            cashtag_url_base: DEFAULT_CASHTAG_URL_BASE.to_string(),
+            hashtag_url_template: String::new(),
+            cashtag_url_template: String::new(),
+            username_url_template: String::new(),
+            list_url_template: String::new(),
+            encode_url_base_values: false,
+            federated_mention_class: DEFAULT_FEDERATED_MENTION_CLASS.to_string(),
+            federated_mention_url_template: DEFAULT_FEDERATED_MENTION_URL_TEMPLATE.to_string(),
+            federated_mention_h_card: false,
+            invisible_tag_attrs: DEFAULT_INVISIBLE_TAG_ATTRS.to_string(),
+            username_include_symbol: false,
+            include_data_screen_name: false,
+            strict: false,
+            emoji_rendering: EmojiRendering::None,
+            emoji_class: DEFAULT_EMOJI_CLASS.to_string(),
+            emoji_image_url_template: DEFAULT_EMOJI_IMAGE_URL_TEMPLATE.to_string(),
+            link_attribute_modifier: None,
+            link_text_modifier: None,
+            entity_renderer: None,
+        }
+    }
+}
+
+impl fmt::Debug for AutolinkOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The modifiers aren't Debug, so only their presence is shown.
+        let json = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        f.debug_struct("AutolinkOptions")
+            .field("options", &json)
+            .field(
+                "link_attribute_modifier",
+                &self.link_attribute_modifier.is_some(),
+            )
+            .field("link_text_modifier", &self.link_text_modifier.is_some())
+            .field("entity_renderer", &self.entity_renderer.is_some())
+            .finish()
+    }
+}
+
+impl AutolinkOptions {
+    /// Parse options from JSON. Missing fields take their default values;
+    /// unknown fields are an error.
+    pub fn try_from_json(json: &str) -> Result<AutolinkOptions, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serialize the options, without the modifiers, as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// An [Autolinker] that borrows these options.
+    pub fn autolinker(&self) -> Autolinker<'_> {
+        let mut autolinker = Autolinker::new(self.no_follow);
+        autolinker.url_class = &self.url_class;
+        autolinker.url_target = &self.url_target;
+        autolinker.symbol_tag = &self.symbol_tag;
+        autolinker.text_with_symbol_tag = &self.text_with_symbol_tag;
+        autolinker.list_class = &self.list_class;
+        autolinker.username_class = &self.username_class;
+        autolinker.hashtag_class = &self.hashtag_class;
+        autolinker.cashtag_class = &self.cashtag_class;
+        autolinker.username_url_base = &self.username_url_base;
+        autolinker.list_url_base = &self.list_url_base;
+        autolinker.hashtag_url_base = &self.hashtag_url_base;
+        autolinker.cashtag_url_base = &self.cashtag_url_base;
+        autolinker.hashtag_url_template = &self.hashtag_url_template;
+        autolinker.cashtag_url_template = &self.cashtag_url_template;
+        autolinker.username_url_template = &self.username_url_template;
+        autolinker.list_url_template = &self.list_url_template;
+        autolinker.encode_url_base_values = self.encode_url_base_values;
+        autolinker.federated_mention_class = &self.federated_mention_class;
+        autolinker.federated_mention_url_template = &self.federated_mention_url_template;
+        autolinker.federated_mention_h_card = self.federated_mention_h_card;
+        autolinker.invisible_tag_attrs = &self.invisible_tag_attrs;
+        autolinker.username_include_symbol = self.username_include_symbol;
+        autolinker.include_data_screen_name = self.include_data_screen_name;
+        autolinker.strict = self.strict;
+        autolinker.emoji_rendering = self.emoji_rendering;
+        autolinker.emoji_class = &self.emoji_class;
+        autolinker.emoji_image_url_template = &self.emoji_image_url_template;
+        autolinker.link_attribute_modifier = self
+            .link_attribute_modifier
+            .clone()
+            .map(|m| Box::new(m) as Box<dyn LinkAttributeModifier>);
+        autolinker.link_text_modifier = self
+            .link_text_modifier
+            .clone()
+            .map(|m| Box::new(m) as Box<dyn LinkTextModifier>);
+        autolinker.entity_renderer = self
+            .entity_renderer
+            .clone()
+            .map(|r| Box::new(r) as Box<dyn EntityRenderer>);
+        autolinker
+    }
+
+    /// Auto-link all entities. See [Autolinker::autolink].
+    pub fn autolink(&self, text: &str) -> String {
+        self.autolinker().autolink(text)
+    }
+
+    /// Auto-link all entities, writing the HTML to `w`. See
+    /// [Autolinker::autolink_into].
+    pub fn autolink_into<W: fmt::Write>(&self, text: &str, w: &mut W) -> fmt::Result {
+        self.autolinker().autolink_into(text, w)
+    }
+
+    /// Auto-link the given entities. See [Autolinker::autolink_entities].
+    pub fn autolink_entities(&self, text: &str, entities: &[Entity]) -> String {
+        let mut buf = String::with_capacity(text.len() * 2);
+        // Writing to a String can't fail.
+        let _ = self
+            .autolinker()
+            .autolink_entities_into(text, entities, &mut buf);
+        buf
+    }
+
+    /// Auto-link all entities, including federated mentions. See
+    /// [Autolinker::autolink_federated].
+    pub fn autolink_federated(&self, text: &str) -> String {
+        self.autolinker().autolink_federated(text)
+    }
+
+    /// Auto-link @username and @username/list references.
+    pub fn autolink_usernames_and_lists(&self, text: &str) -> String {
+        self.autolinker().autolink_usernames_and_lists(text)
+    }
+
+    /// Auto-link #hashtag references.
+    pub fn autolink_hashtags(&self, text: &str) -> String {
+        self.autolinker().autolink_hashtags(text)
+    }
+
+    /// Auto-link URLs with a protocol.
+    pub fn autolink_urls(&self, text: &str) -> String {
+        self.autolinker().autolink_urls(text)
+    }
+
+    /// Auto-link $cashtag references.
+    pub fn autolink_cashtags(&self, text: &str) -> String {
+        self.autolinker().autolink_cashtags(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::Type;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_send_sync() {
+        assert_send_sync::<AutolinkOptions>();
+        assert_send_sync::<Arc<AutolinkOptions>>();
+    }
+
+    #[test]
+    fn test_json() {
+        let options = AutolinkOptions::try_from_json("{}").unwrap();
+        assert_eq!(options.to_json(), AutolinkOptions::default().to_json());
+        assert_eq!(options.hashtag_class, DEFAULT_HASHTAG_CLASS);
+
+        let options = AutolinkOptions::try_from_json(
+            r#"{"urlClass": "link", "usernameIncludeSymbol": true,
+                "federatedMentionHCard": true}"#,
+        )
+        .unwrap();
+        assert_eq!(options.url_class, "link");
+        assert!(options.username_include_symbol);
+        assert!(options.federated_mention_h_card);
+        let round_trip = AutolinkOptions::try_from_json(&options.to_json()).unwrap();
+        assert_eq!(round_trip.to_json(), options.to_json());
+
+        let options = AutolinkOptions::try_from_json(r#"{"emojiRendering": "span"}"#).unwrap();
+        assert_eq!(options.emoji_rendering, EmojiRendering::Span);
+
+        assert!(AutolinkOptions::try_from_json(r#"{"urlClas": "x"}"#).is_err());
+    }
+
+    #[test]
+    fn test_autolinker() {
+        let options = AutolinkOptions {
+            username_include_symbol: true,
+            username_class: "user".to_string(),
+            ..AutolinkOptions::default()
+        };
+        assert_eq!(
+            options.autolink("@bob"),
+            "<a class=\"user\" href=\"https://twitter.com/bob\">@bob</a>"
+        );
+    }
+
+    #[test]
+    fn test_shared_modifiers() {
+        struct Upper;
+        impl LinkTextModifier for Upper {
+            fn modify(&self, _entity: &Entity, text: &str) -> String {
+                text.to_uppercase()
+            }
+        }
+        let options = Arc::new(AutolinkOptions {
+            link_text_modifier: Some(Arc::new(Upper)),
+            link_attribute_modifier: Some(Arc::new(AddAttributeModifier::new(
+                vec![Type::HASHTAG],
+                "data-x".to_string(),
+                "1".to_string(),
+            ))),
+            ..AutolinkOptions::default()
+        });
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let options = Arc::clone(&options);
+                std::thread::spawn(move || options.autolink_hashtags("#tag"))
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(
+                handle.join().unwrap(),
+                "<a href=\"https://twitter.com/search?q=%23tag\" title=\"#tag\" \
+                 class=\"tweet-url hashtag\" data-x=\"1\">#TAG</a>"
+            );
+        }
+    }
+}
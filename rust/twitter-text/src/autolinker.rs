@@ -2,10 +2,21 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+pub use crate::autolink_options::AutolinkOptions;
 use crate::entity;
 use crate::entity::Entity;
-use crate::extractor::{Extract, Extractor, ParserBackend};
+use crate::extractor::{is_valid_emoji, Extract, Extractor, ParserBackend};
+use crate::io_write::write_io;
+use crate::nom_parser::emoji;
+use crate::sanitize;
+use crate::segment::{split_display_url, DisplayUrlParts, Segment};
+use crate::url_template;
+use serde_derive::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::fmt;
+use std::fmt::Write as _;
+use std::io;
+use std::sync::Arc;
 
 /// Get the character at a given UTF-16 offset in a string.
 fn char_at_utf16_offset(text: &str, utf16_offset: i32) -> Option<char> {
@@ -65,11 +76,70 @@ pub const DEFAULT_HASHTAG_URL_BASE: &str = "https://twitter.com/search?q=%23";
  */
 pub const DEFAULT_CASHTAG_URL_BASE: &str = "https://twitter.com/search?q=%24";
 
+/**
+ * Default CSS class for auto-linked federated mention URLs
+ */
+pub const DEFAULT_FEDERATED_MENTION_CLASS: &str = "tweet-url federated-mention";
+
+/**
+ * Default href template for federated mention links. {domain} and {user} are replaced with the
+ * parts of @user@domain.
+ */
+pub const DEFAULT_FEDERATED_MENTION_URL_TEMPLATE: &str = "https://{domain}/@{user}";
+
 /**
  * Default attribute for invisible span tag
  */
 pub const DEFAULT_INVISIBLE_TAG_ATTRS: &str = "style='position:absolute;left:-9999px;'";
 
+/**
+ * Default CSS class for emoji images and spans
+ */
+pub const DEFAULT_EMOJI_CLASS: &str = "emoji";
+
+/**
+ * Default src template for emoji images. {codepoints} is replaced with the emoji's code points in
+ * lowercase hex, joined by "-", as in Twemoji file names.
+ */
+pub const DEFAULT_EMOJI_IMAGE_URL_TEMPLATE: &str =
+    "https://cdn.jsdelivr.net/gh/jdecked/twemoji@latest/assets/svg/{codepoints}.svg";
+
+/// How the Autolinker writes emoji in the text between entities.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EmojiRendering {
+    /// Emoji are written as text.
+    #[default]
+    None,
+    /// Each emoji is replaced with an `<img>` whose src comes from
+    /// emoji_image_url_template and whose alt text is the emoji, so copying
+    /// the text keeps it.
+    Image,
+    /// Each emoji is wrapped in a `<span>` with the emoji class.
+    Span,
+}
+
+impl EmojiRendering {
+    /// The rendering named "none", "image" or "span".
+    pub fn from_name(name: &str) -> Option<EmojiRendering> {
+        match name {
+            "none" => Some(EmojiRendering::None),
+            "image" => Some(EmojiRendering::Image),
+            "span" => Some(EmojiRendering::Span),
+            _ => None,
+        }
+    }
+
+    /// The name [EmojiRendering::from_name] accepts.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EmojiRendering::None => "none",
+            EmojiRendering::Image => "image",
+            EmojiRendering::Span => "span",
+        }
+    }
+}
+
 /**
  * Trait for modifying HTML attributes on generated links.
  */
@@ -129,15 +199,148 @@ impl LinkAttributeModifier for ReplaceClassModifier {
     }
 }
 
+impl<T: LinkAttributeModifier + ?Sized> LinkAttributeModifier for Arc<T> {
+    fn modify(&self, entity: &Entity, attributes: &mut Attributes) {
+        (**self).modify(entity, attributes)
+    }
+}
+
 /**
  * Trait for modifying the text content of generated links.
  */
 pub trait LinkTextModifier {
     fn modify(&self, entity: &Entity, text: &str) -> String;
+
+    /// Returns true if `modify` returns HTML that is safe to insert as is. In
+    /// strict mode, other modifiers get the link's plain text and their output
+    /// is escaped.
+    fn is_safe_html(&self) -> bool {
+        false
+    }
+}
+
+impl<T: LinkTextModifier + ?Sized> LinkTextModifier for Arc<T> {
+    fn modify(&self, entity: &Entity, text: &str) -> String {
+        (**self).modify(entity, text)
+    }
+
+    fn is_safe_html(&self) -> bool {
+        (**self).is_safe_html()
+    }
+}
+
+/**
+ * Trait for writing the whole replacement for an entity, for markup that the
+ * attribute and text modifiers can't produce. Each method defaults to the
+ * Autolinker's own rendering, so a renderer only overrides the entity types it
+ * changes. `text` is the full text being auto-linked; the Autolinker's
+ * default_href, default_title and default_class helpers give the values the
+ * default rendering would use.
+ */
+pub trait EntityRenderer {
+    fn render_url(&self, autolinker: &Autolinker, entity: &Entity, text: &str, buf: &mut String) {
+        autolinker.render_default(entity, text, buf);
+    }
+
+    fn render_hashtag(
+        &self,
+        autolinker: &Autolinker,
+        entity: &Entity,
+        text: &str,
+        buf: &mut String,
+    ) {
+        autolinker.render_default(entity, text, buf);
+    }
+
+    fn render_cashtag(
+        &self,
+        autolinker: &Autolinker,
+        entity: &Entity,
+        text: &str,
+        buf: &mut String,
+    ) {
+        autolinker.render_default(entity, text, buf);
+    }
+
+    fn render_mention(
+        &self,
+        autolinker: &Autolinker,
+        entity: &Entity,
+        text: &str,
+        buf: &mut String,
+    ) {
+        autolinker.render_default(entity, text, buf);
+    }
+
+    fn render_list(&self, autolinker: &Autolinker, entity: &Entity, text: &str, buf: &mut String) {
+        autolinker.render_default(entity, text, buf);
+    }
+
+    fn render_federated_mention(
+        &self,
+        autolinker: &Autolinker,
+        entity: &Entity,
+        text: &str,
+        buf: &mut String,
+    ) {
+        autolinker.render_default(entity, text, buf);
+    }
+}
+
+impl<T: EntityRenderer + ?Sized> EntityRenderer for Arc<T> {
+    fn render_url(&self, autolinker: &Autolinker, entity: &Entity, text: &str, buf: &mut String) {
+        (**self).render_url(autolinker, entity, text, buf)
+    }
+
+    fn render_hashtag(
+        &self,
+        autolinker: &Autolinker,
+        entity: &Entity,
+        text: &str,
+        buf: &mut String,
+    ) {
+        (**self).render_hashtag(autolinker, entity, text, buf)
+    }
+
+    fn render_cashtag(
+        &self,
+        autolinker: &Autolinker,
+        entity: &Entity,
+        text: &str,
+        buf: &mut String,
+    ) {
+        (**self).render_cashtag(autolinker, entity, text, buf)
+    }
+
+    fn render_mention(
+        &self,
+        autolinker: &Autolinker,
+        entity: &Entity,
+        text: &str,
+        buf: &mut String,
+    ) {
+        (**self).render_mention(autolinker, entity, text, buf)
+    }
+
+    fn render_list(&self, autolinker: &Autolinker, entity: &Entity, text: &str, buf: &mut String) {
+        (**self).render_list(autolinker, entity, text, buf)
+    }
+
+    fn render_federated_mention(
+        &self,
+        autolinker: &Autolinker,
+        entity: &Entity,
+        text: &str,
+        buf: &mut String,
+    ) {
+        (**self).render_federated_mention(autolinker, entity, text, buf)
+    }
 }
 
 /**
  * Adds HTML links to hashtag, username and list references in Tweet text.
+ * [AutolinkOptions] holds the same settings as owned values, and can render
+ * while shared between threads.
  */
 pub struct Autolinker<'a> {
     pub no_follow: bool,
@@ -153,6 +356,29 @@ pub struct Autolinker<'a> {
     pub list_url_base: &'a str,
     pub hashtag_url_base: &'a str,
     pub cashtag_url_base: &'a str,
+    /// Href templates. When set, a template is used instead of the
+    /// matching `*_url_base`, and its placeholder is replaced with the
+    /// percent-encoded value: `{tag}` for hashtags and cashtags, `{user}` for
+    /// usernames, and `{user}` and `{list}` for lists. A `*_url_base` is
+    /// followed by the raw value, as the conformance suite expects, unless
+    /// `encode_url_base_values` is set.
+    pub hashtag_url_template: &'a str,
+    pub cashtag_url_template: &'a str,
+    pub username_url_template: &'a str,
+    pub list_url_template: &'a str,
+    /// When true, the values after a `*_url_base` are percent-encoded like
+    /// template values, so `#日本語` links to
+    /// `https://twitter.com/search?q=%23%E6%97%A5%E6%9C%AC%E8%AA%9E`.
+    pub encode_url_base_values: bool,
+    pub federated_mention_class: &'a str,
+    /// The href template for @user@domain mentions, with percent-encoded
+    /// `{user}` and `{domain}` placeholders. An empty template leaves federated
+    /// mentions as plain text.
+    pub federated_mention_url_template: &'a str,
+    /// When true, federated mention links are wrapped in a Mastodon-style
+    /// `<span class="h-card">`, with the `u-url mention` classes on the link and
+    /// only the user part shown.
+    pub federated_mention_h_card: bool,
     pub invisible_tag_attrs: &'a str,
     pub username_include_symbol: bool,
     /// When true, adds `data-screen-name` attribute to username links (like Old JS twitter-text).
@@ -161,6 +387,22 @@ pub struct Autolinker<'a> {
     pub extractor: Extractor,
     pub link_attribute_modifier: Option<Box<dyn LinkAttributeModifier + 'a>>,
     pub link_text_modifier: Option<Box<dyn LinkTextModifier + 'a>>,
+    /// When set, writes the replacement for each entity in place of the default markup.
+    pub entity_renderer: Option<Box<dyn EntityRenderer + 'a>>,
+    /// When true, the output is safe to insert into a page whatever the input:
+    /// all text outside of links is HTML-escaped, attributes outside an
+    /// allowlist and hrefs or emoji srcs with schemes other than http and
+    /// https are dropped, link text modifiers that aren't
+    /// [LinkTextModifier::is_safe_html] get plain text and have their output
+    /// escaped, and symbol tags that aren't tag names are left out. An
+    /// [EntityRenderer]'s own markup is trusted.
+    pub strict: bool,
+    pub emoji_rendering: EmojiRendering,
+    pub emoji_class: &'a str,
+    /// The src template for [EmojiRendering::Image], with a `{codepoints}`
+    /// placeholder. U+FE0F is left out of the code points unless the emoji
+    /// has a zero width joiner, as Twemoji names its files.
+    pub emoji_image_url_template: &'a str,
 }
 
 impl<'a> Autolinker<'a> {
@@ -187,290 +429,806 @@ impl<'a> Autolinker<'a> {
             list_url_base: DEFAULT_LIST_URL_BASE,
             hashtag_url_base: DEFAULT_HASHTAG_URL_BASE,
             cashtag_url_base: DEFAULT_CASHTAG_URL_BASE,
+            hashtag_url_template: "",
+            cashtag_url_template: "",
+            username_url_template: "",
+            list_url_template: "",
+            encode_url_base_values: false,
+            federated_mention_class: DEFAULT_FEDERATED_MENTION_CLASS,
+            federated_mention_url_template: DEFAULT_FEDERATED_MENTION_URL_TEMPLATE,
+            federated_mention_h_card: false,
             invisible_tag_attrs: DEFAULT_INVISIBLE_TAG_ATTRS,
             username_include_symbol: false,
             include_data_screen_name: false,
             extractor,
             link_attribute_modifier: None,
             link_text_modifier: None,
+            entity_renderer: None,
+            strict: false,
+            emoji_rendering: EmojiRendering::None,
+            emoji_class: DEFAULT_EMOJI_CLASS,
+            emoji_image_url_template: DEFAULT_EMOJI_IMAGE_URL_TEMPLATE,
         }
     }
 
+    // Write a link. `attributes` are written in order, followed by rel="nofollow"
+    // when no_follow is set; `link_text` writes the already-escaped link text,
+    // and `plain_text` is its unescaped text for modifiers in strict mode.
+    // The modifiers get owned copies, so a link without modifiers is written
+    // without building any Strings.
     fn link_to_text(
         &self,
         entity: &Entity,
-        original_text: &str,
-        attributes: &mut Attributes,
-        buf: &mut String,
-    ) {
-        if self.no_follow {
-            attributes.push((String::from("rel"), String::from("nofollow")));
+        attributes: &[Option<(&str, AttrValue)>],
+        link_text: &dyn Fn(&mut dyn fmt::Write) -> fmt::Result,
+        plain_text: &[&str],
+        w: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        let rel = self
+            .no_follow
+            .then_some(("rel", AttrValue::Str("nofollow")));
+        let attributes = attributes.iter().chain(std::iter::once(&rel)).flatten();
+
+        w.write_str("<a")?;
+        if let Some(ref modifier) = self.link_attribute_modifier {
+            let mut owned: Attributes = attributes
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            modifier.modify(entity, &mut owned);
+            for (k, v) in &owned {
+                self.write_attribute(w, k, &AttrValue::Str(v))?;
+            }
+        } else {
+            for (k, v) in attributes {
+                self.write_attribute(w, k, v)?;
+            }
         }
+        w.write_char('>')?;
 
-        // Call link attribute modifier if set
-        if let Some(ref modifier) = self.link_attribute_modifier {
-            modifier.modify(entity, attributes);
+        match &self.link_text_modifier {
+            Some(modifier) if self.strict && !modifier.is_safe_html() => {
+                write_escaped_html(w, &modifier.modify(entity, &plain_text.concat()))?;
+            }
+            Some(modifier) => {
+                let mut text = String::new();
+                link_text(&mut text)?;
+                w.write_str(&modifier.modify(entity, &text))?;
+            }
+            None => link_text(w)?,
+        }
+        w.write_str("</a>")
+    }
+
+    // In strict mode, attributes that aren't allowlisted and hrefs with unsafe
+    // schemes are dropped.
+    fn write_attribute(&self, w: &mut dyn fmt::Write, key: &str, value: &AttrValue) -> fmt::Result {
+        if self.strict
+            && (!sanitize::is_allowed_attribute(key)
+                || ((key == HREF || key == "src") && !sanitize::is_safe_href(value.chars())))
+        {
+            return Ok(());
         }
+        write_attribute(w, key, value)
+    }
 
-        // Call link text modifier if set
-        let text = if let Some(ref modifier) = self.link_text_modifier {
-            modifier.modify(entity, original_text)
+    // Text outside of markup, which strict mode escapes.
+    fn write_text(&self, w: &mut dyn fmt::Write, text: &str) -> fmt::Result {
+        if self.strict {
+            write_escaped_html(w, text)
         } else {
-            original_text.to_string()
-        };
+            w.write_str(text)
+        }
+    }
 
-        buf.push_str("<a");
-        for (k, v) in attributes {
-            buf.push(' ');
-            buf.push_str(&escape_html(k));
-            buf.push_str("=\"");
-            buf.push_str(&escape_html(v));
-            buf.push('"');
+    // Text between entities, with emoji written as emoji_rendering says.
+    fn write_plain(&self, w: &mut dyn fmt::Write, text: &str) -> fmt::Result {
+        if self.emoji_rendering == EmojiRendering::None {
+            return self.write_text(w, text);
+        }
+        let mut last = 0;
+        let mut pos = 0;
+        while let Some(c) = text[pos..].chars().next() {
+            match emoji::try_parse_emoji(&text[pos..]) {
+                Some((emoji, len)) if is_valid_emoji(emoji) => {
+                    self.write_text(w, &text[last..pos])?;
+                    self.write_emoji(w, emoji)?;
+                    pos += len;
+                    last = pos;
+                }
+                _ => pos += c.len_utf8(),
+            }
+        }
+        self.write_text(w, &text[last..])
+    }
+
+    fn write_emoji(&self, w: &mut dyn fmt::Write, emoji: &str) -> fmt::Result {
+        let class = AttrValue::Str(self.emoji_class);
+        match self.emoji_rendering {
+            EmojiRendering::Image => {
+                let src = url_template::expand(
+                    self.emoji_image_url_template,
+                    &[("codepoints", &emoji_codepoints(emoji))],
+                );
+                w.write_str("<img")?;
+                self.write_attribute(w, CLASS, &class)?;
+                self.write_attribute(w, "draggable", &AttrValue::Str("false"))?;
+                self.write_attribute(w, "alt", &AttrValue::Str(emoji))?;
+                self.write_attribute(w, "src", &AttrValue::Owned(src))?;
+                w.write_char('>')
+            }
+            EmojiRendering::Span => {
+                w.write_str("<span")?;
+                self.write_attribute(w, CLASS, &class)?;
+                w.write_char('>')?;
+                w.write_str(emoji)?;
+                w.write_str("</span>")
+            }
+            EmojiRendering::None => w.write_str(emoji),
         }
-        buf.push('>');
-        buf.push_str(&text);
-        buf.push_str("</a>");
     }
 
+    // A symbol tag, or "" in strict mode if it isn't a valid tag name.
+    fn tag_name<'t>(&self, tag: &'t str) -> &'t str {
+        if self.strict && !sanitize::is_tag_name(tag) {
+            ""
+        } else {
+            tag
+        }
+    }
+
+    // Write a link whose text is a symbol followed by `value`, the escaped
+    // concatenation of its pieces. The symbol is outside the link for mentions,
+    // unless username_include_symbol is set.
     fn link_to_text_with_symbol(
         &self,
         entity: &Entity,
         sym: &str,
-        original_text: &str,
-        attributes: &mut Attributes,
-        buf: &mut String,
-    ) {
-        let tagged_symbol = match self.symbol_tag {
-            "" => String::from(sym),
-            _ => format!("<{}>{}</{}>", self.symbol_tag, sym, self.symbol_tag),
+        value: (&str, &str),
+        attributes: &[Option<(&str, AttrValue)>],
+        w: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        let symbol_tag = self.tag_name(self.symbol_tag);
+        let text_tag = self.tag_name(self.text_with_symbol_tag);
+        let write_symbol = |w: &mut dyn fmt::Write| {
+            write_start_tag(w, symbol_tag)?;
+            self.write_text(w, sym)?;
+            write_end_tag(w, symbol_tag)
         };
-        let text = escape_html(original_text);
-        let tagged_text: Cow<'_, str> = match self.text_with_symbol_tag {
-            "" => text,
-            _ => Cow::Owned(format!(
-                "<{}>{}</{}>",
-                self.text_with_symbol_tag, text, self.text_with_symbol_tag
-            )),
+        let write_value = |w: &mut dyn fmt::Write| {
+            write_start_tag(w, text_tag)?;
+            write_escaped_html(w, value.0)?;
+            write_escaped_html(w, value.1)?;
+            write_end_tag(w, text_tag)
         };
         let inc_sym =
             self.username_include_symbol || !(sym.contains('@') || sym.contains('\u{FF20}'));
 
         if inc_sym {
-            self.link_to_text(entity, &(tagged_symbol + &tagged_text), attributes, buf);
+            let write_text = |w: &mut dyn fmt::Write| {
+                write_symbol(w)?;
+                write_value(w)
+            };
+            self.link_to_text(entity, attributes, &write_text, &[sym, value.0, value.1], w)
         } else {
-            buf.push_str(tagged_symbol.as_str());
-            self.link_to_text(entity, &tagged_text, attributes, buf);
+            write_symbol(w)?;
+            self.link_to_text(entity, attributes, &write_value, &[value.0, value.1], w)
         }
     }
 
-    fn link_to_hashtag(&self, entity: &Entity, text: &str, buf: &mut String) {
-        let hash_char = char_at_utf16_offset(text, entity.get_start()).unwrap_or('#');
-        let hashtag = entity.get_value();
-        let mut attrs: Attributes = Vec::new();
-        attrs.push((HREF.to_string(), self.hashtag_url_base.to_owned() + hashtag));
-        attrs.push((TITLE.to_string(), "#".to_owned() + hashtag));
+    // A `*_url_base` followed by `values` separated by "/", percent-encoded
+    // when encode_url_base_values is set.
+    fn base_link<'s>(&self, base: &'s str, values: &[&'s str]) -> AttrValue<'s> {
+        match values {
+            [value] if !self.encode_url_base_values => AttrValue::Concat(base, value, ""),
+            _ => {
+                let mut buf = base.to_string();
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        buf.push('/');
+                    }
+                    if self.encode_url_base_values {
+                        url_template::percent_encode(value, &mut buf);
+                    } else {
+                        buf.push_str(value);
+                    }
+                }
+                AttrValue::Owned(buf)
+            }
+        }
+    }
 
-        if contains_rtl(text) {
-            attrs.push((CLASS.to_string(), self.hashtag_class.to_owned() + " rtl"));
-        } else {
-            attrs.push((CLASS.to_string(), self.hashtag_class.to_string()));
+    // The link for a hashtag or cashtag, given without its symbol.
+    fn tag_link<'s>(&self, template: &'s str, base: &'s str, tag: &'s str) -> AttrValue<'s> {
+        match template {
+            "" => self.base_link(base, &[tag]),
+            template => AttrValue::Owned(url_template::expand(template, &[("tag", tag)])),
         }
-        let hash_str = hash_char.to_string();
-        self.link_to_text_with_symbol(entity, &hash_str, hashtag, &mut attrs, buf);
     }
 
-    fn link_to_cashtag(&self, entity: &Entity, _text: &str, buf: &mut String) {
-        let cashtag = entity.get_value();
-        let mut attrs: Attributes = Vec::new();
-        attrs.push((HREF.to_string(), self.cashtag_url_base.to_owned() + cashtag));
-        attrs.push((TITLE.to_string(), "$".to_owned() + cashtag));
-        attrs.push((CLASS.to_string(), String::from(self.cashtag_class)));
+    /// The link for a hashtag, given without its "#".
+    pub(crate) fn hashtag_href(&self, hashtag: &str) -> String {
+        self.tag_link(self.hashtag_url_template, self.hashtag_url_base, hashtag)
+            .to_string()
+    }
+
+    /// The link for a cashtag, given without its "$".
+    pub(crate) fn cashtag_href(&self, cashtag: &str) -> String {
+        self.tag_link(self.cashtag_url_template, self.cashtag_url_base, cashtag)
+            .to_string()
+    }
 
-        self.link_to_text_with_symbol(entity, "$", cashtag, &mut attrs, buf);
+    // The link for a mention or list entity.
+    fn mention_link<'s>(&'s self, entity: &'s Entity) -> AttrValue<'s> {
+        let user = entity.get_value();
+        let list_slug = entity.get_list_slug();
+        match (
+            list_slug,
+            self.username_url_template,
+            self.list_url_template,
+        ) {
+            ("", "", _) => self.base_link(self.username_url_base, &[user]),
+            ("", template, _) => {
+                AttrValue::Owned(url_template::expand(template, &[("user", user)]))
+            }
+            (_, _, template) => {
+                let list = list_slug.strip_prefix('/').unwrap_or(list_slug);
+                match template {
+                    "" => self.base_link(self.list_url_base, &[user, list]),
+                    template => AttrValue::Owned(url_template::expand(
+                        template,
+                        &[("user", user), ("list", list)],
+                    )),
+                }
+            }
+        }
     }
 
-    fn link_to_mention_and_list(&self, entity: &Entity, text: &str, buf: &mut String) {
-        let mut mention = String::from(entity.get_value());
-        let at_char = char_at_utf16_offset(text, entity.get_start()).unwrap_or('@');
-        let mut attrs: Attributes = Vec::new();
+    /// The link for a mention or list entity.
+    pub(crate) fn mention_href(&self, entity: &Entity) -> String {
+        self.mention_link(entity).to_string()
+    }
 
-        if entity.get_type() == entity::Type::MENTION && !entity.get_list_slug().is_empty() {
-            mention.push_str(entity.get_list_slug());
-            attrs.push((CLASS.to_string(), self.list_class.to_owned()));
-            attrs.push((HREF.to_string(), self.list_url_base.to_owned() + &mention));
-        } else {
-            attrs.push((CLASS.to_string(), self.username_class.to_owned()));
-            attrs.push((
-                HREF.to_string(),
-                self.username_url_base.to_owned() + &mention,
-            ));
-            if self.include_data_screen_name {
-                attrs.push(("data-screen-name".to_string(), mention.clone()));
+    /// The link for a federated mention, or None if it isn't linked.
+    pub(crate) fn federated_mention_href(&self, entity: &Entity) -> Option<String> {
+        if self.federated_mention_url_template.is_empty() {
+            return None;
+        }
+        let (_, user, domain) = split_federated_mention(entity.get_value())?;
+        Some(url_template::expand(
+            self.federated_mention_url_template,
+            &[("domain", domain), ("user", user)],
+        ))
+    }
+
+    /// Returns true if a URL entity is left unlinked because the extractor's
+    /// domain policy drops it. The expanded URL is checked too, so a t.co
+    /// link can't hide a blocked domain.
+    pub(crate) fn drops_url(&self, entity: &Entity) -> bool {
+        let expanded_url = entity.get_expanded_url();
+        let drops_value = match entity.host() {
+            Some(host) => self.extractor.drops_host(host),
+            None => self.extractor.drops_url(entity.get_value()),
+        };
+        drops_value || (!expanded_url.is_empty() && self.extractor.drops_url(expanded_url))
+    }
+
+    /// The link target the default rendering uses for an entity, or None if
+    /// the entity isn't linked.
+    pub fn default_href(&self, entity: &Entity) -> Option<String> {
+        let value = entity.get_value();
+        match entity.get_type() {
+            entity::Type::URL if self.drops_url(entity) => None,
+            entity::Type::URL => Some(value.to_string()),
+            entity::Type::HASHTAG => Some(self.hashtag_href(value)),
+            entity::Type::CASHTAG => Some(self.cashtag_href(value)),
+            entity::Type::MENTION => Some(self.mention_href(entity)),
+            entity::Type::FEDERATEDMENTION => self.federated_mention_href(entity),
+        }
+    }
+
+    /// The title attribute the default rendering uses for an entity, if any.
+    pub fn default_title(&self, entity: &Entity) -> Option<String> {
+        match entity.get_type() {
+            entity::Type::HASHTAG => Some("#".to_owned() + entity.get_value()),
+            entity::Type::CASHTAG => Some("$".to_owned() + entity.get_value()),
+            entity::Type::URL if !entity.get_expanded_url().is_empty() => {
+                Some(entity.get_expanded_url().to_string())
             }
+            entity::Type::FEDERATEDMENTION if self.federated_mention_h_card => {
+                Some(entity.get_value().to_string())
+            }
+            _ => None,
         }
+    }
 
-        let at_str = at_char.to_string();
-        self.link_to_text_with_symbol(entity, &at_str, mention.as_str(), &mut attrs, buf);
+    /// The class attribute the default rendering uses for an entity in
+    /// `text`, if any. Hashtags in right-to-left text get an extra "rtl" class.
+    pub fn default_class(&self, entity: &Entity, text: &str) -> Option<String> {
+        self.class_value(entity, text)
+            .map(|class| class.to_string())
     }
 
-    fn link_to_url(&self, entity: &Entity, _text: &str, buf: &mut String) {
-        let url = entity.get_value();
-        let mut link_text = escape_html(url);
-        if !entity.get_display_url().is_empty() && !entity.get_expanded_url().is_empty() {
-            // Goal: If a user copies and pastes a tweet containing t.co'ed link, the resulting paste
-            // should contain the full original URL (expanded_url), not the display URL.
-            //
-            // Method: Whenever possible, we actually emit HTML that contains expanded_url, and use
-            // font-size:0 to hide those parts that should not be displayed
-            // (because they are not part of display_url).
-            // Elements with font-size:0 get copied even though they are not visible.
-            // Note that display:none doesn't work here. Elements with display:none don't get copied.
-            //
-            // Additionally, we want to *display* ellipses, but we don't want them copied.
-            // To make this happen we wrap the ellipses in a tco-ellipsis class and provide an onCopy
-            // handler that sets display:none on everything with the tco-ellipsis class.
-            //
-            // As an example: The user tweets "hi http://longdomainname.com/foo"
-            // This gets shortened to "hi http://t.co/xyzabc", with display_url = "…nname.com/foo"
-            // This will get rendered as:
-            // <span class='tco-ellipsis'> <!-- This stuff should get displayed but not copied -->
-            //   …
-            //   <!-- There's a chance the onCopy event handler might not fire. In case that happens,
-            //        we include an &nbsp; here so that the … doesn't bump up against the URL and ruin it.
-            //        The &nbsp; is inside the tco-ellipsis span so that when the onCopy handler *does*
-            //        fire, it doesn't get copied.  Otherwise the copied text would have two spaces
-            //        in a row, e.g. "hi  http://longdomainname.com/foo".
-            //   <span style='font-size:0'>&nbsp;</span>
-            // </span>
-            // <span style='font-size:0'>  <!-- This stuff should get copied but not displayed -->
-            //   http://longdomai
-            // </span>
-            // <span class='js-display-url'> <!-- This stuff should get displayed *and* copied -->
-            //   nname.com/foo
-            // </span>
-            // <span class='tco-ellipsis'> <!-- This stuff should get displayed but not copied -->
-            //   <span style='font-size:0'>&nbsp;</span>
-            //   …
-            // </span>
-            //
-            // Exception: pic.twitter.com images, for which expandedUrl =
-            // "https://twitter.com/username/status/1234/photo/1
-            // For those URLs, display_url is not a substring of expanded_url,
-            // so we don't do anything special to render the elided parts.
-            // For a pic.twitter.com URL, the only elided part will be the "https://", so this is fine.
-            let display_url_sans_ellipses = entity.get_display_url().replace("…", "");
-            let index = entity.get_expanded_url().find(&display_url_sans_ellipses);
-            if let Some(display_url_index_in_expanded_url) = index {
-                let before_display_url = entity
-                    .get_expanded_url()
-                    .chars()
-                    .take(display_url_index_in_expanded_url)
-                    .collect::<String>();
-                let after_display_url = entity
-                    .get_expanded_url()
-                    .chars()
-                    .skip(display_url_index_in_expanded_url + display_url_sans_ellipses.len())
-                    .collect::<String>();
-                let preceding_ellipsis = if entity.get_display_url().starts_with("…") {
-                    "…"
-                } else {
-                    ""
-                };
-                let following_ellipsis = if entity.get_display_url().ends_with("…") {
-                    "…"
-                } else {
-                    ""
-                };
-                let invisible_span = "<span ".to_owned() + self.invisible_tag_attrs + ">";
+    fn class_value(&self, entity: &Entity, text: &str) -> Option<AttrValue<'a>> {
+        let class = match entity.get_type() {
+            entity::Type::URL => self.url_class,
+            entity::Type::HASHTAG if contains_rtl(text) => {
+                return Some(AttrValue::Concat(self.hashtag_class, " rtl", ""))
+            }
+            entity::Type::HASHTAG => self.hashtag_class,
+            entity::Type::CASHTAG => self.cashtag_class,
+            entity::Type::MENTION if entity.get_list_slug().is_empty() => self.username_class,
+            entity::Type::MENTION => self.list_class,
+            entity::Type::FEDERATEDMENTION if self.federated_mention_h_card => {
+                return Some(match self.federated_mention_class {
+                    "" => AttrValue::Str("u-url mention"),
+                    class => AttrValue::Concat("u-url mention ", class, ""),
+                });
+            }
+            entity::Type::FEDERATEDMENTION => self.federated_mention_class,
+        };
+        if class.is_empty() {
+            None
+        } else {
+            Some(AttrValue::Str(class))
+        }
+    }
 
-                let mut sb = String::from("<span class='tco-ellipsis'>");
-                sb += preceding_ellipsis;
-                sb += &invisible_span;
-                sb += "&nbsp;</span></span>";
-                sb += &invisible_span;
-                sb += &escape_html(&before_display_url);
-                sb += "</span>";
-                sb += "<span class='js-display-url'>";
-                sb += &escape_html(&display_url_sans_ellipses);
-                sb += "</span>";
-                sb += &invisible_span;
-                sb += &escape_html(&after_display_url);
-                sb += "</span>";
-                sb += "<span class='tco-ellipsis'>";
-                sb += &invisible_span;
-                sb += "&nbsp;</span>";
-                sb += following_ellipsis;
-                sb += "</span>";
+    /// Write the default markup for an entity in `text`, ignoring
+    /// [Autolinker::entity_renderer].
+    pub fn render_default(&self, entity: &Entity, text: &str, buf: &mut String) {
+        // Writing to a String can't fail.
+        let _ = self.write_default(entity, text, buf);
+    }
+
+    fn write_default(&self, entity: &Entity, text: &str, w: &mut dyn fmt::Write) -> fmt::Result {
+        match entity.get_type() {
+            entity::Type::URL => self.link_to_url(entity, w),
+            entity::Type::HASHTAG => self.link_to_hashtag(entity, text, w),
+            entity::Type::MENTION => self.link_to_mention_and_list(entity, text, w),
+            entity::Type::CASHTAG => self.link_to_cashtag(entity, w),
+            entity::Type::FEDERATEDMENTION => self.link_to_federated_mention(entity, text, w),
+        }
+    }
 
-                link_text = Cow::Owned(sb);
-            } else {
-                link_text = Cow::Owned(String::from(entity.get_display_url()));
+    // Write the markup for an entity. An entity_renderer writes into `scratch`,
+    // which is then copied to `w`.
+    fn render(
+        &self,
+        entity: &Entity,
+        text: &str,
+        scratch: &mut String,
+        w: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        let renderer = match &self.entity_renderer {
+            Some(renderer) => renderer,
+            None => return self.write_default(entity, text, w),
+        };
+        scratch.clear();
+        let buf = scratch;
+        match entity.get_type() {
+            entity::Type::URL => renderer.render_url(self, entity, text, buf),
+            entity::Type::HASHTAG => renderer.render_hashtag(self, entity, text, buf),
+            entity::Type::CASHTAG => renderer.render_cashtag(self, entity, text, buf),
+            entity::Type::MENTION if entity.get_list_slug().is_empty() => {
+                renderer.render_mention(self, entity, text, buf)
+            }
+            entity::Type::MENTION => renderer.render_list(self, entity, text, buf),
+            entity::Type::FEDERATEDMENTION => {
+                renderer.render_federated_mention(self, entity, text, buf)
             }
         }
+        w.write_str(buf)
+    }
+
+    fn link_to_hashtag(&self, entity: &Entity, text: &str, w: &mut dyn fmt::Write) -> fmt::Result {
+        let mut hash_buf = [0; 4];
+        let hash_str = char_at_utf16_offset(text, entity.get_start())
+            .unwrap_or('#')
+            .encode_utf8(&mut hash_buf);
+        let hashtag = entity.get_value();
+        let attrs = [
+            Some((
+                HREF,
+                self.tag_link(self.hashtag_url_template, self.hashtag_url_base, hashtag),
+            )),
+            Some((TITLE, AttrValue::Concat("#", hashtag, ""))),
+            Some((
+                CLASS,
+                self.class_value(entity, text).unwrap_or(AttrValue::Str("")),
+            )),
+        ];
+        self.link_to_text_with_symbol(entity, hash_str, (hashtag, ""), &attrs, w)
+    }
+
+    fn link_to_cashtag(&self, entity: &Entity, w: &mut dyn fmt::Write) -> fmt::Result {
+        let cashtag = entity.get_value();
+        let attrs = [
+            Some((
+                HREF,
+                self.tag_link(self.cashtag_url_template, self.cashtag_url_base, cashtag),
+            )),
+            Some((TITLE, AttrValue::Concat("$", cashtag, ""))),
+            Some((CLASS, AttrValue::Str(self.cashtag_class))),
+        ];
+        self.link_to_text_with_symbol(entity, "$", (cashtag, ""), &attrs, w)
+    }
+
+    fn link_to_mention_and_list(
+        &self,
+        entity: &Entity,
+        text: &str,
+        w: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        let mention = entity.get_value();
+        let list_slug = entity.get_list_slug();
+        let mut at_buf = [0; 4];
+        let at_str = char_at_utf16_offset(text, entity.get_start())
+            .unwrap_or('@')
+            .encode_utf8(&mut at_buf);
+
+        let attrs = if !list_slug.is_empty() {
+            [
+                Some((CLASS, AttrValue::Str(self.list_class))),
+                Some((HREF, self.mention_link(entity))),
+                None,
+            ]
+        } else {
+            [
+                Some((CLASS, AttrValue::Str(self.username_class))),
+                Some((HREF, self.mention_link(entity))),
+                self.include_data_screen_name
+                    .then_some(("data-screen-name", AttrValue::Str(mention))),
+            ]
+        };
+        self.link_to_text_with_symbol(entity, at_str, (mention, list_slug), &attrs, w)
+    }
+
+    fn link_to_federated_mention(
+        &self,
+        entity: &Entity,
+        text: &str,
+        w: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        let mention = entity.get_value();
+        let (href, (symbol, user, _)) = match (
+            self.federated_mention_href(entity),
+            split_federated_mention(mention),
+        ) {
+            (Some(href), Some(parts)) => (href, parts),
+            _ => return self.write_text(w, mention),
+        };
+        let attrs = [
+            self.class_value(entity, text).map(|class| (CLASS, class)),
+            Some((HREF, AttrValue::Owned(href))),
+            self.federated_mention_h_card
+                .then_some((TITLE, AttrValue::Str(mention))),
+        ];
+        if self.federated_mention_h_card {
+            // Like Mastodon, show only the user, with the symbol outside its span.
+            let link_text = |w: &mut dyn fmt::Write| {
+                self.write_text(w, symbol)?;
+                w.write_str("<span>")?;
+                write_escaped_html(w, user)?;
+                w.write_str("</span>")
+            };
+            w.write_str("<span class=\"h-card\">")?;
+            self.link_to_text(entity, &attrs, &link_text, &[symbol, user], w)?;
+            w.write_str("</span>")
+        } else {
+            let link_text = |w: &mut dyn fmt::Write| write_escaped_html(w, mention);
+            self.link_to_text(entity, &attrs, &link_text, &[mention], w)
+        }
+    }
 
-        let mut attrs: Attributes = Vec::new();
-        attrs.push((HREF.to_string(), String::from(url)));
-        if !entity.get_expanded_url().is_empty() {
-            attrs.push((TITLE.to_string(), String::from(entity.get_expanded_url())));
+    fn link_to_url(&self, entity: &Entity, w: &mut dyn fmt::Write) -> fmt::Result {
+        let url = entity.get_value();
+        // URLs blocked by the extractor's domain policy are left as plain text.
+        if self.drops_url(entity) {
+            return self.write_text(w, url);
         }
-        if !self.url_class.is_empty() {
-            attrs.push((CLASS.to_string(), String::from(self.url_class)));
+        let display_url = entity.get_display_url();
+        let expanded_url = entity.get_expanded_url();
+        let attrs = [
+            Some((HREF, AttrValue::Str(url))),
+            (!expanded_url.is_empty()).then_some((TITLE, AttrValue::Str(expanded_url))),
+            (!self.url_class.is_empty()).then_some((CLASS, AttrValue::Str(self.url_class))),
+            (!self.url_target.is_empty()).then_some((TARGET, AttrValue::Str(self.url_target))),
+        ];
+        if display_url.is_empty() || expanded_url.is_empty() {
+            let link_text = |w: &mut dyn fmt::Write| write_escaped_html(w, url);
+            return self.link_to_text(entity, &attrs, &link_text, &[url], w);
         }
-        if !self.url_target.is_empty() {
-            attrs.push((TARGET.to_string(), String::from(self.url_target)));
+
+        // Goal: If a user copies and pastes a tweet containing t.co'ed link, the resulting paste
+        // should contain the full original URL (expanded_url), not the display URL.
+        //
+        // Method: Whenever possible, we actually emit HTML that contains expanded_url, and use
+        // font-size:0 to hide those parts that should not be displayed
+        // (because they are not part of display_url).
+        // Elements with font-size:0 get copied even though they are not visible.
+        // Note that display:none doesn't work here. Elements with display:none don't get copied.
+        //
+        // Additionally, we want to *display* ellipses, but we don't want them copied.
+        // To make this happen we wrap the ellipses in a tco-ellipsis class and provide an onCopy
+        // handler that sets display:none on everything with the tco-ellipsis class.
+        //
+        // As an example: The user tweets "hi http://longdomainname.com/foo"
+        // This gets shortened to "hi http://t.co/xyzabc", with display_url = "…nname.com/foo"
+        // This will get rendered as:
+        // <span class='tco-ellipsis'> <!-- This stuff should get displayed but not copied -->
+        //   …
+        //   <!-- There's a chance the onCopy event handler might not fire. In case that happens,
+        //        we include an &nbsp; here so that the … doesn't bump up against the URL and ruin it.
+        //        The &nbsp; is inside the tco-ellipsis span so that when the onCopy handler *does*
+        //        fire, it doesn't get copied.  Otherwise the copied text would have two spaces
+        //        in a row, e.g. "hi  http://longdomainname.com/foo".
+        //   <span style='font-size:0'>&nbsp;</span>
+        // </span>
+        // <span style='font-size:0'>  <!-- This stuff should get copied but not displayed -->
+        //   http://longdomai
+        // </span>
+        // <span class='js-display-url'> <!-- This stuff should get displayed *and* copied -->
+        //   nname.com/foo
+        // </span>
+        // <span class='tco-ellipsis'> <!-- This stuff should get displayed but not copied -->
+        //   <span style='font-size:0'>&nbsp;</span>
+        //   …
+        // </span>
+        //
+        // Exception: pic.twitter.com images, for which expandedUrl =
+        // "https://twitter.com/username/status/1234/photo/1
+        // For those URLs, display_url is not a substring of expanded_url,
+        // so we don't do anything special to render the elided parts.
+        // For a pic.twitter.com URL, the only elided part will be the "https://", so this is fine.
+        match split_display_url(display_url, expanded_url) {
+            Some((before, display, after)) => {
+                let link_text = |w: &mut dyn fmt::Write| {
+                    let preceding_ellipsis = if display_url.starts_with('…') {
+                        "…"
+                    } else {
+                        ""
+                    };
+                    let following_ellipsis = if display_url.ends_with('…') {
+                        "…"
+                    } else {
+                        ""
+                    };
+                    w.write_str("<span class='tco-ellipsis'>")?;
+                    w.write_str(preceding_ellipsis)?;
+                    self.write_invisible_span_start(w)?;
+                    w.write_str("&nbsp;</span></span>")?;
+                    self.write_invisible_span_start(w)?;
+                    write_escaped_html(w, before)?;
+                    w.write_str("</span><span class='js-display-url'>")?;
+                    write_escaped_html(w, display)?;
+                    w.write_str("</span>")?;
+                    self.write_invisible_span_start(w)?;
+                    write_escaped_html(w, after)?;
+                    w.write_str("</span><span class='tco-ellipsis'>")?;
+                    self.write_invisible_span_start(w)?;
+                    w.write_str("&nbsp;</span>")?;
+                    w.write_str(following_ellipsis)?;
+                    w.write_str("</span>")
+                };
+                self.link_to_text(entity, &attrs, &link_text, &[display_url], w)
+            }
+            None => {
+                let link_text = |w: &mut dyn fmt::Write| self.write_text(w, display_url);
+                self.link_to_text(entity, &attrs, &link_text, &[display_url], w)
+            }
         }
-        self.link_to_text(entity, &link_text, &mut attrs, buf);
+    }
+
+    // The start tag of a span styled with invisible_tag_attrs. Strict mode
+    // uses the default attributes unless these are class and style attributes.
+    fn write_invisible_span_start(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        let attrs = match self.invisible_tag_attrs {
+            attrs if self.strict && !sanitize::is_safe_attribute_list(attrs) => {
+                DEFAULT_INVISIBLE_TAG_ATTRS
+            }
+            attrs => attrs,
+        };
+        w.write_str("<span ")?;
+        w.write_str(attrs)?;
+        w.write_char('>')
     }
 
     pub fn autolink_entities(&self, text: &str, entities: &Vec<Entity>) -> String {
         let mut buf = String::with_capacity(text.len() * 2);
-        // Track position in both UTF-16 code units (for entity indices) and bytes (for slicing)
-        let mut utf16_offset = 0i32;
-        let mut byte_offset = 0usize;
-
-        for entity in entities {
-            // Find byte position corresponding to entity start (UTF-16 offset)
-            let entity_start_utf16 = entity.get_start();
-            while utf16_offset < entity_start_utf16 && byte_offset < text.len() {
-                let c = text[byte_offset..].chars().next().unwrap();
-                buf.push(c);
-                utf16_offset += c.len_utf16() as i32;
-                byte_offset += c.len_utf8();
-            }
-
-            match entity.get_type() {
-                entity::Type::URL => self.link_to_url(entity, text, &mut buf),
-                entity::Type::HASHTAG => self.link_to_hashtag(entity, text, &mut buf),
-                entity::Type::MENTION => self.link_to_mention_and_list(entity, text, &mut buf),
-                entity::Type::CASHTAG => self.link_to_cashtag(entity, text, &mut buf),
-                entity::Type::FEDERATEDMENTION => {
-                    // Federated mentions are not auto-linked by default
-                    // Just output the text as-is
-                    buf += entity.get_value();
+        // Writing to a String can't fail.
+        let _ = self.autolink_entities_into(text, entities, &mut buf);
+        buf
+    }
+
+    /// Like [Autolinker::autolink_entities], but writes the HTML to `w`. Without
+    /// modifiers or an entity renderer, links are written without building
+    /// Strings, except for hrefs expanded from URL templates.
+    pub fn autolink_entities_into<W: fmt::Write>(
+        &self,
+        text: &str,
+        entities: &[Entity],
+        w: &mut W,
+    ) -> fmt::Result {
+        let mut result = Ok(());
+        let mut scratch = String::new();
+        walk_entities(text, entities, |piece, entity| {
+            if result.is_ok() {
+                result = match entity {
+                    None => self.write_plain(w, piece),
+                    Some(entity) => self.render(entity, text, &mut scratch, w),
+                };
+            }
+        });
+        result
+    }
+
+    /// Split the text into plain text and link [Segment]s, for renderers that
+    /// build their own markup. The links match those of [Autolinker::autolink].
+    pub fn segments(&self, text: &str) -> Vec<Segment> {
+        let entities = self.extractor.extract_entities_with_indices(text);
+        self.segments_for_entities(text, &entities)
+    }
+
+    /// Split the text into [Segment]s at the given entities, whose offsets are
+    /// in UTF-16 code units.
+    pub fn segments_for_entities(&self, text: &str, entities: &[Entity]) -> Vec<Segment> {
+        let mut segments: Vec<Segment> = Vec::with_capacity(entities.len() * 2 + 1);
+        let mut offset = 0i32;
+        walk_entities(text, entities, |piece, entity| {
+            let (start, end) = match entity {
+                Some(entity) => (entity.get_start(), entity.get_end()),
+                None => (offset, offset + piece.encode_utf16().count() as i32),
+            };
+            offset = end;
+            let segment = entity.and_then(|entity| self.segment(entity, text, piece, start, end));
+            match (segment, segments.last_mut()) {
+                (Some(segment), _) => segments.push(segment),
+                // Unlinked text joins the text before it.
+                (
+                    None,
+                    Some(Segment::Text {
+                        text,
+                        end: last_end,
+                        ..
+                    }),
+                ) if *last_end == start => {
+                    text.push_str(piece);
+                    *last_end = end;
                 }
+                (None, _) => segments.push(Segment::Text {
+                    text: piece.to_string(),
+                    start,
+                    end,
+                }),
             }
+        });
+        segments
+    }
 
-            // Skip past the entity in the source text
-            let entity_end_utf16 = entity.get_end();
-            while utf16_offset < entity_end_utf16 && byte_offset < text.len() {
-                let c = text[byte_offset..].chars().next().unwrap();
-                utf16_offset += c.len_utf16() as i32;
-                byte_offset += c.len_utf8();
+    // The link segment for an entity, or None if it stays plain text.
+    fn segment(
+        &self,
+        entity: &Entity,
+        text: &str,
+        source: &str,
+        start: i32,
+        end: i32,
+    ) -> Option<Segment> {
+        let symbol = || char_at_utf16_offset(text, start);
+        let value = entity.get_value();
+        let source = source.to_string();
+        let segment = match entity.get_type() {
+            entity::Type::HASHTAG => Segment::Hashtag {
+                text: source,
+                start,
+                end,
+                symbol: symbol().unwrap_or('#'),
+                tag: value.to_string(),
+                href: self.hashtag_href(value),
+                rtl: contains_rtl(text),
+            },
+            entity::Type::CASHTAG => Segment::Cashtag {
+                text: source,
+                start,
+                end,
+                tag: value.to_string(),
+                href: self.cashtag_href(value),
+            },
+            entity::Type::MENTION if entity.get_list_slug().is_empty() => Segment::Mention {
+                text: source,
+                start,
+                end,
+                symbol: symbol().unwrap_or('@'),
+                screen_name: value.to_string(),
+                href: self.mention_href(entity),
+                include_symbol: self.username_include_symbol,
+            },
+            entity::Type::MENTION => Segment::List {
+                text: source,
+                start,
+                end,
+                symbol: symbol().unwrap_or('@'),
+                screen_name: value.to_string(),
+                list_slug: entity.get_list_slug().to_string(),
+                href: self.mention_href(entity),
+                include_symbol: self.username_include_symbol,
+            },
+            entity::Type::URL if !self.drops_url(entity) => {
+                let display_url = entity.get_display_url();
+                let expanded_url = entity.get_expanded_url();
+                let expanded = !display_url.is_empty() && !expanded_url.is_empty();
+                Segment::Url {
+                    text: source,
+                    start,
+                    end,
+                    href: value.to_string(),
+                    display: if expanded { display_url } else { value }.to_string(),
+                    expanded: if expanded { expanded_url } else { value }.to_string(),
+                    display_parts: if expanded {
+                        DisplayUrlParts::split(display_url, expanded_url)
+                    } else {
+                        None
+                    },
+                }
             }
-        }
+            entity::Type::FEDERATEDMENTION => {
+                let (_, user, domain) = split_federated_mention(value)?;
+                Segment::FederatedMention {
+                    text: source,
+                    start,
+                    end,
+                    symbol: symbol().unwrap_or('@'),
+                    user: user.to_string(),
+                    domain: domain.to_string(),
+                    href: self.federated_mention_href(entity)?,
+                }
+            }
+            entity::Type::URL => return None,
+        };
+        Some(segment)
+    }
 
-        // Append remaining text
-        buf += &text[byte_offset..];
-        buf
+    // The text to extract entities from. Outside strict mode, only angle
+    // brackets are escaped; strict mode escapes all text as it is written.
+    fn escape_input<'t>(&self, original: &'t str) -> Cow<'t, str> {
+        if self.strict {
+            Cow::Borrowed(original)
+        } else {
+            escape_brackets(original)
+        }
     }
 
     /// Auto-link all entities.
     pub fn autolink(&self, original: &str) -> String {
-        let text = escape_brackets(original);
+        let text = self.escape_input(original);
         let entities = self.extractor.extract_entities_with_indices(&text);
         self.autolink_entities(&text, &entities)
     }
 
+    /// Auto-link all entities, writing the HTML to `w` instead of returning
+    /// it. See [Autolinker::autolink_entities_into].
+    pub fn autolink_into<W: fmt::Write>(&self, original: &str, w: &mut W) -> fmt::Result {
+        let text = self.escape_input(original);
+        let entities = self.extractor.extract_entities_with_indices(&text);
+        self.autolink_entities_into(&text, &entities, w)
+    }
+
+    /// Auto-link all entities, writing the HTML to `w` as UTF-8.
+    pub fn autolink_to_writer<W: io::Write>(&self, original: &str, w: &mut W) -> io::Result<()> {
+        write_io(w, |mut f| self.autolink_into(original, &mut f))
+    }
+
+    /// Auto-link all entities, including Mastodon-style @user@domain mentions.
+    /// Federated mention links use federated_mention_url_template and
+    /// federated_mention_class.
+    pub fn autolink_federated(&self, original: &str) -> String {
+        let text = self.escape_input(original);
+        let entities = self
+            .extractor
+            .extract_entities_with_indices_federated(&text);
+        self.autolink_entities(&text, &entities)
+    }
+
     /// Auto-link the @username and @username/list references in the provided text.
     /// Links to @username references will have the username_class CSS classes added.
     /// Links to @username/list references will have the list_class CSS class added.
@@ -505,6 +1263,66 @@ impl<'a> Autolinker<'a> {
     }
 }
 
+/// Split a federated mention, "@user@domain", into its symbol, user and domain.
+pub(crate) fn split_federated_mention(mention: &str) -> Option<(&str, &str, &str)> {
+    let symbol_len = mention.chars().next()?.len_utf8();
+    let (user, domain) = mention[symbol_len..].split_once('@')?;
+    Some((&mention[..symbol_len], user, domain))
+}
+
+/// Split `text` at the entities, whose offsets are in UTF-16 code units. Calls
+/// `f` in order with each run of text between entities and None, and with the
+/// source text of each entity and the entity.
+pub(crate) fn walk_entities<'t, 'e>(
+    text: &'t str,
+    entities: &'e [Entity<'e>],
+    mut f: impl FnMut(&'t str, Option<&'e Entity<'e>>),
+) {
+    // Track position in both UTF-16 code units (for entity indices) and bytes (for slicing)
+    let mut utf16_offset = 0i32;
+    let mut byte_offset = 0usize;
+    let mut advance = |utf16_target: i32| {
+        let start = byte_offset;
+        for c in text[byte_offset..].chars() {
+            if utf16_offset >= utf16_target {
+                break;
+            }
+            utf16_offset += c.len_utf16() as i32;
+            byte_offset += c.len_utf8();
+        }
+        &text[start..byte_offset]
+    };
+
+    for entity in entities {
+        let before = advance(entity.get_start());
+        if !before.is_empty() {
+            f(before, None);
+        }
+        let source = advance(entity.get_end());
+        f(source, Some(entity));
+    }
+
+    let rest = advance(i32::MAX);
+    if !rest.is_empty() {
+        f(rest, None);
+    }
+}
+
+/// An emoji's code points in lowercase hex, joined by "-". Like Twemoji, U+FE0F
+/// is dropped unless the emoji has a zero width joiner.
+fn emoji_codepoints(emoji: &str) -> String {
+    let keep_fe0f = emoji.contains('\u{200d}');
+    let mut codepoints = String::with_capacity(emoji.len() * 2);
+    for c in emoji.chars().filter(|&c| keep_fe0f || c != '\u{fe0f}') {
+        if !codepoints.is_empty() {
+            codepoints.push('-');
+        }
+        // Writing to a String can't fail.
+        let _ = write!(codepoints, "{:x}", c as u32);
+    }
+    codepoints
+}
+
 fn contains_rtl(s: &str) -> bool {
     for c in s.chars() {
         if ('\u{0600}'..='\u{06FF}').contains(&c)
@@ -519,45 +1337,92 @@ fn contains_rtl(s: &str) -> bool {
     false
 }
 
-/**
- * Adapted from <https://github.com/rust-lang/rust/blob/master/src/librustdoc/html/escape.rs>
- * Returns Cow::Borrowed if no escaping needed, avoiding allocation.
- */
-fn escape_html(s: &str) -> Cow<'_, str> {
-    // Fast path: check if any escaping is needed
-    let needs_escape = s
-        .bytes()
-        .any(|b| matches!(b, b'<' | b'>' | b'&' | b'\'' | b'"'));
-    if !needs_escape {
-        return Cow::Borrowed(s);
+/// An attribute value, kept in pieces so it can be written without building a String.
+enum AttrValue<'s> {
+    Str(&'s str),
+    Concat(&'s str, &'s str, &'s str),
+    Owned(String),
+}
+
+impl AttrValue<'_> {
+    fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        let (a, b, c) = match self {
+            AttrValue::Str(s) => (*s, "", ""),
+            AttrValue::Concat(a, b, c) => (*a, *b, *c),
+            AttrValue::Owned(s) => (s.as_str(), "", ""),
+        };
+        a.chars().chain(b.chars()).chain(c.chars())
     }
+}
 
-    let mut last = 0;
-    let mut buf = String::with_capacity(s.len() + 32);
-    for (i, ch) in s.bytes().enumerate() {
-        match ch {
-            b'<' | b'>' | b'&' | b'\'' | b'"' => {
-                buf.push_str(&s[last..i]);
-                let escaped = match ch {
-                    b'>' => "&gt;",
-                    b'<' => "&lt;",
-                    b'&' => "&amp;",
-                    b'\'' => "&#39;",
-                    b'"' => "&quot;",
-                    _ => unreachable!(),
-                };
-                buf.push_str(escaped);
-                last = i + 1;
+impl fmt::Display for AttrValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttrValue::Str(s) => f.write_str(s),
+            AttrValue::Concat(a, b, c) => {
+                f.write_str(a)?;
+                f.write_str(b)?;
+                f.write_str(c)
             }
-            _ => {}
+            AttrValue::Owned(s) => f.write_str(s),
         }
     }
+}
 
-    if last < s.len() {
-        buf.push_str(&s[last..]);
+fn write_attribute(w: &mut dyn fmt::Write, key: &str, value: &AttrValue) -> fmt::Result {
+    w.write_char(' ')?;
+    write_escaped_html(w, key)?;
+    w.write_str("=\"")?;
+    match value {
+        AttrValue::Str(s) => write_escaped_html(w, s)?,
+        AttrValue::Concat(a, b, c) => {
+            write_escaped_html(w, a)?;
+            write_escaped_html(w, b)?;
+            write_escaped_html(w, c)?;
+        }
+        AttrValue::Owned(s) => write_escaped_html(w, s)?,
     }
+    w.write_char('"')
+}
 
-    Cow::Owned(buf)
+fn write_start_tag(w: &mut dyn fmt::Write, tag: &str) -> fmt::Result {
+    if tag.is_empty() {
+        return Ok(());
+    }
+    w.write_char('<')?;
+    w.write_str(tag)?;
+    w.write_char('>')
+}
+
+fn write_end_tag(w: &mut dyn fmt::Write, tag: &str) -> fmt::Result {
+    if tag.is_empty() {
+        return Ok(());
+    }
+    w.write_str("</")?;
+    w.write_str(tag)?;
+    w.write_char('>')
+}
+
+/**
+ * Adapted from <https://github.com/rust-lang/rust/blob/master/src/librustdoc/html/escape.rs>
+ * Writes runs that need no escaping straight through.
+ */
+pub(crate) fn write_escaped_html(w: &mut dyn fmt::Write, s: &str) -> fmt::Result {
+    let mut last = 0;
+    for (i, ch) in s.bytes().enumerate() {
+        let escaped = match ch {
+            b'>' => "&gt;",
+            b'<' => "&lt;",
+            b'&' => "&amp;",
+            b'\'' => "&#39;",
+            b'"' => "&quot;",
+            _ => continue,
+        };
+        w.write_str(&s[last..i])?;
+        w.write_str(escaped)?;
+        last = i + 1;
+    }
+    w.write_str(&s[last..])
 }
 
 fn escape_brackets(s: &str) -> Cow<'_, str> {
@@ -595,14 +1460,17 @@ fn escape_brackets(s: &str) -> Cow<'_, str> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain_policy::DomainPolicy;
     use crate::entity::{Entity, Type};
 
     #[test]
     fn test_escape_html() {
         let s = "foo <bar> baz & 'hmm' or \"hmm\"";
+        let mut buf = String::new();
+        write_escaped_html(&mut buf, s).unwrap();
         assert_eq!(
             "foo &lt;bar&gt; baz &amp; &#39;hmm&#39; or &quot;hmm&quot;",
-            escape_html(s)
+            buf
         );
     }
 
@@ -765,6 +1633,7 @@ mod tests {
             list_slug: "",
             display_url: "blog.twitter.com/2011/05/twitte…",
             expanded_url: "http://blog.twitter.com/2011/05/twitter-for-mac-update.html",
+            host_span: None,
         };
 
         let entities = vec![entity];
@@ -780,6 +1649,33 @@ mod tests {
         assert!(result.contains("style='position:absolute;left:-9999px;'"));
     }
 
+    #[test]
+    fn test_autolink_with_domain_policy() {
+        let mut linker = Autolinker::new(false);
+        let policy = DomainPolicy::blocklist(["evil.com"]).unwrap();
+        linker.extractor.set_domain_policy(Some(policy));
+
+        let result = linker.autolink("go to http://www.evil.com/x or http://ok.com #tag");
+        assert!(!result.contains("href=\"http://www.evil.com/x\""));
+        assert!(result.contains("go to http://www.evil.com/x or"));
+        assert!(result.contains("href=\"http://ok.com\""));
+        assert!(result.contains("#tag</a>"));
+
+        // Entities from elsewhere are checked too, including the expanded URL.
+        let entity = Entity {
+            t: Type::URL,
+            start: 0,
+            end: 19,
+            value: "http://t.co/0JG5Mcq",
+            list_slug: "",
+            display_url: "evil.com/x",
+            expanded_url: "http://evil.com/x",
+            host_span: None,
+        };
+        let text = "http://t.co/0JG5Mcq";
+        assert_eq!(linker.autolink_entities(text, &vec![entity]), text);
+    }
+
     #[test]
     fn test_autolink_entities_mixed() {
         let linker = Autolinker::new(false);
@@ -794,6 +1690,7 @@ mod tests {
                 list_slug: "",
                 display_url: "",
                 expanded_url: "",
+                host_span: None,
             },
             Entity {
                 t: Type::MENTION,
@@ -803,6 +1700,7 @@ mod tests {
                 list_slug: "",
                 display_url: "",
                 expanded_url: "",
+                host_span: None,
             },
             Entity {
                 t: Type::URL,
@@ -812,6 +1710,7 @@ mod tests {
                 list_slug: "",
                 display_url: "",
                 expanded_url: "",
+                host_span: None,
             },
         ];
 
@@ -947,4 +1846,613 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_entity_renderer() {
+        struct Renderer;
+        impl EntityRenderer for Renderer {
+            fn render_hashtag(
+                &self,
+                autolinker: &Autolinker,
+                entity: &Entity,
+                _text: &str,
+                buf: &mut String,
+            ) {
+                buf.push_str("<button data-href=\"");
+                buf.push_str(&autolinker.default_href(entity).unwrap());
+                buf.push_str("\">");
+                buf.push_str(&autolinker.default_title(entity).unwrap());
+                buf.push_str("</button>");
+            }
+
+            fn render_mention(
+                &self,
+                autolinker: &Autolinker,
+                entity: &Entity,
+                text: &str,
+                buf: &mut String,
+            ) {
+                buf.push_str("<span class=\"hovercard\">");
+                autolinker.render_default(entity, text, buf);
+                buf.push_str("</span>");
+            }
+
+            fn render_list(
+                &self,
+                autolinker: &Autolinker,
+                entity: &Entity,
+                text: &str,
+                buf: &mut String,
+            ) {
+                buf.push_str("<b class=\"");
+                buf.push_str(&autolinker.default_class(entity, text).unwrap());
+                buf.push_str("\">");
+                buf.push_str(entity.get_value());
+                buf.push_str(entity.get_list_slug());
+                buf.push_str("</b>");
+            }
+        }
+
+        let mut linker = Autolinker::new(false);
+        linker.entity_renderer = Some(Box::new(Renderer));
+        assert_eq!(
+            linker.autolink("#tag @user @user/list $TWTR"),
+            "<button data-href=\"https://twitter.com/search?q=%23tag\">#tag</button> \
+             <span class=\"hovercard\">@<a class=\"tweet-url username\" \
+             href=\"https://twitter.com/user\">user</a></span> \
+             <b class=\"tweet-url list-slug\">user/list</b> \
+             <a href=\"https://twitter.com/search?q=%24TWTR\" title=\"$TWTR\" \
+             class=\"tweet-url cashtag\">$TWTR</a>"
+        );
+    }
+
+    #[test]
+    fn test_default_attributes() {
+        let mut linker = Autolinker::new(false);
+        let hashtag = Entity::new(entity::Type::HASHTAG, "tag", 0, 4);
+        assert_eq!(
+            linker.default_href(&hashtag).as_deref(),
+            Some("https://twitter.com/search?q=%23tag")
+        );
+        assert_eq!(linker.default_title(&hashtag).as_deref(), Some("#tag"));
+        assert_eq!(
+            linker.default_class(&hashtag, "#tag").as_deref(),
+            Some("tweet-url hashtag")
+        );
+        assert_eq!(
+            linker.default_class(&hashtag, "#tag שלום").as_deref(),
+            Some("tweet-url hashtag rtl")
+        );
+
+        let url = Entity::new(entity::Type::URL, "https://example.com", 0, 19);
+        assert_eq!(
+            linker.default_href(&url).as_deref(),
+            Some("https://example.com")
+        );
+        assert_eq!(linker.default_title(&url), None);
+        assert_eq!(linker.default_class(&url, ""), None);
+        linker.url_class = "link";
+        assert_eq!(linker.default_class(&url, "").as_deref(), Some("link"));
+
+        let mention = Entity::new(entity::Type::MENTION, "user", 0, 5);
+        assert_eq!(linker.default_title(&mention), None);
+        let federated = Entity::new(entity::Type::FEDERATEDMENTION, "@a@b.social", 0, 11);
+        assert_eq!(
+            linker.default_href(&federated).as_deref(),
+            Some("https://b.social/@a")
+        );
+        linker.federated_mention_url_template = "";
+        assert_eq!(linker.default_href(&federated), None);
+    }
+
+    #[test]
+    fn test_autolink_federated() {
+        let mut linker = Autolinker::new(false);
+        assert_eq!(
+            linker.autolink_federated("hi @alice@example.social and @bob"),
+            "hi <a class=\"tweet-url federated-mention\" \
+             href=\"https://example.social/@alice\">@alice@example.social</a> and \
+             @<a class=\"tweet-url username\" href=\"https://twitter.com/bob\">bob</a>"
+        );
+        // autolink doesn't extract federated mentions.
+        assert!(!linker
+            .autolink("hi @alice@example.social")
+            .contains("example.social/@alice"));
+
+        linker.federated_mention_url_template = "https://{domain}/users/{user}";
+        linker.federated_mention_h_card = true;
+        assert_eq!(
+            linker.autolink_federated("＠alice@example.social"),
+            "<span class=\"h-card\"><a class=\"u-url mention tweet-url federated-mention\" \
+             href=\"https://example.social/users/alice\" title=\"＠alice@example.social\">\
+             ＠<span>alice</span></a></span>"
+        );
+
+        linker.federated_mention_url_template = "";
+        assert_eq!(
+            linker.autolink_federated("hi @alice@example.social"),
+            "hi @alice@example.social"
+        );
+    }
+
+    #[test]
+    fn test_url_templates() {
+        let mut linker = Autolinker::new(false);
+        // Bases append the value unencoded, unless asked to encode it.
+        assert!(linker
+            .autolink("#日本語")
+            .contains("href=\"https://twitter.com/search?q=%23日本語\""));
+        assert!(linker
+            .autolink("@user/my-list")
+            .contains("href=\"https://twitter.com/user/my-list\""));
+        linker.encode_url_base_values = true;
+        assert!(linker
+            .autolink("#日本語")
+            .contains("href=\"https://twitter.com/search?q=%23%E6%97%A5%E6%9C%AC%E8%AA%9E\""));
+        assert!(linker
+            .autolink("$TWTR @user @user/my-list")
+            .contains("href=\"https://twitter.com/search?q=%24TWTR\""));
+        assert!(linker
+            .autolink("@user @user/my-list")
+            .contains("href=\"https://twitter.com/user\""));
+        assert!(linker
+            .autolink("@user/my-list")
+            .contains("href=\"https://twitter.com/user/my-list\""));
+        linker.encode_url_base_values = false;
+
+        linker.hashtag_url_template = "https://x.com/hashtag/{tag}?src=hashtag_click";
+        linker.cashtag_url_template = "https://x.com/search?q=%24{tag}&src=cashtag_click";
+        linker.username_url_template = "https://x.com/{user}";
+        linker.list_url_template = "https://x.com/{user}/lists/{list}";
+        assert_eq!(
+            linker.autolink("#日本語 $TWTR"),
+            "<a href=\"https://x.com/hashtag/%E6%97%A5%E6%9C%AC%E8%AA%9E?src=hashtag_click\" \
+             title=\"#日本語\" class=\"tweet-url hashtag\">#日本語</a> \
+             <a href=\"https://x.com/search?q=%24TWTR&amp;src=cashtag_click\" \
+             title=\"$TWTR\" class=\"tweet-url cashtag\">$TWTR</a>"
+        );
+        assert_eq!(
+            linker.autolink("@user @user/my-list"),
+            "@<a class=\"tweet-url username\" href=\"https://x.com/user\">user</a> \
+             @<a class=\"tweet-url list-slug\" href=\"https://x.com/user/lists/my-list\">\
+             user/my-list</a>"
+        );
+
+        linker.federated_mention_url_template = "https://{domain}/@{user}?from={x}";
+        let entity = Entity::new(Type::FEDERATEDMENTION, "@a_b@example.social", 0, 19);
+        assert_eq!(
+            linker.default_href(&entity).as_deref(),
+            Some("https://example.social/@a_b?from={x}")
+        );
+    }
+
+    #[test]
+    fn test_autolink_entities_federated() {
+        let mut linker = Autolinker::new(true);
+        linker.federated_mention_class = "";
+        let text = "🔥 @a_b@example.social";
+        let entity = Entity::new(Type::FEDERATEDMENTION, "@a_b@example.social", 3, 22);
+        assert_eq!(
+            linker.autolink_entities(text, &vec![entity]),
+            "🔥 <a href=\"https://example.social/@a_b\" rel=\"nofollow\">@a_b@example.social</a>"
+        );
+    }
+
+    #[test]
+    fn test_segments_federated() {
+        let linker = Autolinker::new(false);
+        let text = "hi @a@b.social";
+        let entity = Entity::new(Type::FEDERATEDMENTION, "@a@b.social", 3, 14);
+        let segments = linker.segments_for_entities(text, &[entity]);
+        assert_eq!(
+            segments[1],
+            Segment::FederatedMention {
+                text: "@a@b.social".to_string(),
+                start: 3,
+                end: 14,
+                symbol: '@',
+                user: "a".to_string(),
+                domain: "b.social".to_string(),
+                href: "https://b.social/@a".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_segments() {
+        let mut linker = Autolinker::new(false);
+        linker.username_include_symbol = true;
+        let segments =
+            linker.segments("🔥 hi @user, see #tag and https://t.co @a@b.social $X ＠bob/list");
+        assert_eq!(
+            segments,
+            vec![
+                Segment::Text {
+                    text: "🔥 hi ".to_string(),
+                    start: 0,
+                    end: 6
+                },
+                Segment::Mention {
+                    text: "@user".to_string(),
+                    start: 6,
+                    end: 11,
+                    symbol: '@',
+                    screen_name: "user".to_string(),
+                    href: "https://twitter.com/user".to_string(),
+                    include_symbol: true,
+                },
+                Segment::Text {
+                    text: ", see ".to_string(),
+                    start: 11,
+                    end: 17
+                },
+                Segment::Hashtag {
+                    text: "#tag".to_string(),
+                    start: 17,
+                    end: 21,
+                    symbol: '#',
+                    tag: "tag".to_string(),
+                    href: "https://twitter.com/search?q=%23tag".to_string(),
+                    rtl: false,
+                },
+                Segment::Text {
+                    text: " and ".to_string(),
+                    start: 21,
+                    end: 26
+                },
+                Segment::Url {
+                    text: "https://t.co".to_string(),
+                    start: 26,
+                    end: 38,
+                    href: "https://t.co".to_string(),
+                    display: "https://t.co".to_string(),
+                    expanded: "https://t.co".to_string(),
+                    display_parts: None,
+                },
+                Segment::Text {
+                    text: " @a@b.social ".to_string(),
+                    start: 38,
+                    end: 51
+                },
+                Segment::Cashtag {
+                    text: "$X".to_string(),
+                    start: 51,
+                    end: 53,
+                    tag: "X".to_string(),
+                    href: "https://twitter.com/search?q=%24X".to_string(),
+                },
+                Segment::Text {
+                    text: " ".to_string(),
+                    start: 53,
+                    end: 54
+                },
+                Segment::List {
+                    text: "＠bob/list".to_string(),
+                    start: 54,
+                    end: 63,
+                    symbol: '＠',
+                    screen_name: "bob".to_string(),
+                    list_slug: "/list".to_string(),
+                    href: "https://twitter.com/bob/list".to_string(),
+                    include_symbol: true,
+                },
+            ]
+        );
+        let joined: String = segments.iter().map(Segment::text).collect();
+        assert_eq!(
+            joined,
+            "🔥 hi @user, see #tag and https://t.co @a@b.social $X ＠bob/list"
+        );
+    }
+
+    #[test]
+    fn test_segments_for_entities() {
+        let mut linker = Autolinker::new(false);
+        let policy = DomainPolicy::blocklist(["evil.com"]).unwrap();
+        linker.extractor.set_domain_policy(Some(policy));
+        let entities = vec![
+            Entity {
+                t: Type::URL,
+                start: 0,
+                end: 19,
+                value: "http://t.co/0JG5Mcq",
+                list_slug: "",
+                display_url: "blog.twitter.com/2011/05/twitte…",
+                expanded_url: "http://blog.twitter.com/2011/05/twitter-for-mac-update.html",
+                host_span: None,
+            },
+            Entity {
+                t: Type::URL,
+                start: 20,
+                end: 39,
+                value: "http://t.co/1JG5Mcq",
+                list_slug: "",
+                display_url: "evil.com/x",
+                expanded_url: "http://evil.com/x",
+                host_span: None,
+            },
+        ];
+        let text = "http://t.co/0JG5Mcq http://t.co/1JG5Mcq";
+        let segments = linker.segments_for_entities(text, &entities);
+        assert_eq!(segments.len(), 2);
+        match &segments[0] {
+            Segment::Url {
+                display,
+                expanded,
+                display_parts: Some(parts),
+                ..
+            } => {
+                assert_eq!(display, "blog.twitter.com/2011/05/twitte…");
+                assert_eq!(expanded, entities[0].expanded_url);
+                assert_eq!(parts.before, "http://");
+                assert!(parts.trailing_ellipsis);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        // The blocked URL is plain text, joined to the space before it.
+        assert_eq!(
+            segments[1],
+            Segment::Text {
+                text: " http://t.co/1JG5Mcq".to_string(),
+                start: 19,
+                end: 39
+            }
+        );
+        assert_eq!(segments[0].href(), Some("http://t.co/0JG5Mcq"));
+        assert_eq!(segments[1].href(), None);
+    }
+
+    #[test]
+    fn test_segments_rtl() {
+        let linker = Autolinker::new(false);
+        let segments = linker.segments("#سلام");
+        assert!(matches!(&segments[0], Segment::Hashtag { rtl: true, .. }));
+    }
+
+    #[test]
+    fn test_autolink_into() {
+        let texts = [
+            "#hashtag @user @user/list $CASH http://example.com/?a=1&b=2 <b>",
+            "#سلام @ｕｓｅｒ ＠user",
+            "no entities",
+        ];
+        let mut linker = Autolinker::new(true);
+        linker.symbol_tag = "s";
+        linker.text_with_symbol_tag = "b";
+        linker.include_data_screen_name = true;
+        linker.url_target = "_blank";
+        for text in texts {
+            let mut buf = String::new();
+            linker.autolink_into(text, &mut buf).unwrap();
+            assert_eq!(buf, linker.autolink(text));
+
+            let mut out = Vec::new();
+            linker.autolink_to_writer(text, &mut out).unwrap();
+            assert_eq!(String::from_utf8(out).unwrap(), linker.autolink(text));
+        }
+
+        struct Upper;
+        impl LinkTextModifier for Upper {
+            fn modify(&self, _entity: &Entity, text: &str) -> String {
+                text.to_uppercase()
+            }
+        }
+        struct Plain;
+        impl EntityRenderer for Plain {
+            fn render_mention(
+                &self,
+                _autolinker: &Autolinker,
+                entity: &Entity,
+                _text: &str,
+                buf: &mut String,
+            ) {
+                buf.push_str(entity.get_value());
+            }
+        }
+        linker.link_text_modifier = Some(Box::new(Upper));
+        linker.entity_renderer = Some(Box::new(Plain));
+        let mut buf = String::new();
+        linker.autolink_into("@a #b @c", &mut buf).unwrap();
+        assert_eq!(buf, linker.autolink("@a #b @c"));
+        assert!(buf.starts_with("a <a href="));
+        // The text modifier sees the whole link text, symbol tags included.
+        assert!(buf.ends_with("<S>#</S><B>B</B></a> c"));
+    }
+
+    #[test]
+    fn test_autolink_into_tco_url() {
+        let linker = Autolinker::new(false);
+        let entity = Entity {
+            t: Type::URL,
+            start: 0,
+            end: 19,
+            value: "http://t.co/0JG5Mcq",
+            list_slug: "",
+            display_url: "blog.twitter.com/2011/05/twitte…",
+            expanded_url: "http://blog.twitter.com/2011/05/twitter-for-mac-update.html",
+            host_span: None,
+        };
+        let mut buf = String::new();
+        linker
+            .autolink_entities_into("http://t.co/0JG5Mcq", &[entity], &mut buf)
+            .unwrap();
+        assert_eq!(
+            buf,
+            "<a href=\"http://t.co/0JG5Mcq\" \
+             title=\"http://blog.twitter.com/2011/05/twitter-for-mac-update.html\">\
+             <span class='tco-ellipsis'><span style='position:absolute;left:-9999px;'>\
+             &nbsp;</span></span>\
+             <span style='position:absolute;left:-9999px;'>http://</span>\
+             <span class='js-display-url'>blog.twitter.com/2011/05/twitte</span>\
+             <span style='position:absolute;left:-9999px;'>r-for-mac-update.html</span>\
+             <span class='tco-ellipsis'><span style='position:absolute;left:-9999px;'>\
+             &nbsp;</span>…</span></a>"
+        );
+    }
+
+    #[test]
+    fn test_autolink_to_writer_error() {
+        struct Full;
+        impl io::Write for Full {
+            fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::WriteZero, "full"))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let linker = Autolinker::new(false);
+        let err = linker.autolink_to_writer("#tag", &mut Full).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    }
+
+    #[test]
+    fn test_strict() {
+        let mut linker = Autolinker::new(false);
+        linker.strict = true;
+        assert_eq!(
+            linker.autolink("\"Tom & Jerry\" <b>#tag</b>"),
+            "&quot;Tom &amp; Jerry&quot; &lt;b&gt;<a href=\"https://twitter.com/search?q=%23tag\" \
+             title=\"#tag\" class=\"tweet-url hashtag\">#tag</a>&lt;/b&gt;"
+        );
+
+        // Symbol tags must be tag names.
+        linker.symbol_tag = "b onclick=alert(1)";
+        linker.text_with_symbol_tag = "i";
+        assert_eq!(
+            linker.autolink("$TWTR"),
+            "<a href=\"https://twitter.com/search?q=%24TWTR\" title=\"$TWTR\" \
+             class=\"tweet-url cashtag\">$<i>TWTR</i></a>"
+        );
+        linker.symbol_tag = "";
+        linker.text_with_symbol_tag = "";
+
+        // Unsafe hrefs and attributes outside the allowlist are dropped.
+        let text = "javascript:alert(1)";
+        let entity = Entity::new(Type::URL, text, 0, 19);
+        assert_eq!(
+            linker.autolink_entities(text, &vec![entity]),
+            "<a>javascript:alert(1)</a>"
+        );
+        struct OnClick;
+        impl LinkAttributeModifier for OnClick {
+            fn modify(&self, _entity: &Entity, attributes: &mut Attributes) {
+                attributes.push(("onclick".to_string(), "x()".to_string()));
+                attributes.push(("data-tag".to_string(), "1".to_string()));
+            }
+        }
+        linker.link_attribute_modifier = Some(Box::new(OnClick));
+        assert_eq!(
+            linker.autolink("@bob"),
+            "@<a class=\"tweet-url username\" href=\"https://twitter.com/bob\" \
+             data-tag=\"1\">bob</a>"
+        );
+        linker.link_attribute_modifier = None;
+
+        // Custom invisible span attributes fall back to the defaults.
+        linker.invisible_tag_attrs = "onmouseover='x()'";
+        let entity = Entity {
+            t: Type::URL,
+            start: 0,
+            end: 19,
+            value: "http://t.co/0JG5Mcq",
+            list_slug: "",
+            display_url: "example.com/a…",
+            expanded_url: "http://example.com/abc",
+            host_span: None,
+        };
+        let result = linker.autolink_entities("http://t.co/0JG5Mcq", &vec![entity]);
+        assert!(!result.contains("onmouseover"));
+        assert!(result.contains(DEFAULT_INVISIBLE_TAG_ATTRS));
+    }
+
+    #[test]
+    fn test_strict_link_text_modifier() {
+        struct Bold {
+            safe: bool,
+        }
+        impl LinkTextModifier for Bold {
+            fn modify(&self, _entity: &Entity, text: &str) -> String {
+                format!("<b>{}</b>", text)
+            }
+            fn is_safe_html(&self) -> bool {
+                self.safe
+            }
+        }
+        let mut linker = Autolinker::new(false);
+        linker.strict = true;
+        linker.symbol_tag = "s";
+        linker.link_text_modifier = Some(Box::new(Bold { safe: false }));
+        assert_eq!(
+            linker.autolink("#a&b"),
+            "<a href=\"https://twitter.com/search?q=%23a\" title=\"#a\" \
+             class=\"tweet-url hashtag\">&lt;b&gt;#a&lt;/b&gt;</a>&amp;b"
+        );
+        linker.link_text_modifier = Some(Box::new(Bold { safe: true }));
+        assert_eq!(
+            linker.autolink("#a"),
+            "<a href=\"https://twitter.com/search?q=%23a\" title=\"#a\" \
+             class=\"tweet-url hashtag\"><b><s>#</s>a</b></a>"
+        );
+    }
+
+    #[test]
+    fn test_emoji_codepoints() {
+        assert_eq!(emoji_codepoints("😀"), "1f600");
+        assert_eq!(emoji_codepoints("❤️"), "2764");
+        assert_eq!(emoji_codepoints("🏳️‍🌈"), "1f3f3-fe0f-200d-1f308");
+        assert_eq!(emoji_codepoints("👍🏽"), "1f44d-1f3fd");
+    }
+
+    #[test]
+    fn test_emoji_rendering() {
+        let mut linker = Autolinker::new(false);
+        assert_eq!(linker.autolink("hi 😀"), "hi 😀");
+
+        linker.emoji_rendering = EmojiRendering::Image;
+        assert_eq!(
+            linker.autolink("hi 😀 & ❤️"),
+            "hi <img class=\"emoji\" draggable=\"false\" alt=\"😀\" \
+             src=\"https://cdn.jsdelivr.net/gh/jdecked/twemoji@latest/assets/svg/1f600.svg\"> \
+             & <img class=\"emoji\" draggable=\"false\" alt=\"❤️\" \
+             src=\"https://cdn.jsdelivr.net/gh/jdecked/twemoji@latest/assets/svg/2764.svg\">"
+        );
+
+        linker.emoji_rendering = EmojiRendering::Span;
+        linker.emoji_class = "e";
+        assert_eq!(
+            linker.autolink("😀 #tag"),
+            "<span class=\"e\">😀</span> <a href=\"https://twitter.com/search?q=%23tag\" \
+             title=\"#tag\" class=\"tweet-url hashtag\">#tag</a>"
+        );
+
+        // Entity text isn't scanned, but the text after it is.
+        let text = "http://t.co/a😀";
+        let entity = Entity::new(Type::URL, "http://t.co/a", 0, 13);
+        assert_eq!(
+            linker.autolink_entities(text, &vec![entity]),
+            "<a href=\"http://t.co/a\">http://t.co/a</a><span class=\"e\">😀</span>"
+        );
+
+        // Strict mode escapes the text around emoji and filters the template.
+        linker.strict = true;
+        linker.emoji_rendering = EmojiRendering::Image;
+        linker.emoji_image_url_template = "javascript:{codepoints}";
+        assert_eq!(
+            linker.autolink("<😀>"),
+            "&lt;<img class=\"e\" draggable=\"false\" alt=\"😀\">&gt;"
+        );
+    }
+
+    #[test]
+    fn test_emoji_rendering_names() {
+        for rendering in [
+            EmojiRendering::None,
+            EmojiRendering::Image,
+            EmojiRendering::Span,
+        ] {
+            assert_eq!(EmojiRendering::from_name(rendering.name()), Some(rendering));
+        }
+        assert_eq!(EmojiRendering::from_name("svg"), None);
+    }
 }
@@ -0,0 +1,165 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! The display range of a post.
+//!
+//! A reply's text starts with the mentions the client filled in, and a post
+//! with media or a quote ends with the attachment's URL. Clients hide both,
+//! showing only the display range. [display_text_range_for] finds it from the
+//! reply context and the attachment URLs, the way the official apps do.
+
+use crate::entity::{Entity, Type};
+use crate::extractor::{Extract, Extractor};
+use twitter_text_config::Range;
+
+/// The context that decides which parts of a post are hidden.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisplayTextOptions<'a> {
+    /// The screen names a reply's leading mentions were filled in from: the
+    /// author of the post being replied to and the users it mentions. Empty
+    /// for posts that aren't replies. Compared without case.
+    pub reply_to_screen_names: &'a [&'a str],
+    /// The URLs of the post's media, quoted post or other attachment, which
+    /// are hidden when they end the text.
+    pub attachment_urls: &'a [&'a str],
+}
+
+/// The display range of a post and the entities inside it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayText<'t> {
+    /// The displayed text in UTF-16 code units. Like
+    /// [TwitterTextParseResults::display_text_range](crate::TwitterTextParseResults::display_text_range),
+    /// the end is inclusive, so the range is empty when `end < start`.
+    pub range: Range,
+    /// The entities inside the range, with offsets into the whole text.
+    pub entities: Vec<Entity<'t>>,
+}
+
+/// Find the display range of `text`. Leading mentions of the users in
+/// `options.reply_to_screen_names` are left out, up to the first mention or
+/// other text that isn't one, as are attachment URLs at the end of the text.
+/// Whitespace around the hidden parts is left out too.
+pub fn display_text_range_for<'t>(text: &'t str, options: &DisplayTextOptions) -> DisplayText<'t> {
+    let entities = Extractor::new().extract_entities_with_indices(text);
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let is_space = |unit: u16| char::from_u32(unit as u32).is_some_and(char::is_whitespace);
+
+    let skip_space = |mut i: usize| {
+        while i < units.len() && is_space(units[i]) {
+            i += 1;
+        }
+        i
+    };
+    let mut start = skip_space(0);
+    for entity in &entities {
+        let is_reply_mention = entity.get_type() == Type::MENTION
+            && entity.get_list_slug().is_empty()
+            && options
+                .reply_to_screen_names
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(entity.get_value()));
+        if entity.get_start() as usize != start || !is_reply_mention {
+            break;
+        }
+        start = skip_space(entity.get_end() as usize);
+    }
+
+    let trim_space = |mut i: usize| {
+        while i > start && is_space(units[i - 1]) {
+            i -= 1;
+        }
+        i
+    };
+    let mut end = trim_space(units.len());
+    for entity in entities.iter().rev() {
+        let is_attachment =
+            entity.get_type() == Type::URL && options.attachment_urls.contains(&entity.get_value());
+        if entity.get_end() as usize != end || entity.get_start() < start as i32 || !is_attachment {
+            break;
+        }
+        end = trim_space(entity.get_start() as usize);
+    }
+
+    let entities = entities
+        .into_iter()
+        .filter(|e| e.get_start() >= start as i32 && e.get_end() <= end as i32)
+        .collect();
+    DisplayText {
+        range: Range::new(start as i32, end as i32 - 1),
+        entities,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn display(text: &str, reply_to: &[&str], attachments: &[&str]) -> (i32, i32, usize) {
+        let options = DisplayTextOptions {
+            reply_to_screen_names: reply_to,
+            attachment_urls: attachments,
+        };
+        let result = display_text_range_for(text, &options);
+        (
+            result.range.start(),
+            result.range.end(),
+            result.entities.len(),
+        )
+    }
+
+    #[test]
+    fn test_plain_text() {
+        let text = "@a hello #tag";
+        assert_eq!(display(text, &[], &[]), (0, 12, 2));
+        assert_eq!(display("", &[], &[]), (0, -1, 0));
+    }
+
+    #[test]
+    fn test_reply_mentions() {
+        let reply_to = ["Alice", "bob"];
+        assert_eq!(
+            display("@alice @Bob  hi @alice", &reply_to, &[]),
+            (13, 21, 1)
+        );
+        // A mention that wasn't filled in ends the prefix, and so does text.
+        assert_eq!(display("@alice @carol @bob hi", &reply_to, &[]), (7, 20, 2));
+        assert_eq!(display("hi @alice", &reply_to, &[]), (0, 8, 1));
+        // Lists aren't reply mentions.
+        assert_eq!(display("@alice/list hi", &reply_to, &[]), (0, 13, 1));
+        // A reply that is all mentions has an empty range.
+        assert_eq!(display("@alice @bob ", &reply_to, &[]), (12, 11, 0));
+    }
+
+    #[test]
+    fn test_attachment_urls() {
+        let photo = "https://t.co/photo";
+        let quote = "https://twitter.com/a/status/1";
+        let text = "look 😀 https://t.co/photo";
+        assert_eq!(display(text, &[], &[photo]), (0, 6, 0));
+        // Only URLs at the end are hidden.
+        let text = "https://t.co/photo is a photo";
+        assert_eq!(display(text, &[], &[photo]), (0, 28, 1));
+        let text = "see https://example.com https://twitter.com/a/status/1 https://t.co/photo ";
+        assert_eq!(display(text, &[], &[photo, quote]), (0, 22, 1));
+    }
+
+    #[test]
+    fn test_reply_with_attachment() {
+        let options = DisplayTextOptions {
+            reply_to_screen_names: &["jack"],
+            attachment_urls: &["https://t.co/x"],
+        };
+        let text = "@jack #first post https://t.co/x";
+        let result = display_text_range_for(text, &options);
+        assert_eq!(result.range, Range::new(6, 16));
+        assert_eq!(result.entities.len(), 1);
+        assert_eq!(result.entities[0].get_value(), "first");
+        assert_eq!(result.entities[0].get_start(), 6);
+
+        // A reply with only an attachment has an empty range.
+        let result = display_text_range_for("@jack https://t.co/x", &options);
+        assert_eq!(result.range, Range::new(6, 5));
+        assert!(result.entities.is_empty());
+    }
+}
@@ -0,0 +1,420 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Per-domain policy for URL entities.
+//!
+//! A [DomainPolicy] assigns each URL a [DomainVerdict] based on its host.
+//! Rules match on whole labels, so `example.com` covers `www.example.com` but
+//! not `badexample.com`, and `*.example.com` covers subdomains only. Hosts and
+//! rules are compared in their Unicode form, so punycode and Unicode spellings
+//! of an IDN match each other.
+
+use crate::entity::{Entity, Type};
+use crate::nom_parser::url::host_span;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+/// What to do with a URL, ordered from least to most restrictive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DomainVerdict {
+    /// Link the URL as usual.
+    #[default]
+    Allow,
+    /// Keep the URL, but mark it for review.
+    Flag,
+    /// Refuse to link the URL.
+    Block,
+}
+
+/// Errors from adding a rule to a [DomainPolicy].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainPolicyError {
+    /// The pattern is empty, has an empty label, or has a `*` anywhere but
+    /// as the whole first label.
+    InvalidPattern(String),
+}
+
+impl fmt::Display for DomainPolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DomainPolicyError::InvalidPattern(pattern) => {
+                write!(f, "invalid domain pattern: {:?}", pattern)
+            }
+        }
+    }
+}
+
+impl Error for DomainPolicyError {}
+
+// The verdicts for the rules that share a suffix.
+#[derive(Debug, Clone, Copy, Default)]
+struct Rule {
+    // "example.com": the domain itself and its subdomains.
+    domain: Option<DomainVerdict>,
+    // "*.example.com": subdomains only.
+    subdomains: Option<DomainVerdict>,
+}
+
+/// Allow, flag or block URL entities by host.
+///
+/// When several rules match, the one naming the most labels wins, and a tie
+/// goes to the more restrictive verdict. Hosts that match no rule get
+/// `default_verdict`, which is [DomainVerdict::Block] for an allowlist.
+///
+/// Attach a policy to an extractor with
+/// [Extractor::set_domain_policy](crate::extractor::Extractor::set_domain_policy).
+#[derive(Debug, Clone)]
+pub struct DomainPolicy {
+    /// The verdict for hosts that match no rule.
+    pub default_verdict: DomainVerdict,
+    /// Drop blocked URLs from extraction results, so they are neither linked
+    /// by the [crate::autolinker::Autolinker] nor weighted as URLs when
+    /// validating. When false, blocked URLs are extracted like any other and
+    /// callers check [DomainPolicy::verdict] themselves.
+    pub drop_blocked: bool,
+    // Normalized rule text without the "*." prefix.
+    rules: HashMap<String, Rule>,
+}
+
+impl DomainPolicy {
+    /// An empty policy that allows everything and drops blocked URLs.
+    pub fn new() -> DomainPolicy {
+        DomainPolicy {
+            default_verdict: DomainVerdict::Allow,
+            drop_blocked: true,
+            rules: HashMap::new(),
+        }
+    }
+
+    /// A policy that blocks every host except those matching `patterns`.
+    pub fn allowlist<I, S>(patterns: I) -> Result<DomainPolicy, DomainPolicyError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut policy = DomainPolicy::new();
+        policy.default_verdict = DomainVerdict::Block;
+        for pattern in patterns {
+            policy.add_rule(pattern.as_ref(), DomainVerdict::Allow)?;
+        }
+        Ok(policy)
+    }
+
+    /// A policy that blocks hosts matching `patterns` and allows the rest.
+    pub fn blocklist<I, S>(patterns: I) -> Result<DomainPolicy, DomainPolicyError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut policy = DomainPolicy::new();
+        for pattern in patterns {
+            policy.add_rule(pattern.as_ref(), DomainVerdict::Block)?;
+        }
+        Ok(policy)
+    }
+
+    /// Add a rule. `example.com` matches the domain and all of its subdomains,
+    /// `*.example.com` matches only the subdomains. IP addresses match exactly.
+    pub fn add_rule(
+        &mut self,
+        pattern: &str,
+        verdict: DomainVerdict,
+    ) -> Result<(), DomainPolicyError> {
+        let invalid = || DomainPolicyError::InvalidPattern(pattern.to_string());
+        let trimmed = pattern.trim();
+        let (subdomains_only, domain) = match trimmed.strip_prefix("*.") {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+        let key = normalize_host(domain).ok_or_else(invalid)?;
+        if key.split('.').any(|label| label.contains('*')) {
+            return Err(invalid());
+        }
+        let rule = self.rules.entry(key).or_default();
+        let slot = if subdomains_only {
+            &mut rule.subdomains
+        } else {
+            &mut rule.domain
+        };
+        // Keep the most restrictive verdict if a pattern is listed twice.
+        *slot = Some(slot.map_or(verdict, |v| v.max(verdict)));
+        Ok(())
+    }
+
+    /// Add a rule for each line of `input`, as in a blocklist file. Blank lines
+    /// and lines starting with `#` are ignored.
+    pub fn add_rules(
+        &mut self,
+        input: &str,
+        verdict: DomainVerdict,
+    ) -> Result<(), DomainPolicyError> {
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            self.add_rule(line, verdict)?;
+        }
+        Ok(())
+    }
+
+    /// The number of distinct rules in the policy.
+    pub fn len(&self) -> usize {
+        self.rules
+            .values()
+            .map(|r| r.domain.is_some() as usize + r.subdomains.is_some() as usize)
+            .sum()
+    }
+
+    /// Returns true if the policy has no rules.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// The verdict for an entity. Entities other than URLs are always allowed.
+    pub fn verdict(&self, entity: &Entity) -> DomainVerdict {
+        match entity.get_type() {
+            Type::URL => match entity.host() {
+                Some(host) => self.verdict_for_host(host),
+                None => self.default_verdict,
+            },
+            _ => DomainVerdict::Allow,
+        }
+    }
+
+    /// The verdict for a URL, with or without protocol.
+    pub fn verdict_for_url(&self, url: &str) -> DomainVerdict {
+        match host_span(url) {
+            Some((start, end)) => self.verdict_for_host(&url[start..end]),
+            None => self.default_verdict,
+        }
+    }
+
+    /// The verdict for a bare host, such as "www.example.com".
+    pub fn verdict_for_host(&self, host: &str) -> DomainVerdict {
+        let normalized = match normalize_host(host) {
+            Some(normalized) => normalized,
+            None => return self.default_verdict,
+        };
+        if is_ip_literal(&normalized) {
+            return match self.rules.get(&normalized).and_then(|r| r.domain) {
+                Some(verdict) => verdict,
+                None => self.default_verdict,
+            };
+        }
+
+        // Walk the suffixes from the TLD outward, keeping the longest match.
+        let label_starts =
+            std::iter::once(0).chain(normalized.match_indices('.').map(|(i, _)| i + 1));
+        let label_starts: Vec<usize> = label_starts.collect();
+        let mut best = None;
+        for &start in label_starts.iter().rev() {
+            let rule = match self.rules.get(&normalized[start..]) {
+                Some(rule) => rule,
+                None => continue,
+            };
+            let subdomains = if start == 0 { None } else { rule.subdomains };
+            let verdict = match (rule.domain, subdomains) {
+                (Some(a), Some(b)) => Some(a.max(b)),
+                (a, b) => a.or(b),
+            };
+            if verdict.is_some() {
+                best = verdict;
+            }
+        }
+        best.unwrap_or(self.default_verdict)
+    }
+
+    // Returns true if extractors should leave this URL out of their results.
+    pub(crate) fn drops_url(&self, url: &str) -> bool {
+        self.drop_blocked && self.verdict_for_url(url) == DomainVerdict::Block
+    }
+
+    // Returns true if extractors should leave URLs with this host out of
+    // their results.
+    pub(crate) fn drops_host(&self, host: &str) -> bool {
+        self.drop_blocked && self.verdict_for_host(host) == DomainVerdict::Block
+    }
+}
+
+impl Default for DomainPolicy {
+    fn default() -> Self {
+        DomainPolicy::new()
+    }
+}
+
+/// Lowercase a host, map full-width dots and decode punycode labels.
+/// Returns None for empty hosts and hosts with empty labels.
+fn normalize_host(host: &str) -> Option<String> {
+    let host = host.strip_suffix('.').unwrap_or(host);
+    if host.is_empty() {
+        return None;
+    }
+    let normalized = if host.starts_with('[') {
+        host.to_lowercase()
+    } else {
+        let (unicode, result) = idna::domain_to_unicode(host);
+        if result.is_ok() {
+            unicode
+        } else {
+            host.to_lowercase()
+        }
+    };
+    if !normalized.starts_with('[') && normalized.split('.').any(|label| label.is_empty()) {
+        return None;
+    }
+    Some(normalized)
+}
+
+fn is_ip_literal(host: &str) -> bool {
+    host.starts_with('[')
+        || host
+            .split('.')
+            .all(|l| l.bytes().all(|b| b.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_suffix_matching() {
+        let policy = DomainPolicy::blocklist(["example.com"]).unwrap();
+        assert_eq!(policy.verdict_for_host("example.com"), DomainVerdict::Block);
+        assert_eq!(
+            policy.verdict_for_host("WWW.Example.COM"),
+            DomainVerdict::Block
+        );
+        assert_eq!(
+            policy.verdict_for_host("a.b.example.com."),
+            DomainVerdict::Block
+        );
+        assert_eq!(
+            policy.verdict_for_host("badexample.com"),
+            DomainVerdict::Allow
+        );
+        assert_eq!(policy.verdict_for_host("example.org"), DomainVerdict::Allow);
+    }
+
+    #[test]
+    fn test_wildcards() {
+        let policy = DomainPolicy::blocklist(["*.example.com"]).unwrap();
+        assert_eq!(policy.verdict_for_host("example.com"), DomainVerdict::Allow);
+        assert_eq!(
+            policy.verdict_for_host("www.example.com"),
+            DomainVerdict::Block
+        );
+
+        let mut policy = DomainPolicy::new();
+        assert!(policy.add_rule("ex*.com", DomainVerdict::Block).is_err());
+        assert!(policy.add_rule("a.*.com", DomainVerdict::Block).is_err());
+        assert!(policy.add_rule("", DomainVerdict::Block).is_err());
+        assert!(policy.add_rule("a..com", DomainVerdict::Block).is_err());
+    }
+
+    #[test]
+    fn test_most_specific_rule_wins() {
+        let mut policy = DomainPolicy::blocklist(["example.com"]).unwrap();
+        policy
+            .add_rule("safe.example.com", DomainVerdict::Allow)
+            .unwrap();
+        policy
+            .add_rule("*.shady.example.com", DomainVerdict::Flag)
+            .unwrap();
+        assert_eq!(
+            policy.verdict_for_host("safe.example.com"),
+            DomainVerdict::Allow
+        );
+        assert_eq!(
+            policy.verdict_for_host("x.safe.example.com"),
+            DomainVerdict::Allow
+        );
+        assert_eq!(
+            policy.verdict_for_host("shady.example.com"),
+            DomainVerdict::Block
+        );
+        assert_eq!(
+            policy.verdict_for_host("x.shady.example.com"),
+            DomainVerdict::Flag
+        );
+
+        // Ties go to the more restrictive verdict.
+        policy
+            .add_rule("safe.example.com", DomainVerdict::Flag)
+            .unwrap();
+        assert_eq!(
+            policy.verdict_for_host("safe.example.com"),
+            DomainVerdict::Flag
+        );
+        assert_eq!(policy.len(), 3);
+    }
+
+    #[test]
+    fn test_allowlist() {
+        let policy = DomainPolicy::allowlist(["example.com", "*.example.org"]).unwrap();
+        assert_eq!(
+            policy.verdict_for_host("www.example.com"),
+            DomainVerdict::Allow
+        );
+        assert_eq!(policy.verdict_for_host("example.org"), DomainVerdict::Block);
+        assert_eq!(
+            policy.verdict_for_host("a.example.org"),
+            DomainVerdict::Allow
+        );
+        assert_eq!(policy.verdict_for_host("example.net"), DomainVerdict::Block);
+    }
+
+    #[test]
+    fn test_idn() {
+        let policy = DomainPolicy::blocklist(["пример.рф"]).unwrap();
+        assert_eq!(
+            policy.verdict_for_host("www.xn--e1afmkfd.xn--p1ai"),
+            DomainVerdict::Block
+        );
+        assert_eq!(policy.verdict_for_host("ПРИМЕР.РФ"), DomainVerdict::Block);
+        assert_eq!(
+            policy.verdict_for_host("www.пример。рф"),
+            DomainVerdict::Block
+        );
+
+        let policy = DomainPolicy::blocklist(["xn--e1afmkfd.xn--p1ai"]).unwrap();
+        assert_eq!(policy.verdict_for_host("пример.рф"), DomainVerdict::Block);
+    }
+
+    #[test]
+    fn test_ip_hosts_match_exactly() {
+        let policy = DomainPolicy::blocklist(["10.0.0.1", "0.1"]).unwrap();
+        assert_eq!(policy.verdict_for_host("10.0.0.1"), DomainVerdict::Block);
+        assert_eq!(policy.verdict_for_host("192.168.0.1"), DomainVerdict::Allow);
+    }
+
+    #[test]
+    fn test_urls_and_entities() {
+        let mut policy = DomainPolicy::new();
+        policy
+            .add_rules(
+                "# comment\nevil.example\n\n*.tracker.example\n",
+                DomainVerdict::Block,
+            )
+            .unwrap();
+        assert_eq!(
+            policy.verdict_for_url("https://user@evil.example:8080/x"),
+            DomainVerdict::Block
+        );
+        assert_eq!(
+            policy.verdict_for_url("a.tracker.example/path"),
+            DomainVerdict::Block
+        );
+        assert_eq!(
+            policy.verdict_for_url("https://good.example/"),
+            DomainVerdict::Allow
+        );
+
+        let url = Entity::new(Type::URL, "http://evil.example", 0, 19);
+        assert_eq!(policy.verdict(&url), DomainVerdict::Block);
+        let hashtag = Entity::new(Type::HASHTAG, "evil.example", 0, 13);
+        assert_eq!(policy.verdict(&hashtag), DomainVerdict::Allow);
+    }
+}
@@ -2,6 +2,12 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+use crate::nom_parser::url::host_span;
+use crate::public_suffix::PublicSuffixList;
+use crate::tlds::{tld_kind, TldKind};
+use crate::url_parts::UrlParts;
+use std::ops::Range;
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum Type {
     URL,
@@ -20,6 +26,10 @@ pub struct Entity<'a> {
     pub list_slug: &'a str,
     pub display_url: &'a str,
     pub expanded_url: &'a str,
+    /// For URL entities, the byte span of the host in `value` as found by the
+    /// parser that extracted it. `None` for other entity types and for
+    /// entities that weren't extracted by this crate.
+    pub host_span: Option<Range<usize>>,
 }
 
 impl<'a> Entity<'a> {
@@ -45,10 +55,63 @@ impl<'a> Entity<'a> {
         self.expanded_url
     }
 
+    /// The host of a URL entity, as a slice of its value. Returns `None` for
+    /// other entity types.
+    ///
+    /// The host is the one the extractor found, so it agrees with the
+    /// extractor's [TldSet](crate::tld_set::TldSet). URL entities built by
+    /// hand have no [Entity::host_span], so their value is parsed against the
+    /// built-in TLDs instead.
+    pub fn host(&self) -> Option<&'a str> {
+        if self.t != Type::URL {
+            return None;
+        }
+        match &self.host_span {
+            Some(span) => self.value.get(span.clone()),
+            None => host_span(self.value).map(|(start, end)| &self.value[start..end]),
+        }
+    }
+
+    /// The components of a URL entity. Returns `None` for other entity types.
+    /// Like [Entity::host], this uses the host the extractor found.
+    pub fn url_parts(&self) -> Option<UrlParts<'a>> {
+        if self.t != Type::URL {
+            return None;
+        }
+        match &self.host_span {
+            Some(span) => UrlParts::with_host(self.value, span.clone()),
+            None => UrlParts::parse(self.value),
+        }
+    }
+
+    /// The registrable domain (eTLD+1) of a URL entity's host, e.g. `bbc.co.uk`
+    /// for `https://news.bbc.co.uk/sport`. Returns `None` for other entity types,
+    /// IP hosts, and hosts that are themselves a public suffix.
+    pub fn registrable_domain(&self, psl: &PublicSuffixList) -> Option<&'a str> {
+        psl.registrable_domain(self.host()?)
+    }
+
+    /// The category of a URL entity's TLD in the built-in TLD table. Returns
+    /// `None` for other entity types, IP hosts, and TLDs only known to a
+    /// runtime-loaded [crate::tld_set::TldSet] (see [crate::tld_set::TldSet::kind]).
+    pub fn tld_kind(&self) -> Option<TldKind> {
+        let host = self.host()?;
+        let host = host.strip_suffix('.').unwrap_or(host);
+        tld_kind(host.rsplit('.').next()?)
+    }
+
     pub fn new(t: Type, value: &'a str, start: i32, end: i32) -> Entity<'a> {
         Entity::new_list(t, value, "", start, end)
     }
 
+    /// Create a URL entity whose host is at `host_span` in `value`.
+    pub fn new_url(value: &'a str, host_span: Range<usize>, start: i32, end: i32) -> Entity<'a> {
+        Entity {
+            host_span: Some(host_span),
+            ..Entity::new(Type::URL, value, start, end)
+        }
+    }
+
     pub fn new_list(
         t: Type,
         value: &'a str,
@@ -64,6 +127,41 @@ impl<'a> Entity<'a> {
             end,
             display_url: "",
             expanded_url: "",
+            host_span: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registrable_domain() {
+        let psl = PublicSuffixList::parse("uk\nco.uk\ncom\n");
+        let url = Entity::new(Type::URL, "https://user@news.bbc.co.uk:443/sport", 0, 37);
+        assert_eq!(url.host(), Some("news.bbc.co.uk"));
+        assert_eq!(url.registrable_domain(&psl), Some("bbc.co.uk"));
+
+        let url = Entity::new(Type::URL, "www.example.com/path", 0, 20);
+        assert_eq!(url.registrable_domain(&psl), Some("example.com"));
+
+        let url = Entity::new(Type::URL, "http://192.168.0.1/", 0, 19);
+        assert_eq!(url.registrable_domain(&psl), None);
+
+        let hashtag = Entity::new(Type::HASHTAG, "example.com", 0, 12);
+        assert_eq!(hashtag.registrable_domain(&psl), None);
+    }
+
+    #[test]
+    fn test_tld_kind() {
+        let url = Entity::new(Type::URL, "https://example.co.uk/path", 0, 26);
+        assert_eq!(url.tld_kind(), Some(TldKind::Country));
+        let url = Entity::new(Type::URL, "example.COM", 0, 11);
+        assert_eq!(url.tld_kind(), Some(TldKind::Generic));
+        let url = Entity::new(Type::URL, "http://www.example.edu", 0, 22);
+        assert_eq!(url.tld_kind(), Some(TldKind::Sponsored));
+        let url = Entity::new(Type::URL, "http://127.0.0.1/", 0, 17);
+        assert_eq!(url.tld_kind(), None);
+    }
+}
@@ -2,16 +2,19 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+use crate::domain_policy::{DomainPolicy, DomainVerdict};
 use crate::entity::{Entity, Type};
+use crate::grapheme::GraphemeBreaker;
 use crate::nom_parser::{self, NomEntity, NomEntityType};
-use crate::tlds::is_valid_tld_case_insensitive;
+use crate::tld_set::TldSet;
+use crate::tlds::TldKind;
 use crate::TwitterTextParseResults;
 use idna::uts46::{AsciiDenyList, DnsLength, Hyphens, Uts46};
 use pest::Parser;
 use std::iter::Peekable;
 use std::str::CharIndices;
-use twitter_text_config::Configuration;
 use twitter_text_config::Range;
+use twitter_text_config::{CompiledConfiguration, Configuration, LengthMode};
 use twitter_text_parser::twitter_text::Rule;
 use twitter_text_parser::twitter_text::TwitterTextParser;
 // Full Pest parser for ParserBackend::Pest mode
@@ -20,7 +23,7 @@ use twitter_text_parser::twitter_text::full_pest::TwitterTextFullPestParser;
 use unicode_normalization::{is_nfc, UnicodeNormalization};
 
 /// Checks if an emoji string is valid using the emojis crate.
-fn is_valid_emoji(s: &str) -> bool {
+pub(crate) fn is_valid_emoji(s: &str) -> bool {
     emojis::get(s).is_some()
 }
 
@@ -65,6 +68,52 @@ pub enum ParserBackend {
     Nom,
 }
 
+/// Which categories of TLD an extractor accepts in URLs without a protocol,
+/// such as "example.co". URLs with a protocol are not affected.
+///
+/// For example, bare ccTLD domains can be limited to locales where they are
+/// common by turning off `country` elsewhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UrlWithoutProtocolPolicy {
+    pub generic: bool,
+    pub country: bool,
+    pub sponsored: bool,
+}
+
+impl Default for UrlWithoutProtocolPolicy {
+    fn default() -> Self {
+        UrlWithoutProtocolPolicy {
+            generic: true,
+            country: true,
+            sponsored: true,
+        }
+    }
+}
+
+impl UrlWithoutProtocolPolicy {
+    /// Returns true if URLs without a protocol may end in a TLD of this kind.
+    pub fn allows(&self, kind: TldKind) -> bool {
+        match kind {
+            TldKind::Generic => self.generic,
+            TldKind::Country => self.country,
+            TldKind::Sponsored => self.sponsored,
+        }
+    }
+
+    fn allows_all(&self) -> bool {
+        self.generic && self.country && self.sponsored
+    }
+
+    // Check the TLD of a validated domain against this policy.
+    fn allows_domain(&self, domain: &str, tlds: &TldSet) -> bool {
+        if self.allows_all() {
+            return true;
+        }
+        let tld = domain.rsplit('.').next().unwrap_or(domain);
+        tlds.kind(tld).is_some_and(|kind| self.allows(kind))
+    }
+}
+
 /**
  * A common Trait implemented by the two Extractors, [Extractor] and [ValidatingExtractor].
  */
@@ -84,6 +133,51 @@ pub trait Extract<'a> {
     /// Get the TLD matching strategy used by this extractor.
     fn get_parser_backend(&self) -> ParserBackend;
 
+    /// Get the set of TLDs this extractor accepts in URLs. Defaults to the
+    /// built-in set.
+    fn get_tld_set(&self) -> &TldSet {
+        const BUILTIN: &TldSet = &TldSet::builtin();
+        BUILTIN
+    }
+
+    /// Get which TLD categories are accepted in URLs without a protocol.
+    /// Defaults to all of them.
+    fn get_url_without_protocol_policy(&self) -> UrlWithoutProtocolPolicy {
+        UrlWithoutProtocolPolicy::default()
+    }
+
+    /// Get the policy that allows, flags or blocks URLs by domain. Defaults to
+    /// no policy.
+    fn get_domain_policy(&self) -> Option<&DomainPolicy> {
+        None
+    }
+
+    /// The domain policy's verdict for an entity. Everything is allowed when
+    /// there is no policy.
+    fn domain_verdict(&self, entity: &Entity) -> DomainVerdict {
+        self.get_domain_policy()
+            .map_or(DomainVerdict::Allow, |policy| policy.verdict(entity))
+    }
+
+    /// Returns true if the domain policy removes this URL from results.
+    fn drops_url(&self, url: &str) -> bool {
+        self.get_domain_policy()
+            .is_some_and(|policy| policy.drops_url(url))
+    }
+
+    /// Returns true if the domain policy removes URLs with this host from
+    /// results.
+    fn drops_host(&self, host: &str) -> bool {
+        self.get_domain_policy()
+            .is_some_and(|policy| policy.drops_host(host))
+    }
+
+    /// Returns true if federated mentions affect the weighted length, so they
+    /// are scanned even when they aren't extracted.
+    fn weighs_federated_mentions(&self) -> bool {
+        false
+    }
+
     /// Extract entities from the source text that match rules allowed by r_match.
     fn extract(&self, s: &'a str, r_match: RuleMatch) -> Self::T;
 
@@ -139,11 +233,15 @@ pub trait Extract<'a> {
                     } else if r_match(r) {
                         if r == Rule::url || r == Rule::url_without_protocol {
                             let span = pair.as_span();
+                            let host = url_host_span(&pair);
                             let requires_exact_tld = r == Rule::url_without_protocol;
-                            if let Some(trim_bytes) =
-                                validate_url(pair, requires_exact_tld, ParserBackend::External)
-                            {
-                                entity_count += 1;
+                            if let Some(trim_bytes) = validate_url(
+                                pair,
+                                requires_exact_tld,
+                                ParserBackend::External,
+                                self.get_tld_set(),
+                                self.get_url_without_protocol_policy(),
+                            ) {
                                 // If TLD was shorter than parsed, create trimmed span
                                 let final_span = if trim_bytes > 0 {
                                     pest::Span::new(
@@ -155,12 +253,23 @@ pub trait Extract<'a> {
                                 } else {
                                     span
                                 };
-                                scanned.push(UnprocessedEntity::UrlSpan(final_span));
+                                let host = host.map(|h| h.start..h.end.min(final_span.end()));
+                                let dropped = match &host {
+                                    Some(host) => self.drops_host(&s[host.clone()]),
+                                    None => self.drops_url(final_span.as_str()),
+                                };
+                                if !dropped {
+                                    entity_count += 1;
+                                    scanned.push(UnprocessedEntity::UrlSpan(final_span, host));
+                                }
                             }
                         } else {
                             entity_count += 1;
                             scanned.push(UnprocessedEntity::Pair(pair));
                         }
+                    } else if r == Rule::federated_mention && self.weighs_federated_mentions() {
+                        let span = pair.as_span();
+                        scanned.push(UnprocessedEntity::WeightOnly(r, span.start(), span.end()));
                     }
                 });
                 // Reverse so we can pop from the end in document order
@@ -193,13 +302,38 @@ pub trait Extract<'a> {
                             // With full Pest grammar, Pest already validated the TLD
                             // We only need to do punycode validation
                             if validate_url_full_pest(&pair) {
-                                entity_count += 1;
-                                scanned.push(UnprocessedEntity::UrlSpan(span));
+                                let host = pair
+                                    .clone()
+                                    .into_inner()
+                                    .find(|pair| {
+                                        let r = pair.as_rule();
+                                        r == FullPestRule::host
+                                            || r == FullPestRule::tco_domain
+                                            || r == FullPestRule::uwp_domain
+                                    })
+                                    .map(|host| host.as_span().start()..host.as_span().end());
+                                let dropped = match &host {
+                                    Some(host) => self.drops_host(&s[host.clone()]),
+                                    None => self.drops_url(span.as_str()),
+                                };
+                                if !dropped {
+                                    entity_count += 1;
+                                    scanned.push(UnprocessedEntity::UrlSpan(span, host));
+                                }
                             }
                         } else {
                             entity_count += 1;
                             scanned.push(UnprocessedEntity::FullPestPair(pair));
                         }
+                    } else if r == FullPestRule::federated_mention
+                        && self.weighs_federated_mentions()
+                    {
+                        let span = pair.as_span();
+                        scanned.push(UnprocessedEntity::WeightOnly(
+                            Rule::federated_mention,
+                            span.start(),
+                            span.end(),
+                        ));
                     }
                 });
                 // Reverse so we can pop from the end in document order
@@ -213,7 +347,9 @@ pub trait Extract<'a> {
     /// Implementation using nom parser with external TLD/emoji validation.
     /// Uses nom combinators compiled to native code for maximum performance.
     fn extract_impl_nom(&self, s: &'a str, r_match: RuleMatch) -> Self::T {
-        let nom_entities = nom_parser::parse_tweet(s);
+        let tlds = self.get_tld_set();
+        let policy = self.get_url_without_protocol_policy();
+        let nom_entities = nom_parser::parse_tweet_with_tlds(s, tlds);
 
         // Pre-filter and count entities we'll keep
         let mut scanned: Vec<UnprocessedEntity<'a>> = Vec::with_capacity(nom_entities.len());
@@ -233,11 +369,12 @@ pub trait Extract<'a> {
                 if rule == Rule::url || rule == Rule::url_without_protocol {
                     // Validate URL and potentially trim to valid TLD boundary
                     let requires_exact_tld = rule == Rule::url_without_protocol;
-                    if let Some(trim_bytes) = validate_url_nom(&entity, requires_exact_tld) {
-                        entity_count += 1;
-                        if trim_bytes > 0 {
+                    if let Some(trim_bytes) =
+                        validate_url_nom(&entity, requires_exact_tld, tlds, policy)
+                    {
+                        let entity = if trim_bytes > 0 {
                             // Create a trimmed entity
-                            let trimmed_entity = NomEntity::new_url(
+                            NomEntity::new_url(
                                 entity.entity_type,
                                 &entity.value[..entity.value.len() - trim_bytes],
                                 entity.start,
@@ -247,9 +384,16 @@ pub trait Extract<'a> {
                                     .host_end
                                     .unwrap_or(entity.end)
                                     .min(entity.end - trim_bytes),
-                            );
-                            scanned.push(UnprocessedEntity::NomEntity(trimmed_entity));
+                            )
                         } else {
+                            entity
+                        };
+                        let dropped = match (entity.host_start, entity.host_end) {
+                            (Some(start), Some(end)) => self.drops_host(&s[start..end]),
+                            _ => self.drops_url(entity.value),
+                        };
+                        if !dropped {
+                            entity_count += 1;
                             scanned.push(UnprocessedEntity::NomEntity(entity));
                         }
                     }
@@ -257,6 +401,12 @@ pub trait Extract<'a> {
                     entity_count += 1;
                     scanned.push(UnprocessedEntity::NomEntity(entity));
                 }
+            } else if rule == Rule::federated_mention && self.weighs_federated_mentions() {
+                scanned.push(UnprocessedEntity::WeightOnly(
+                    rule,
+                    entity.start,
+                    entity.end,
+                ));
             }
         }
 
@@ -391,9 +541,15 @@ pub trait Extract<'a> {
         end: i32,
     ) -> Option<Entity<'a>> {
         match ue {
-            UnprocessedEntity::UrlSpan(url) => {
-                Some(Entity::new(Type::URL, url.as_str(), start, end))
-            }
+            UnprocessedEntity::UrlSpan(url, host) => Some(match host {
+                Some(host) => Entity::new_url(
+                    url.as_str(),
+                    host.start - url.start()..host.end - url.start(),
+                    start,
+                    end,
+                ),
+                None => Entity::new(Type::URL, url.as_str(), start, end),
+            }),
             UnprocessedEntity::Pair(pair) => {
                 let s = pair.as_str();
                 match pair.as_rule() {
@@ -488,7 +644,15 @@ pub trait Extract<'a> {
                 let s = entity.value;
                 match entity.entity_type {
                     NomEntityType::Url | NomEntityType::UrlWithoutProtocol => {
-                        Some(Entity::new(Type::URL, s, start, end))
+                        Some(match (entity.host_start, entity.host_end) {
+                            (Some(host_start), Some(host_end)) => Entity::new_url(
+                                s,
+                                host_start - entity.start..host_end - entity.start,
+                                start,
+                                end,
+                            ),
+                            _ => Entity::new(Type::URL, s, start, end),
+                        })
                     }
                     NomEntityType::Hashtag => Some(Entity::new(
                         Type::HASHTAG,
@@ -533,6 +697,7 @@ pub trait Extract<'a> {
                     NomEntityType::Emoji | NomEntityType::InvalidChar => None,
                 }
             }
+            UnprocessedEntity::WeightOnly(..) => None,
         }
     }
 }
@@ -543,6 +708,9 @@ pub trait Extract<'a> {
 pub struct Extractor {
     extract_url_without_protocol: bool,
     parser_backend: ParserBackend,
+    tld_set: TldSet,
+    url_without_protocol_policy: UrlWithoutProtocolPolicy,
+    domain_policy: Option<DomainPolicy>,
 }
 
 impl Default for Extractor {
@@ -557,6 +725,9 @@ impl Extractor {
         Extractor {
             extract_url_without_protocol: true,
             parser_backend: ParserBackend::default(),
+            tld_set: TldSet::default(),
+            url_without_protocol_policy: UrlWithoutProtocolPolicy::default(),
+            domain_policy: None,
         }
     }
 
@@ -565,9 +736,38 @@ impl Extractor {
         Extractor {
             extract_url_without_protocol: true,
             parser_backend,
+            tld_set: TldSet::default(),
+            url_without_protocol_policy: UrlWithoutProtocolPolicy::default(),
+            domain_policy: None,
         }
     }
 
+    /// Set the set of TLDs this extractor accepts in URLs, such as one loaded
+    /// at runtime with [TldSet::from_iana_path].
+    pub fn set_tld_set(&mut self, tld_set: TldSet) {
+        self.tld_set = tld_set;
+    }
+
+    /// Set which TLD categories are accepted in URLs without a protocol.
+    pub fn set_url_without_protocol_policy(&mut self, policy: UrlWithoutProtocolPolicy) {
+        self.url_without_protocol_policy = policy;
+    }
+
+    /// Set the policy that allows, flags or blocks URLs by domain. Blocked URLs
+    /// are left out of the results if [DomainPolicy::drop_blocked] is set.
+    pub fn set_domain_policy(&mut self, policy: Option<DomainPolicy>) {
+        self.domain_policy = policy;
+    }
+
+    /// Extract every mention at the start of a reply, like "@a @b @c" in
+    /// "@a @b @c text". Each mention is followed by whitespace; as in the
+    /// "reply" rule, a mention followed by a `user_invalid_suffix` ends the
+    /// chain, and so does a list. The body starts after the whitespace that
+    /// follows the last mention.
+    pub fn extract_reply_usernames<'a>(&self, s: &'a str) -> ReplyMentions<'a> {
+        reply_mentions(self, s)
+    }
+
     /// Extract a vector of URLs as [String] objects.
     pub fn extract_urls(&self, s: &str) -> Vec<String> {
         // Use optimized path for Nom backend - skip Entity creation entirely
@@ -583,7 +783,8 @@ impl Extractor {
                 return Vec::new();
             }
 
-            let nom_entities = nom_parser::parse_urls_only(s, include_without_protocol);
+            let nom_entities =
+                nom_parser::parse_urls_only_with_tlds(s, include_without_protocol, &self.tld_set);
             let requires_exact_tld = true; // For protocol-less URLs
 
             return nom_entities
@@ -591,15 +792,23 @@ impl Extractor {
                 .filter_map(|entity| {
                     let is_protocol_less =
                         entity.entity_type == nom_parser::NomEntityType::UrlWithoutProtocol;
-                    if let Some(trim_bytes) =
-                        validate_url_nom(&entity, is_protocol_less && requires_exact_tld)
-                    {
-                        if trim_bytes > 0 {
-                            Some(String::from(
-                                &entity.value[..entity.value.len() - trim_bytes],
-                            ))
+                    if let Some(trim_bytes) = validate_url_nom(
+                        &entity,
+                        is_protocol_less && requires_exact_tld,
+                        &self.tld_set,
+                        self.url_without_protocol_policy,
+                    ) {
+                        let url = &entity.value[..entity.value.len() - trim_bytes];
+                        let dropped = match (entity.host_start, entity.host_end) {
+                            (Some(start), Some(end)) => {
+                                self.drops_host(&s[start..end.min(entity.end - trim_bytes)])
+                            }
+                            _ => self.drops_url(url),
+                        };
+                        if dropped {
+                            None
                         } else {
-                            Some(String::from(entity.value))
+                            Some(String::from(url))
                         }
                     } else {
                         None
@@ -735,6 +944,18 @@ impl<'a> Extract<'a> for Extractor {
         self.parser_backend
     }
 
+    fn get_tld_set(&self) -> &TldSet {
+        &self.tld_set
+    }
+
+    fn get_url_without_protocol_policy(&self) -> UrlWithoutProtocolPolicy {
+        self.url_without_protocol_policy
+    }
+
+    fn get_domain_policy(&self) -> Option<&DomainPolicy> {
+        self.domain_policy.as_ref()
+    }
+
     fn extract(&self, s: &'a str, r_match: RuleMatch) -> Vec<Entity<'a>> {
         self.extract_impl(s, r_match)
     }
@@ -853,7 +1074,11 @@ impl<'a> Extract<'a> for Extractor {
 pub struct ValidatingExtractor<'a> {
     extract_url_without_protocol: bool,
     parser_backend: ParserBackend,
+    tld_set: TldSet,
+    url_without_protocol_policy: UrlWithoutProtocolPolicy,
+    domain_policy: Option<DomainPolicy>,
     config: &'a Configuration,
+    compiled: Option<&'a CompiledConfiguration>,
     ld: LengthData,
 }
 
@@ -864,7 +1089,11 @@ impl<'a> ValidatingExtractor<'a> {
         ValidatingExtractor {
             extract_url_without_protocol: true,
             parser_backend: ParserBackend::default(),
+            tld_set: TldSet::default(),
+            url_without_protocol_policy: UrlWithoutProtocolPolicy::default(),
+            domain_policy: None,
             config: configuration,
+            compiled: None,
             ld: LengthData::empty(),
         }
     }
@@ -878,11 +1107,61 @@ impl<'a> ValidatingExtractor<'a> {
         ValidatingExtractor {
             extract_url_without_protocol: true,
             parser_backend,
+            tld_set: TldSet::default(),
+            url_without_protocol_policy: UrlWithoutProtocolPolicy::default(),
+            domain_policy: None,
             config: configuration,
+            compiled: None,
             ld: LengthData::empty(),
         }
     }
 
+    /// Create a new Extractor that weighs text with a precompiled table.
+    /// [ValidatingExtractor::prep_input] must be called prior to extract.
+    pub fn with_compiled_configuration(
+        compiled: &'a CompiledConfiguration,
+    ) -> ValidatingExtractor<'a> {
+        let mut extractor = ValidatingExtractor::new(compiled.configuration());
+        extractor.set_compiled_configuration(compiled);
+        extractor
+    }
+
+    /// Weigh text with a precompiled table, replacing the configuration this
+    /// extractor was created with.
+    pub fn set_compiled_configuration(&mut self, compiled: &'a CompiledConfiguration) {
+        self.config = compiled.configuration();
+        self.compiled = Some(compiled);
+    }
+
+    /// Set the set of TLDs this extractor accepts in URLs, such as one loaded
+    /// at runtime with [TldSet::from_iana_path].
+    pub fn set_tld_set(&mut self, tld_set: TldSet) {
+        self.tld_set = tld_set;
+    }
+
+    /// Set which TLD categories are accepted in URLs without a protocol.
+    pub fn set_url_without_protocol_policy(&mut self, policy: UrlWithoutProtocolPolicy) {
+        self.url_without_protocol_policy = policy;
+    }
+
+    /// Set the policy that allows, flags or blocks URLs by domain. Blocked URLs
+    /// are left out of the results if [DomainPolicy::drop_blocked] is set.
+    pub fn set_domain_policy(&mut self, policy: Option<DomainPolicy>) {
+        self.domain_policy = policy;
+    }
+
+    /// Extract the mentions that start a reply, as
+    /// [Extractor::extract_reply_usernames] does, with validation data for the
+    /// whole text.
+    pub fn extract_reply_usernames(&self, s: &'a str) -> ReplyMentionsResult<'a> {
+        let replies = reply_mentions(self, s);
+        ReplyMentionsResult {
+            parse_results: self.extract_urls_with_indices(s).parse_results,
+            mentions: replies.mentions,
+            body_start: replies.body_start,
+        }
+    }
+
     /// Initialize the [ValidatingExtractor] text length data.
     pub fn prep_input(&mut self, s: &str) -> String {
         // Avoid allocation if already NFC-normalized
@@ -912,7 +1191,11 @@ impl<'a> ValidatingExtractor<'a> {
         ValidatingExtractor {
             extract_url_without_protocol: true,
             parser_backend: ParserBackend::default(),
+            tld_set: TldSet::default(),
+            url_without_protocol_policy: UrlWithoutProtocolPolicy::default(),
+            domain_policy: None,
             config: configuration,
+            compiled: None,
             ld: LengthData {
                 normalized_length: length,
                 normalized_length_utf8: length_utf8,
@@ -932,7 +1215,11 @@ impl<'a> ValidatingExtractor<'a> {
         ValidatingExtractor {
             extract_url_without_protocol: true,
             parser_backend,
+            tld_set: TldSet::default(),
+            url_without_protocol_policy: UrlWithoutProtocolPolicy::default(),
+            domain_policy: None,
             config: configuration,
+            compiled: None,
             ld: LengthData {
                 normalized_length: length,
                 normalized_length_utf8: length_utf8,
@@ -963,6 +1250,41 @@ fn calculate_length(text: &str) -> (i32, i32) {
     (length, length_utf8)
 }
 
+// Parse the reply chain at the start of the text and convert its usernames
+// to entities with UTF-16 indices.
+fn reply_mentions<'a, E: Extract<'a>>(extractor: &E, s: &'a str) -> ReplyMentions<'a> {
+    let pairs = match TwitterTextParser::parse(Rule::reply_chain, s) {
+        Ok(pairs) => pairs.filter(|p| p.as_rule() == Rule::username),
+        Err(_) => {
+            return ReplyMentions {
+                mentions: Vec::new(),
+                body_start: 0,
+            }
+        }
+    };
+    let mut mentions = Vec::new();
+    let mut last_end = 0;
+    let mut index = 0;
+    for pair in pairs {
+        let span = pair.as_span();
+        let start = index + calculate_length(&s[last_end..span.start()]).0;
+        let end = start + calculate_length(span.as_str()).0;
+        last_end = span.end();
+        index = end;
+        if let Some(e) = extractor.entity_from_pair(UnprocessedEntity::Pair(pair), start, end) {
+            mentions.push(e);
+        }
+    }
+    if last_end > 0 {
+        let rest = &s[last_end..];
+        index += calculate_length(&rest[..rest.len() - rest.trim_start().len()]).0;
+    }
+    ReplyMentions {
+        mentions,
+        body_start: index,
+    }
+}
+
 impl<'a> Extract<'a> for ValidatingExtractor<'a> {
     type T = ExtractResult<'a>;
     type Mention = MentionResult<'a>;
@@ -979,6 +1301,22 @@ impl<'a> Extract<'a> for ValidatingExtractor<'a> {
         self.parser_backend
     }
 
+    fn get_tld_set(&self) -> &TldSet {
+        &self.tld_set
+    }
+
+    fn get_url_without_protocol_policy(&self) -> UrlWithoutProtocolPolicy {
+        self.url_without_protocol_policy
+    }
+
+    fn get_domain_policy(&self) -> Option<&DomainPolicy> {
+        self.domain_policy.as_ref()
+    }
+
+    fn weighs_federated_mentions(&self) -> bool {
+        self.config.federated_mention_local_part_only()
+    }
+
     fn extract(&self, s: &'a str, r_match: RuleMatch) -> Self::T {
         self.extract_impl(s, r_match)
     }
@@ -1000,7 +1338,7 @@ impl<'a> Extract<'a> for ValidatingExtractor<'a> {
         scanned: &mut Vec<UnprocessedEntity<'a>>,
     ) -> ExtractResult<'a> {
         let mut iter = s.char_indices().peekable();
-        let mut metrics = TextMetrics::new(self.config, self.ld.normalized_length);
+        let mut metrics = TextMetrics::new(self.config, self.compiled, self.ld.normalized_length);
         let mut entities = Vec::with_capacity(count);
         let mut start_index = 0;
         while let Some(entity) = scanned.pop() {
@@ -1008,16 +1346,28 @@ impl<'a> Extract<'a> for ValidatingExtractor<'a> {
             let r = entity.as_rule();
             if r == Rule::invalid_char {
                 metrics.is_valid = false;
-            } else if r == Rule::emoji && self.config.emoji_parsing_enabled {
-                metrics.weighted_count += self.config.default_weight;
+            } else if r == Rule::emoji
+                && self.config.emoji_parsing_enabled
+                && self.config.length_mode == LengthMode::Weighted
+            {
+                metrics.weighted_count += self.config.emoji_weight();
                 start_index += metrics.scan(iter.by_ref(), entity.end(), TrackAction::Emoji);
             } else {
-                let action = if r == Rule::url {
-                    TrackAction::Url
+                let mut end_index = start_index;
+                if r == Rule::federated_mention && self.config.federated_mention_local_part_only() {
+                    // Only "@user" counts; "@domain" takes up space but no weight.
+                    let local_end =
+                        entity.start() + local_part_len(&s[entity.start()..entity.end()]);
+                    end_index += metrics.scan(iter.by_ref(), local_end, TrackAction::Text);
+                    end_index += metrics.scan(iter.by_ref(), entity.end(), TrackAction::Uncounted);
                 } else {
-                    TrackAction::Text
-                };
-                let end_index = start_index + metrics.scan(iter.by_ref(), entity.end(), action);
+                    let action = if r == Rule::url {
+                        TrackAction::Url(self.config.url_length(&s[entity.start()..entity.end()]))
+                    } else {
+                        TrackAction::Text
+                    };
+                    end_index += metrics.scan(iter.by_ref(), entity.end(), action);
+                }
                 if let Some(e) = self.entity_from_pair(entity, start_index, end_index) {
                     entities.push(e);
                 }
@@ -1094,6 +1444,26 @@ impl<'a> MentionResult<'a> {
     }
 }
 
+/// The mentions that start a reply, returned by [Extractor].
+#[derive(Debug)]
+pub struct ReplyMentions<'a> {
+    /// The leading mentions, in order.
+    pub mentions: Vec<Entity<'a>>,
+    /// Where the text after the mentions and the whitespace following them
+    /// starts, in UTF-16 code units. 0 if the text doesn't start with a mention.
+    pub body_start: i32,
+}
+
+/// The mentions that start a reply and validation data returned by
+/// [ValidatingExtractor].
+#[derive(Debug)]
+pub struct ReplyMentionsResult<'a> {
+    pub parse_results: TwitterTextParseResults,
+    pub mentions: Vec<Entity<'a>>,
+    /// Where the text after the mentions starts, as in [ReplyMentions::body_start].
+    pub body_start: i32,
+}
+
 // Tracks validation data during entity extraction.
 struct TextMetrics<'a> {
     is_valid: bool,
@@ -1102,13 +1472,20 @@ struct TextMetrics<'a> {
     valid_offset: i32,
     normalized_length: i32,
     scaled_max_weighted_tweet_length: i32,
-    /// Cached weight for fast path (code points 0-4351), or None if no fast path available
+    /// Cached weight for the fast path, or None if the first range doesn't start at 0
     fast_path_weight: Option<i32>,
     config: &'a Configuration,
+    compiled: Option<&'a CompiledConfiguration>,
+    /// Set in [LengthMode::Graphemes], where each cluster counts as one character
+    graphemes: Option<GraphemeBreaker>,
 }
 
 impl<'a> TextMetrics<'a> {
-    fn new(config: &Configuration, normalized_length: i32) -> TextMetrics<'_> {
+    fn new(
+        config: &'a Configuration,
+        compiled: Option<&'a CompiledConfiguration>,
+        normalized_length: i32,
+    ) -> TextMetrics<'a> {
         // Pre-compute fast path weight if first range starts at 0
         let fast_path_weight = config.ranges.first().and_then(|r| {
             if r.range.start() == 0 {
@@ -1126,6 +1503,11 @@ impl<'a> TextMetrics<'a> {
             scaled_max_weighted_tweet_length: config.max_weighted_tweet_length * config.scale,
             fast_path_weight,
             config,
+            compiled,
+            graphemes: match config.length_mode {
+                LengthMode::Weighted => None,
+                LengthMode::Graphemes => Some(GraphemeBreaker::new()),
+            },
         }
     }
 
@@ -1145,24 +1527,34 @@ impl<'a> TextMetrics<'a> {
         self.add_char(c);
     }
 
-    fn track_url(&mut self, count: i32) {
-        self.weighted_count += self.config.transformed_url_length * self.config.scale;
+    fn track_url(&mut self, count: i32, length: i32) {
+        self.weighted_count += length * self.config.scale;
         self.add_offset(count);
+        if let Some(breaker) = self.graphemes.as_mut() {
+            breaker.reset();
+        }
     }
 
     fn track_text(&mut self, c: char) {
+        if let Some(breaker) = self.graphemes.as_mut() {
+            if self.offset < self.normalized_length {
+                if breaker.is_boundary(c) {
+                    self.weighted_count += self.config.scale;
+                }
+                self.add_char(c);
+            }
+            return;
+        }
         if self.offset < self.normalized_length {
             let code_point: i32 = c as i32;
-            // Fast path: use cached weight for code points 0-4351 (ASCII, Latin-1, common scripts)
-            let char_weight = if let Some(weight) = self.fast_path_weight {
-                if code_point <= 4351 {
-                    weight
-                } else {
-                    self.weight_for_code_point(code_point)
-                }
-            } else {
+            let char_weight = match (self.compiled, self.fast_path_weight) {
+                (Some(compiled), _) => compiled.weight(c),
+                // Fast path: use cached weight for code points 0-4351 (ASCII, Latin-1, common
+                // scripts)
+                (None, Some(weight)) if code_point <= 4351 => weight,
+                (None, Some(_)) => self.weight_for_code_point(code_point),
                 // No fast path (v1 config or unusual ranges) - use default weight
-                self.config.default_weight
+                (None, None) => self.config.default_weight,
             };
             self.weighted_count += char_weight;
             self.add_char(c);
@@ -1204,7 +1596,7 @@ impl<'a> TextMetrics<'a> {
                     self.track_emoji(c);
                 }
             }
-            TrackAction::Url => {
+            TrackAction::Url(length) => {
                 while let Some(&(pos, c)) = iter.peek() {
                     if pos >= limit {
                         break;
@@ -1212,7 +1604,17 @@ impl<'a> TextMetrics<'a> {
                     iter.next();
                     offset += as_i32(c.len_utf16());
                 }
-                self.track_url(offset);
+                self.track_url(offset, length);
+            }
+            TrackAction::Uncounted => {
+                while let Some(&(pos, c)) = iter.peek() {
+                    if pos >= limit {
+                        break;
+                    }
+                    iter.next();
+                    offset += as_i32(c.len_utf16());
+                    self.add_char(c);
+                }
             }
         }
 
@@ -1223,43 +1625,52 @@ impl<'a> TextMetrics<'a> {
 enum TrackAction {
     Text,
     Emoji,
-    Url,
+    /// A URL, counting the given number of characters.
+    Url(i32),
+    /// Text that advances the offsets without adding weight.
+    Uncounted,
 }
 
 pub enum UnprocessedEntity<'a> {
-    UrlSpan(pest::Span<'a>),
+    /// A URL and the byte span of its host in the input.
+    UrlSpan(pest::Span<'a>, Option<std::ops::Range<usize>>),
     Pair(Pair<'a>),
     FullPestPair(FullPestPair<'a>),
     NomEntity(NomEntity<'a>),
+    /// A span that only affects the weighted length, with the rule that matched it.
+    WeightOnly(Rule, usize, usize),
 }
 
 impl<'a> UnprocessedEntity<'a> {
     fn start(&self) -> usize {
         match self {
-            UnprocessedEntity::UrlSpan(span) => span.start(),
+            UnprocessedEntity::UrlSpan(span, _) => span.start(),
             UnprocessedEntity::Pair(pair) => pair.as_span().start(),
             UnprocessedEntity::FullPestPair(pair) => pair.as_span().start(),
             UnprocessedEntity::NomEntity(entity) => entity.start,
+            UnprocessedEntity::WeightOnly(_, start, _) => *start,
         }
     }
 
     fn end(&self) -> usize {
         match self {
-            UnprocessedEntity::UrlSpan(span) => span.end(),
+            UnprocessedEntity::UrlSpan(span, _) => span.end(),
             UnprocessedEntity::Pair(pair) => pair.as_span().end(),
             UnprocessedEntity::FullPestPair(pair) => pair.as_span().end(),
             UnprocessedEntity::NomEntity(entity) => entity.end,
+            UnprocessedEntity::WeightOnly(_, _, end) => *end,
         }
     }
 
     fn as_rule(&self) -> Rule {
         match self {
-            UnprocessedEntity::UrlSpan(_span) => Rule::url,
+            UnprocessedEntity::UrlSpan(..) => Rule::url,
             UnprocessedEntity::Pair(pair) => pair.as_rule(),
             // Convert FullPestRule to Rule - they have the same variant names
             UnprocessedEntity::FullPestPair(pair) => full_pest_rule_to_rule(pair.as_rule()),
             // Convert NomEntityType to Rule
             UnprocessedEntity::NomEntity(entity) => nom_entity_type_to_rule(entity.entity_type),
+            UnprocessedEntity::WeightOnly(rule, _, _) => *rule,
         }
     }
 }
@@ -1342,6 +1753,14 @@ fn valid_punycode_full_pest(original: &str, domain: &FullPestPair) -> bool {
     }
 }
 
+// The length in bytes of the "@user" part of a federated mention.
+fn local_part_len(mention: &str) -> usize {
+    let prefix = mention.chars().next().map_or(0, char::len_utf8);
+    mention[prefix..]
+        .find('@')
+        .map_or(mention.len(), |at| prefix + at)
+}
+
 fn calculate_offset(s: &str) -> usize {
     s.chars().next().unwrap_or(' ').len_utf8()
 }
@@ -1353,7 +1772,24 @@ fn calculate_offset(s: &str) -> usize {
 /// The `parser_backend` parameter controls how TLDs are validated:
 /// - `ParserBackend::Pest`: Trust the Pest grammar's TLD matching (no external validation)
 /// - `ParserBackend::External`: Use phf lookup for O(1) TLD validation
-fn validate_url(p: Pair, requires_exact_tld: bool, parser_backend: ParserBackend) -> Option<usize> {
+// The byte span of a URL pair's host in the input.
+fn url_host_span(p: &Pair) -> Option<std::ops::Range<usize>> {
+    p.clone()
+        .into_inner()
+        .find(|pair| {
+            let r = pair.as_rule();
+            r == Rule::host || r == Rule::tco_domain || r == Rule::uwp_domain
+        })
+        .map(|host| host.as_span().start()..host.as_span().end())
+}
+
+fn validate_url(
+    p: Pair,
+    requires_exact_tld: bool,
+    parser_backend: ParserBackend,
+    tlds: &TldSet,
+    policy: UrlWithoutProtocolPolicy,
+) -> Option<usize> {
     let original_span = p.as_span();
     let original = p.as_str();
     match p.into_inner().find(|pair| {
@@ -1398,7 +1834,7 @@ fn validate_url(p: Pair, requires_exact_tld: bool, parser_backend: ParserBackend
                     // Find the last dot to get the TLD portion
                     if let Some(last_dot) = trimmed_domain.rfind('.') {
                         let tld = &trimmed_domain[last_dot + 1..];
-                        if is_valid_tld_case_insensitive(tld) {
+                        if tlds.contains(tld) && policy.allows_domain(trimmed_domain, tlds) {
                             // Calculate bytes from end of original URL span to end of valid domain
                             let domain_end_in_url = domain_span.end() - original_span.start();
                             let url_len = original.len();
@@ -1415,7 +1851,13 @@ fn validate_url(p: Pair, requires_exact_tld: bool, parser_backend: ParserBackend
             // Validate TLD by working through domain from right to left
             // This handles cases like "example.comだよね.comtest" where we need to find
             // the rightmost valid TLD boundary (should stop at "example.com")
-            match find_valid_tld_boundary(domain, requires_exact_tld) {
+            match find_valid_tld_boundary(domain, requires_exact_tld, tlds) {
+                Some(valid_domain_len)
+                    if requires_exact_tld
+                        && !policy.allows_domain(&domain[..valid_domain_len], tlds) =>
+                {
+                    None
+                }
                 Some(valid_domain_len) => {
                     let domain_trim = domain.len() - valid_domain_len;
 
@@ -1445,7 +1887,12 @@ fn validate_url(p: Pair, requires_exact_tld: bool, parser_backend: ParserBackend
 /// Validates a URL parsed by the nom parser.
 /// Returns Some(trim_bytes) if valid (0 means no trimming needed),
 /// or None if the URL is invalid.
-fn validate_url_nom(entity: &NomEntity, requires_exact_tld: bool) -> Option<usize> {
+fn validate_url_nom(
+    entity: &NomEntity,
+    requires_exact_tld: bool,
+    tlds: &TldSet,
+    policy: UrlWithoutProtocolPolicy,
+) -> Option<usize> {
     let original = entity.value;
 
     // Get the host/domain portion using the stored positions
@@ -1479,7 +1926,7 @@ fn validate_url_nom(entity: &NomEntity, requires_exact_tld: bool) -> Option<usiz
             let trimmed_domain = &domain[..valid_domain_end];
             if let Some(last_dot) = trimmed_domain.rfind('.') {
                 let tld = &trimmed_domain[last_dot + 1..];
-                if is_valid_tld_case_insensitive(tld) {
+                if tlds.contains(tld) && policy.allows_domain(trimmed_domain, tlds) {
                     let after_domain = original.len() - host_end;
                     let total_trim = domain_trim + after_domain;
                     return Some(total_trim);
@@ -1490,7 +1937,12 @@ fn validate_url_nom(entity: &NomEntity, requires_exact_tld: bool) -> Option<usiz
     }
 
     // Validate TLD by finding the valid boundary
-    match find_valid_tld_boundary(domain, requires_exact_tld) {
+    match find_valid_tld_boundary(domain, requires_exact_tld, tlds) {
+        Some(valid_domain_len)
+            if requires_exact_tld && !policy.allows_domain(&domain[..valid_domain_len], tlds) =>
+        {
+            None
+        }
         Some(valid_domain_len) => {
             let domain_trim = domain.len() - valid_domain_len;
 
@@ -1540,7 +1992,7 @@ fn valid_punycode_str(original: &str, domain: &str) -> bool {
 /// before the script boundary.
 ///
 /// Returns Some(byte_position) of the valid domain end, or None if no valid TLD found.
-fn find_valid_tld_boundary(domain: &str, requires_exact_tld: bool) -> Option<usize> {
+fn find_valid_tld_boundary(domain: &str, requires_exact_tld: bool, tlds: &TldSet) -> Option<usize> {
     // First check if domain has any script mixing (Latin followed by non-Latin in same label)
     let has_script_mixing_in_domain = domain.split('.').any(has_script_mixing);
 
@@ -1564,8 +2016,7 @@ fn find_valid_tld_boundary(domain: &str, requires_exact_tld: bool) -> Option<usi
                 let boundary = find_script_boundary(segment);
                 let effective_segment = &segment[..boundary];
 
-                if !effective_segment.is_empty() && is_valid_tld_case_insensitive(effective_segment)
-                {
+                if !effective_segment.is_empty() && tlds.contains(effective_segment) {
                     let end_pos = dot_pos + 1 + effective_segment.len();
                     #[cfg(test)]
                     eprintln!(
@@ -1585,7 +2036,7 @@ fn find_valid_tld_boundary(domain: &str, requires_exact_tld: bool) -> Option<usi
         let segment = &after_dot[..segment_end];
 
         // Check if this segment is a valid TLD (case-insensitive, no heap allocation)
-        if is_valid_tld_case_insensitive(segment) {
+        if tlds.contains(segment) {
             // Check if the segment before this TLD contains underscores
             // If so, this is not a valid URL (domain_segment can't have underscores)
             let before_dot = &domain[..dot_pos];
@@ -1614,7 +2065,7 @@ fn find_valid_tld_boundary(domain: &str, requires_exact_tld: bool) -> Option<usi
         // but "com-that-you..." is not.
         if let Some(hyphen_pos) = segment.find('-') {
             let before_hyphen = &segment[..hyphen_pos];
-            if is_valid_tld_case_insensitive(before_hyphen) {
+            if tlds.contains(before_hyphen) {
                 // Also check if the segment before this TLD contains underscores
                 let before_dot = &domain[..dot_pos];
                 let prev_segment = before_dot.rsplit('.').next().unwrap_or(before_dot);
@@ -1645,7 +2096,7 @@ fn find_valid_tld_boundary(domain: &str, requires_exact_tld: bool) -> Option<usi
             // We try progressively shorter prefixes
             for (char_idx, _) in segment.char_indices().skip(2) {
                 let prefix = &segment[..char_idx];
-                if is_valid_tld_case_insensitive(prefix) {
+                if tlds.contains(prefix) {
                     let end_pos = dot_pos + 1 + prefix.len();
                     #[cfg(test)]
                     eprintln!(
@@ -1862,6 +2313,48 @@ mod tests {
         assert_eq!("user", extracted.unwrap().value);
     }
 
+    #[test]
+    fn test_reply_usernames() {
+        let extractor = Extractor::new();
+        let replies = extractor.extract_reply_usernames(" @a  ＠b\n@c text @d");
+        let names: Vec<_> = replies.mentions.iter().map(|e| e.value).collect();
+        assert_eq!(vec!["a", "b", "c"], names);
+        assert_eq!((1, 3), (replies.mentions[0].start, replies.mentions[0].end));
+        assert_eq!((5, 7), (replies.mentions[1].start, replies.mentions[1].end));
+        assert_eq!(11, replies.body_start);
+
+        // The chain stops at a mention with an invalid suffix or without a
+        // space before it.
+        let replies = extractor.extract_reply_usernames("@a @b- text");
+        assert_eq!(1, replies.mentions.len());
+        assert_eq!(3, replies.body_start);
+        let replies = extractor.extract_reply_usernames("@a,@b text");
+        assert_eq!(1, replies.mentions.len());
+        assert_eq!(2, replies.body_start);
+
+        // Offsets are in UTF-16 code units.
+        let replies = extractor.extract_reply_usernames("@a \u{1F600} @b");
+        assert_eq!(1, replies.mentions.len());
+        assert_eq!(3, replies.body_start);
+
+        let replies = extractor.extract_reply_usernames("text @a");
+        assert!(replies.mentions.is_empty());
+        assert_eq!(0, replies.body_start);
+        let replies = extractor.extract_reply_usernames("  text");
+        assert_eq!(0, replies.body_start);
+        let replies = extractor.extract_reply_usernames("@a@b text");
+        assert!(replies.mentions.is_empty());
+
+        // A list ends the chain, and isn't part of it.
+        let replies = extractor.extract_reply_usernames("@a/list hi");
+        assert!(replies.mentions.is_empty());
+        assert_eq!(0, replies.body_start);
+        let replies = extractor.extract_reply_usernames("@a @b/list hi");
+        let names: Vec<_> = replies.mentions.iter().map(|e| e.value).collect();
+        assert_eq!(vec!["a"], names);
+        assert_eq!(3, replies.body_start);
+    }
+
     // Mention tests - ported from Java ExtractorTest.MentionTest
 
     #[test]
@@ -2398,6 +2891,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validating_extractor_reply_usernames() {
+        let config = Configuration::default();
+        let text = "@alice @bob hi @carol";
+        let extractor = ValidatingExtractor::new_with_nfc_input(&config, text);
+        let result = extractor.extract_reply_usernames(text);
+        assert!(result.parse_results.is_valid);
+        assert_eq!(21, result.parse_results.weighted_length);
+        let names: Vec<_> = result.mentions.iter().map(|e| e.value).collect();
+        assert_eq!(vec!["alice", "bob"], names);
+        assert_eq!(12, result.body_start);
+
+        let result = extractor.extract_reply_usernames("hi @carol");
+        assert!(result.mentions.is_empty());
+        assert_eq!(0, result.body_start);
+
+        // Both extractors find the same chain.
+        for text in [
+            "@a/list hi",
+            "@a @b/list hi",
+            " @a  ＠b\n@c text @d",
+            "@a @b- text",
+        ] {
+            let extractor = ValidatingExtractor::new_with_nfc_input(&config, text);
+            let result = extractor.extract_reply_usernames(text);
+            let replies = Extractor::new().extract_reply_usernames(text);
+            assert_eq!(replies.mentions, result.mentions, "{}", text);
+            assert_eq!(replies.body_start, result.body_start, "{}", text);
+        }
+    }
+
     #[test]
     fn test_validating_extractor_federated_mentions() {
         // Test that ValidatingExtractor can extract federated mentions
@@ -2750,7 +3274,7 @@ mod debug_tests {
             );
         }
 
-        let result = find_valid_tld_boundary(domain, false);
+        let result = find_valid_tld_boundary(domain, false, &TldSet::default());
         eprintln!("Result: {:?}", result);
     }
 
@@ -2985,10 +3509,15 @@ mod debug_tests {
                         let domain = &entity.value[host_in_value_start..host_in_value_end];
                         eprintln!("Domain extracted: '{}'", domain);
 
-                        let boundary = find_valid_tld_boundary(domain, false);
+                        let boundary = find_valid_tld_boundary(domain, false, &TldSet::default());
                         eprintln!("find_valid_tld_boundary result: {:?}", boundary);
 
-                        let trim_result = validate_url_nom(entity, false);
+                        let trim_result = validate_url_nom(
+                            entity,
+                            false,
+                            &TldSet::default(),
+                            UrlWithoutProtocolPolicy::default(),
+                        );
                         eprintln!("validate_url_nom result: {:?}", trim_result);
                     }
                 }
@@ -3157,4 +3686,214 @@ mod debug_tests {
             );
         }
     }
+
+    #[test]
+    fn test_runtime_tld_set() {
+        let tlds = TldSet::from_iana("# test list\nCOM\nSAYRER\n").unwrap();
+        for backend in [ParserBackend::Nom, ParserBackend::External] {
+            let mut extractor = Extractor::with_parser_backend(backend);
+            extractor.set_tld_set(tlds.clone());
+
+            let urls = extractor.extract_urls("http://example.sayrer example.sayrer example.co.jp");
+            assert_eq!(
+                urls,
+                vec!["http://example.sayrer", "example.sayrer"],
+                "{:?}",
+                backend
+            );
+
+            let entities = extractor.extract_urls_with_indices("see example.sayrer/path");
+            assert_eq!(entities.len(), 1, "{:?}", backend);
+            assert_eq!(entities[0].get_value(), "example.sayrer/path");
+
+            let config = twitter_text_config::config_v3();
+            let mut validating = ValidatingExtractor::with_parser_backend(config, backend);
+            validating.set_tld_set(tlds.clone());
+            let input = validating.prep_input("example.sayrer");
+            assert_eq!(
+                validating.extract_urls_with_indices(&input).entities.len(),
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn test_url_host_from_parser() {
+        let psl = crate::public_suffix::PublicSuffixList::parse("sayrer\ncom\n");
+        let tlds = TldSet::default().with_allowed(["sayrer"]).unwrap();
+        for backend in [ParserBackend::Nom, ParserBackend::External] {
+            let mut extractor = Extractor::with_parser_backend(backend);
+            extractor.set_tld_set(tlds.clone());
+            let entities = extractor
+                .extract_urls_with_indices("see https://a.example.sayrer:8/x b.example.sayrer");
+            let hosts: Vec<_> = entities.iter().map(|e| e.host()).collect();
+            assert_eq!(
+                hosts,
+                vec![Some("a.example.sayrer"), Some("b.example.sayrer")],
+                "{:?}",
+                backend
+            );
+            assert_eq!(entities[1].registrable_domain(&psl), Some("example.sayrer"));
+
+            let entities = Extractor::with_parser_backend(backend)
+                .extract_urls_with_indices("example.comだよね https://t.co/abc");
+            let hosts: Vec<_> = entities.iter().map(|e| e.host()).collect();
+            assert_eq!(
+                hosts,
+                vec![Some("example.com"), Some("t.co")],
+                "{:?}",
+                backend
+            );
+        }
+        let entities = Extractor::with_parser_backend(ParserBackend::Pest)
+            .extract_urls_with_indices("see https://user@example.com:8/x");
+        assert_eq!(entities[0].host(), Some("example.com"));
+        assert_eq!(entities[0].host_span, Some(13..24));
+    }
+
+    #[test]
+    fn test_tld_allowlist_and_denylist() {
+        let tlds = TldSet::default()
+            .with_allowed(["corp", "internal", "lan"])
+            .unwrap()
+            .with_denied(["zip", "mov"])
+            .unwrap();
+        for backend in [ParserBackend::Nom, ParserBackend::External] {
+            let mut extractor = Extractor::with_parser_backend(backend);
+            let text =
+                "see wiki.corp https://build.internal/job svc.lan:8080 notes.zip http://clip.mov";
+            assert_eq!(
+                extractor.extract_urls(text),
+                vec!["notes.zip", "http://clip.mov"],
+                "{:?}",
+                backend
+            );
+
+            extractor.set_tld_set(tlds.clone());
+            assert_eq!(
+                extractor.extract_urls(text),
+                vec!["wiki.corp", "https://build.internal/job", "svc.lan:8080"],
+                "{:?}",
+                backend
+            );
+            assert_eq!(extractor.extract_urls_with_indices(text).len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_url_without_protocol_policy() {
+        let text = "example.co example.com example.edu http://example.co";
+        let generic_only = UrlWithoutProtocolPolicy {
+            generic: true,
+            country: false,
+            sponsored: false,
+        };
+        for backend in [ParserBackend::Nom, ParserBackend::External] {
+            let mut extractor = Extractor::with_parser_backend(backend);
+            assert_eq!(extractor.extract_urls(text).len(), 4, "{:?}", backend);
+
+            extractor.set_url_without_protocol_policy(generic_only);
+            assert_eq!(
+                extractor.extract_urls(text),
+                vec!["example.com", "http://example.co"],
+                "{:?}",
+                backend
+            );
+            let entities = extractor.extract_urls_with_indices(text);
+            let kinds: Vec<_> = entities.iter().map(|e| e.tld_kind()).collect();
+            assert_eq!(
+                kinds,
+                vec![Some(TldKind::Generic), Some(TldKind::Country)],
+                "{:?}",
+                backend
+            );
+        }
+    }
+
+    #[test]
+    fn test_domain_policy() {
+        let text = "a https://evil.com/x b https://ok.com/y c evil.com";
+        let mut policy = DomainPolicy::blocklist(["evil.com"]).unwrap();
+        policy.add_rule("*.com", DomainVerdict::Flag).unwrap();
+        for backend in [
+            ParserBackend::Nom,
+            ParserBackend::External,
+            ParserBackend::Pest,
+        ] {
+            let mut extractor = Extractor::with_parser_backend(backend);
+            extractor.set_domain_policy(Some(policy.clone()));
+            assert_eq!(
+                extractor.extract_urls(text),
+                vec!["https://ok.com/y"],
+                "{:?}",
+                backend
+            );
+            let entities = extractor.extract_entities_with_indices(text);
+            assert_eq!(entities.len(), 1, "{:?}", backend);
+            assert_eq!(extractor.domain_verdict(&entities[0]), DomainVerdict::Flag);
+
+            let mut keep_blocked = policy.clone();
+            keep_blocked.drop_blocked = false;
+            extractor.set_domain_policy(Some(keep_blocked));
+            let entities = extractor.extract_urls_with_indices(text);
+            let verdicts: Vec<_> = entities
+                .iter()
+                .map(|e| extractor.domain_verdict(e))
+                .collect();
+            assert_eq!(
+                verdicts,
+                vec![
+                    DomainVerdict::Block,
+                    DomainVerdict::Flag,
+                    DomainVerdict::Block
+                ],
+                "{:?}",
+                backend
+            );
+        }
+    }
+
+    #[test]
+    fn test_domain_policy_runtime_tld() {
+        // The built-in TLDs reject "com-lab" as a hyphenated "com", so the
+        // policy has to use the host the extractor found. Only the nom
+        // backend accepts hyphenated TLDs.
+        let text = "a https://evil.com-lab/x b ok.com-lab c evil.com-lab";
+        let mut policy = DomainPolicy::blocklist(["evil.com-lab"]).unwrap();
+        let mut extractor = Extractor::with_parser_backend(ParserBackend::Nom);
+        extractor.set_tld_set(TldSet::from_tlds(["com-lab"]).unwrap());
+        extractor.set_domain_policy(Some(policy.clone()));
+        assert_eq!(extractor.extract_urls(text), vec!["ok.com-lab"]);
+        assert_eq!(extractor.extract_urls_with_indices(text).len(), 1);
+
+        policy.drop_blocked = false;
+        extractor.set_domain_policy(Some(policy));
+        let entities = extractor.extract_urls_with_indices(text);
+        let verdicts: Vec<_> = entities
+            .iter()
+            .map(|e| extractor.domain_verdict(e))
+            .collect();
+        assert_eq!(
+            verdicts,
+            vec![
+                DomainVerdict::Block,
+                DomainVerdict::Allow,
+                DomainVerdict::Block
+            ]
+        );
+    }
+
+    #[test]
+    fn test_domain_policy_weighting() {
+        let config = twitter_text_config::config_v3();
+        let text = "see https://evil.com/x";
+        let mut extractor = ValidatingExtractor::new_with_nfc_input(config, text);
+        let result = extractor.extract_urls_with_indices(text);
+        assert_eq!(result.parse_results.weighted_length, 4 + 23);
+
+        extractor.set_domain_policy(Some(DomainPolicy::blocklist(["evil.com"]).unwrap()));
+        let result = extractor.extract_urls_with_indices(text);
+        assert!(result.entities.is_empty());
+        assert_eq!(result.parse_results.weighted_length, text.len() as i32);
+    }
 }
@@ -8,11 +8,9 @@ use crate::validator;
 use crate::validator::Validator;
 use crate::{parse, TwitterTextParseResults};
 use cxx::{CxxVector, UniquePtr};
-use twitter_text_config::Configuration;
 use twitter_text_config::Range;
 use twitter_text_config::WeightedRange;
-
-use std::path::PathBuf;
+use twitter_text_config::{ConfigError, Configuration, EntityWeights, LengthMode};
 
 #[cxx::bridge(namespace = twitter_text)]
 pub mod ffi {
@@ -28,6 +26,24 @@ pub mod ffi {
         pub weight: i32,
     }
 
+    pub enum LengthMode {
+        Weighted,
+        Graphemes,
+    }
+
+    pub struct SchemeLength {
+        pub scheme: String,
+        pub length: i32,
+    }
+
+    // [EntityWeights], with `has_emoji_weight` standing in for the Option.
+    pub struct EntityWeights {
+        pub transformed_url_length_by_scheme: Vec<SchemeLength>,
+        pub has_emoji_weight: bool,
+        pub emoji_weight: i32,
+        pub federated_mention_local_part_only: bool,
+    }
+
     pub struct Configuration {
         pub version: i32,
         pub max_weighted_tweet_length: i32,
@@ -36,6 +52,9 @@ pub mod ffi {
         pub transformed_url_length: i32,
         pub ranges: Vec<WeightedRange>,
         pub emoji_parsing_enabled: bool,
+        pub length_mode: LengthMode,
+        pub has_entity_weights: bool,
+        pub entity_weights: EntityWeights,
     }
 
     pub struct AutolinkerConfig {
@@ -106,8 +125,8 @@ pub mod ffi {
         fn config_v3() -> UniquePtr<Configuration>;
         fn default_config() -> UniquePtr<Configuration>;
         fn get_config_weighted_ranges(config: &Configuration) -> Vec<WeightedRange>;
-        fn configuration_from_path(path: &str) -> UniquePtr<Configuration>;
-        fn configuration_from_json(json: &str) -> UniquePtr<Configuration>;
+        fn configuration_from_path(path: &str) -> Result<UniquePtr<Configuration>>;
+        fn configuration_from_json(json: &str) -> Result<UniquePtr<Configuration>>;
 
         // Autolinker
         fn autolink_default_config() -> UniquePtr<AutolinkerConfig>;
@@ -296,6 +315,36 @@ impl ffi::WeightedRange {
     }
 }
 
+impl ffi::EntityWeights {
+    fn from(weights: &EntityWeights) -> ffi::EntityWeights {
+        ffi::EntityWeights {
+            transformed_url_length_by_scheme: weights
+                .transformed_url_length_by_scheme
+                .iter()
+                .map(|(scheme, &length)| ffi::SchemeLength {
+                    scheme: scheme.clone(),
+                    length,
+                })
+                .collect(),
+            has_emoji_weight: weights.emoji_weight.is_some(),
+            emoji_weight: weights.emoji_weight.unwrap_or_default(),
+            federated_mention_local_part_only: weights.federated_mention_local_part_only,
+        }
+    }
+
+    fn to(weights: &ffi::EntityWeights) -> EntityWeights {
+        EntityWeights {
+            transformed_url_length_by_scheme: weights
+                .transformed_url_length_by_scheme
+                .iter()
+                .map(|sl| (sl.scheme.clone(), sl.length))
+                .collect(),
+            emoji_weight: weights.has_emoji_weight.then_some(weights.emoji_weight),
+            federated_mention_local_part_only: weights.federated_mention_local_part_only,
+        }
+    }
+}
+
 impl ffi::Configuration {
     pub fn from(config: &Configuration) -> ffi::Configuration {
         ffi::Configuration {
@@ -310,6 +359,17 @@ impl ffi::Configuration {
                 .map(|r| ffi::WeightedRange::from(r))
                 .collect(),
             emoji_parsing_enabled: config.emoji_parsing_enabled,
+            length_mode: match config.length_mode {
+                LengthMode::Weighted => ffi::LengthMode::Weighted,
+                LengthMode::Graphemes => ffi::LengthMode::Graphemes,
+            },
+            has_entity_weights: config.entity_weights.is_some(),
+            entity_weights: ffi::EntityWeights::from(
+                config
+                    .entity_weights
+                    .as_ref()
+                    .unwrap_or(&EntityWeights::default()),
+            ),
         }
     }
 
@@ -326,6 +386,13 @@ impl ffi::Configuration {
                 .map(|wr| ffi::WeightedRange::to(&wr))
                 .collect(),
             emoji_parsing_enabled: config.emoji_parsing_enabled,
+            length_mode: match config.length_mode {
+                ffi::LengthMode::Graphemes => LengthMode::Graphemes,
+                _ => LengthMode::Weighted,
+            },
+            entity_weights: config
+                .has_entity_weights
+                .then(|| ffi::EntityWeights::to(&config.entity_weights)),
         }
     }
 }
@@ -370,17 +437,14 @@ pub fn get_config_weighted_ranges(config: &ffi::Configuration) -> Vec<ffi::Weigh
     config.ranges.to_vec()
 }
 
-pub fn configuration_from_path(path: &str) -> UniquePtr<ffi::Configuration> {
-    let pathbuf = PathBuf::from(path);
-    UniquePtr::new(ffi::Configuration::from(
-        &Configuration::configuration_from_path(&pathbuf),
-    ))
+pub fn configuration_from_path(path: &str) -> Result<UniquePtr<ffi::Configuration>, ConfigError> {
+    let config = Configuration::try_from_path(path)?;
+    Ok(UniquePtr::new(ffi::Configuration::from(&config)))
 }
 
-pub fn configuration_from_json(json: &str) -> UniquePtr<ffi::Configuration> {
-    UniquePtr::new(ffi::Configuration::from(
-        &Configuration::configuration_from_json(json),
-    ))
+pub fn configuration_from_json(json: &str) -> Result<UniquePtr<ffi::Configuration>, ConfigError> {
+    let config = Configuration::try_from_json(json)?;
+    Ok(UniquePtr::new(ffi::Configuration::from(&config)))
 }
 
 pub fn autolink_default_config() -> UniquePtr<ffi::AutolinkerConfig> {
@@ -430,6 +494,7 @@ pub fn autolink_entities(
             list_slug: &e.list_slug,
             display_url: &e.display_url,
             expanded_url: &e.expanded_url,
+            host_span: None,
         })
         .collect();
 
@@ -875,11 +940,59 @@ pub fn new_with_config<'a>(config: &'a ffi::AutolinkerConfig) -> Autolinker<'a>
         list_url_base: &config.list_url_base,
         hashtag_url_base: &config.hashtag_url_base,
         cashtag_url_base: &config.cashtag_url_base,
+        hashtag_url_template: "",
+        cashtag_url_template: "",
+        username_url_template: "",
+        list_url_template: "",
+        encode_url_base_values: false,
+        federated_mention_class: crate::autolinker::DEFAULT_FEDERATED_MENTION_CLASS,
+        federated_mention_url_template: crate::autolinker::DEFAULT_FEDERATED_MENTION_URL_TEMPLATE,
+        federated_mention_h_card: false,
         invisible_tag_attrs: &config.invisible_tag_attrs,
         username_include_symbol: config.username_include_symbol,
         include_data_screen_name: false,
         extractor,
         link_attribute_modifier: None,
         link_text_modifier: None,
+        entity_renderer: None,
+        strict: false,
+        emoji_rendering: crate::autolinker::EmojiRendering::None,
+        emoji_class: crate::autolinker::DEFAULT_EMOJI_CLASS,
+        emoji_image_url_template: crate::autolinker::DEFAULT_EMOJI_IMAGE_URL_TEMPLATE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configuration_entity_weights() {
+        let config = Configuration::default();
+        assert_eq!(
+            ffi::Configuration::to(&ffi::Configuration::from(&config)),
+            config
+        );
+
+        let config = Configuration {
+            entity_weights: Some(EntityWeights {
+                transformed_url_length_by_scheme: [("https".to_string(), 30)].into(),
+                emoji_weight: Some(0),
+                federated_mention_local_part_only: true,
+            }),
+            ..Configuration::default()
+        };
+        let bridged = ffi::Configuration::from(&config);
+        assert!(bridged.has_entity_weights);
+        assert!(bridged.entity_weights.has_emoji_weight);
+        assert_eq!(ffi::Configuration::to(&bridged), config);
+
+        let config = Configuration {
+            entity_weights: Some(EntityWeights::default()),
+            ..Configuration::default()
+        };
+        let bridged = ffi::Configuration::from(&config);
+        assert!(!bridged.entity_weights.has_emoji_weight);
+        assert_eq!(ffi::Configuration::to(&bridged), config);
     }
 }
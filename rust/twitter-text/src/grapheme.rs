@@ -0,0 +1,112 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Streaming extended grapheme cluster boundaries.
+//!
+//! The UAX #29 rules come from `unicode-segmentation`, which wants the text
+//! up front. Rules only look back to the start of the current cluster, so
+//! [GraphemeBreaker] keeps that cluster and asks whether the next character
+//! continues it.
+
+use unicode_segmentation::GraphemeCursor;
+
+/// Finds grapheme cluster boundaries one character at a time.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GraphemeBreaker {
+    cluster: String,
+}
+
+impl GraphemeBreaker {
+    pub(crate) fn new() -> GraphemeBreaker {
+        GraphemeBreaker::default()
+    }
+
+    /// Returns true if `c` starts a new grapheme cluster.
+    pub(crate) fn is_boundary(&mut self, c: char) -> bool {
+        let offset = self.cluster.len();
+        self.cluster.push(c);
+        let boundary = offset == 0
+            || GraphemeCursor::new(offset, self.cluster.len(), true)
+                .is_boundary(&self.cluster, 0)
+                .unwrap_or(true);
+        if boundary {
+            self.cluster.clear();
+            self.cluster.push(c);
+        }
+        boundary
+    }
+
+    /// Forget the previous character, so the next one starts a cluster.
+    pub(crate) fn reset(&mut self) {
+        self.cluster.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(s: &str) -> usize {
+        let mut breaker = GraphemeBreaker::new();
+        s.chars().filter(|&c| breaker.is_boundary(c)).count()
+    }
+
+    #[test]
+    fn test_ascii_and_crlf() {
+        assert_eq!(count(""), 0);
+        assert_eq!(count("hello"), 5);
+        assert_eq!(count("a\r\nb"), 3);
+        assert_eq!(count("a\n\rb"), 4);
+    }
+
+    #[test]
+    fn test_combining_marks() {
+        assert_eq!(count("e\u{301}"), 1);
+        assert_eq!(count("\u{915}\u{93F}"), 1); // कि
+        assert_eq!(count("\u{5D1}\u{5BC}"), 1); // בּ
+        assert_eq!(count("1\u{FE0F}\u{20E3}"), 1); // keycap
+    }
+
+    #[test]
+    fn test_prepend_and_conjuncts() {
+        assert_eq!(count("\u{600}1"), 1); // ؀1
+        assert_eq!(count("\u{915}\u{94D}\u{937}\u{93F}"), 1); // क्षि
+        assert_eq!(count("\u{915}\u{94D}"), 1); // क्
+    }
+
+    #[test]
+    fn test_hangul() {
+        assert_eq!(count("한국어"), 3);
+        assert_eq!(count("\u{1112}\u{1161}\u{11AB}"), 1); // conjoining jamo 한
+    }
+
+    #[test]
+    fn test_emoji() {
+        assert_eq!(count("👨‍👩‍👧‍👦"), 1);
+        assert_eq!(count("🙋🏽👨‍🎤"), 2);
+        assert_eq!(count("❤\u{FE0F}"), 1);
+        assert_eq!(
+            count("🏴\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}"),
+            1
+        );
+        // A ZWJ after a letter doesn't join the next emoji.
+        assert_eq!(count("a\u{200D}🐱"), 2);
+    }
+
+    #[test]
+    fn test_regional_indicators() {
+        assert_eq!(count("🇯🇵"), 1);
+        assert_eq!(count("🇯🇵🇺🇸"), 2);
+        assert_eq!(count("🇯🇵🇺"), 2);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut breaker = GraphemeBreaker::new();
+        assert!(breaker.is_boundary('e'));
+        assert!(!breaker.is_boundary('\u{301}'));
+        breaker.reset();
+        assert!(breaker.is_boundary('\u{301}'));
+    }
+}
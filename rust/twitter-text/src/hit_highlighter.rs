@@ -2,8 +2,11 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+use crate::io_write::write_io;
 use pest::iterators::Pairs;
 use pest::Parser;
+use std::fmt;
+use std::io;
 use twitter_text_parser::highlighter::HighlightParser;
 use twitter_text_parser::highlighter::Rule;
 
@@ -34,16 +37,40 @@ impl HitHighlighter {
     }
 
     pub fn highlight(&self, text: &str, hits: Vec<Hit>) -> String {
+        let tag = &self.highlight_tag;
+        let mut buf = String::with_capacity(text.len() + (hits.len() * (tag.len() * 2 + 5)));
+        // Writing to a String can't fail.
+        let _ = self.highlight_into(text, &hits, &mut buf);
+        buf
+    }
+
+    /// Like [HitHighlighter::highlight], but writes the result to `w`.
+    pub fn highlight_into<W: fmt::Write>(
+        &self,
+        text: &str,
+        hits: &[Hit],
+        w: &mut W,
+    ) -> fmt::Result {
         if hits.is_empty() {
-            return String::from(text);
+            return w.write_str(text);
         }
 
-        let mut builder = HighlightBuilder::new(text, &self.highlight_tag, &hits);
+        let mut builder = HighlightBuilder::new(w, &self.highlight_tag);
         if let Ok(pairs) = HighlightParser::parse(Rule::hit_text, text) {
-            Self::walk(pairs, &hits[..], &mut builder);
+            Self::walk(pairs, hits, &mut builder);
         }
 
-        builder.buffer()
+        builder.result()
+    }
+
+    /// Like [HitHighlighter::highlight], but writes the result to `w` as UTF-8.
+    pub fn highlight_to_writer<W: io::Write>(
+        &self,
+        text: &str,
+        hits: &[Hit],
+        w: &mut W,
+    ) -> io::Result<()> {
+        write_io(w, |mut f| self.highlight_into(text, hits, &mut f))
     }
 
     fn walk(pairs: Pairs<Rule>, hits: &[Hit], builder: &mut HighlightBuilder) -> usize {
@@ -86,38 +113,48 @@ impl HitHighlighter {
     }
 }
 
-struct HighlightBuilder {
-    buffer: String,
+// Writes the highlighted text, keeping the first write error.
+struct HighlightBuilder<'w, 't> {
+    w: &'w mut dyn fmt::Write,
     char_count: usize,
-    open: String,
-    close: String,
+    tag: &'t str,
+    result: fmt::Result,
 }
 
-impl HighlightBuilder {
-    fn new(text: &str, tag: &str, hits: &[Hit]) -> HighlightBuilder {
-        let capacity = text.len() + (hits.len() * (tag.len() + 2 + tag.len() + 3));
+impl<'w, 't> HighlightBuilder<'w, 't> {
+    fn new(w: &'w mut dyn fmt::Write, tag: &'t str) -> HighlightBuilder<'w, 't> {
         HighlightBuilder {
-            buffer: String::with_capacity(capacity),
+            w,
             char_count: 0,
-            open: ["<", tag, ">"].join(""),
-            close: ["</", tag, ">"].join(""),
+            tag,
+            result: Ok(()),
+        }
+    }
+
+    fn write(&mut self, s: &str) {
+        if self.result.is_ok() {
+            self.result = self.w.write_str(s);
         }
     }
 
     fn append_open(&mut self) {
-        self.buffer.push_str(self.open.as_str());
+        self.write("<");
+        self.write(self.tag);
+        self.write(">");
     }
 
     fn append_close(&mut self) {
-        self.buffer.push_str(self.close.as_str());
+        self.write("</");
+        self.write(self.tag);
+        self.write(">");
     }
 
     fn append_tag(&mut self, text: &str) {
-        self.buffer.push_str(text);
+        self.write(text);
     }
 
     fn append_char(&mut self, c: char) {
-        self.buffer.push(c);
+        self.write(c.encode_utf8(&mut [0; 4]));
         self.char_count += 1;
     }
 
@@ -125,7 +162,35 @@ impl HighlightBuilder {
         self.char_count
     }
 
-    fn buffer(self) -> String {
-        self.buffer
+    fn result(self) -> fmt::Result {
+        self.result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_into() {
+        let highlighter = HitHighlighter::new_with_tag("b");
+        let text = "a <a href=\"x\">link</a> here";
+        let hits = vec![(0, 1), (2, 6)];
+        let expected = highlighter.highlight(text, hits.clone());
+        assert_eq!(expected, "<b>a</b> <a href=\"x\"><b>link</b></a> here");
+
+        let mut buf = String::new();
+        highlighter.highlight_into(text, &hits, &mut buf).unwrap();
+        assert_eq!(buf, expected);
+
+        let mut out = Vec::new();
+        highlighter
+            .highlight_to_writer(text, &hits, &mut out)
+            .unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+
+        let mut buf = String::new();
+        highlighter.highlight_into("日本", &[], &mut buf).unwrap();
+        assert_eq!(buf, "日本");
     }
 }
@@ -0,0 +1,70 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Runs the `fmt::Write` renderers against an `io::Write`.
+
+use std::fmt;
+use std::io;
+
+struct Adapter<'w, W: io::Write> {
+    inner: &'w mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for Adapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+/// Call `f` with a `fmt::Write` that writes UTF-8 to `w`. The first I/O error
+/// stops `f` and is returned.
+pub(crate) fn write_io<W: io::Write>(
+    w: &mut W,
+    f: impl FnOnce(&mut dyn fmt::Write) -> fmt::Result,
+) -> io::Result<()> {
+    let mut adapter = Adapter {
+        inner: w,
+        error: None,
+    };
+    match f(&mut adapter) {
+        Ok(()) => Ok(()),
+        Err(fmt::Error) => Err(adapter
+            .error
+            .unwrap_or_else(|| io::Error::other("formatter error"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Full;
+
+    impl io::Write for Full {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::WriteZero, "full"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_io() {
+        let mut out = Vec::new();
+        write_io(&mut out, |w| w.write_str("日本")).unwrap();
+        assert_eq!(out, "日本".as_bytes());
+
+        let err = write_io(&mut Full, |w| w.write_str("x")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+
+        let err = write_io(&mut out, |_| Err(fmt::Error)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+    }
+}
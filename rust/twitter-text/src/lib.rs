@@ -2,24 +2,42 @@
 // Licensed under the Apache License, Version 2.0
 // http://www.apache.org/licenses/LICENSE-2.0
 
+mod autolink_options;
 pub mod autolinker;
+pub mod display_text;
+pub mod domain_policy;
 pub mod entity;
 pub mod extractor;
+mod grapheme;
 pub mod hit_highlighter;
+mod io_write;
+pub mod markdown;
 pub mod nom_parser;
+pub mod post;
+pub mod public_suffix;
+pub mod retweet;
+mod sanitize;
+pub mod segment;
+pub mod terminal;
+pub mod tld_set;
 pub mod tlds;
+pub mod url_parts;
+mod url_template;
 pub mod validator;
 
 #[cfg(feature = "ffi")]
 pub mod ffi;
 
 use extractor::{Extract, ValidatingExtractor};
-use twitter_text_config::Configuration;
 use twitter_text_config::Range;
+use twitter_text_config::{CompiledConfiguration, Configuration};
 
 // Re-export ParserBackend for convenience
 pub use extractor::ParserBackend;
 
+pub use display_text::display_text_range_for;
+pub use retweet::parse_retweet_prefix;
+
 /// A struct that represents a parsed tweet containing the length of the tweet,
 /// its validity, display ranges etc. The name mirrors Twitter's Java implementation.
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
@@ -106,7 +124,40 @@ pub fn parse_with_parser_backend(
     extract_urls: bool,
     parser_backend: ParserBackend,
 ) -> TwitterTextParseResults {
-    let mut extractor = ValidatingExtractor::with_parser_backend(config, parser_backend);
+    let extractor = ValidatingExtractor::with_parser_backend(config, parser_backend);
+    parse_with_extractor(extractor, text, extract_urls)
+}
+
+/// Produce a [TwitterTextParseResults] struct from a [str], weighing each character with a
+/// [CompiledConfiguration] table. Prefer this over [parse] when parsing many texts with the
+/// same configuration, especially text outside the first weighted range, such as CJK.
+pub fn parse_compiled(
+    text: &str,
+    compiled: &CompiledConfiguration,
+    extract_urls: bool,
+) -> TwitterTextParseResults {
+    parse_compiled_with_parser_backend(text, compiled, extract_urls, ParserBackend::default())
+}
+
+/// Produce a [TwitterTextParseResults] struct from a [str] using a [CompiledConfiguration] table
+/// and the specified parser backend.
+pub fn parse_compiled_with_parser_backend(
+    text: &str,
+    compiled: &CompiledConfiguration,
+    extract_urls: bool,
+    parser_backend: ParserBackend,
+) -> TwitterTextParseResults {
+    let mut extractor =
+        ValidatingExtractor::with_parser_backend(compiled.configuration(), parser_backend);
+    extractor.set_compiled_configuration(compiled);
+    parse_with_extractor(extractor, text, extract_urls)
+}
+
+fn parse_with_extractor(
+    mut extractor: ValidatingExtractor,
+    text: &str,
+    extract_urls: bool,
+) -> TwitterTextParseResults {
     let input = extractor.prep_input(text);
     if extract_urls {
         extractor
@@ -188,4 +239,116 @@ mod tests {
         assert!(result.is_valid);
         assert_eq!(result.permillage, 14);
     }
+
+    #[test]
+    fn test_parse_compiled_matches_parse() {
+        let texts = [
+            "Hello, world! https://example.com/path",
+            "日本語のテキスト、漢字とかなを含む。",
+            "H🐱☺👨‍👩‍👧‍👦 \u{2010}\u{2020}\u{2030}",
+            "Ελληνικά, русский, עברית, العربية",
+        ];
+        for config in [
+            twitter_text_config::config_v1(),
+            twitter_text_config::config_v2(),
+            twitter_text_config::config_v3(),
+        ] {
+            let compiled = CompiledConfiguration::new(config);
+            for text in texts {
+                assert_eq!(
+                    parse_compiled(text, &compiled, true),
+                    parse(text, config, true),
+                    "{}",
+                    text
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ranges_not_starting_at_zero() {
+        // Ranges only apply when the first one starts at 0, so everything gets
+        // the default weight.
+        let config = Configuration {
+            ranges: vec![twitter_text_config::WeightedRange::new(0x3040, 0x30ff, 100)],
+            ..Configuration::default()
+        };
+        let compiled = CompiledConfiguration::new(&config);
+        let text = "abひらがな";
+        let expected = 6 * 2;
+        assert_eq!(parse(text, &config, false).weighted_length, expected);
+        assert_eq!(
+            parse_compiled(text, &compiled, false).weighted_length,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_grapheme_length_mode() {
+        let config = Configuration {
+            max_weighted_tweet_length: 10,
+            length_mode: twitter_text_config::LengthMode::Graphemes,
+            ..Configuration::default()
+        };
+        // Family emoji, flag, e + combining acute, and three Hangul syllables.
+        let text = "👨‍👩‍👧‍👦🇯🇵e\u{301}한국어";
+        let result = parse(text, &config, false);
+        assert_eq!(result.weighted_length, 6);
+        assert_eq!(result.permillage, 600);
+        assert!(result.is_valid);
+
+        // URLs still count as transformedURLLength when extracted.
+        let result = parse("go https://example.com/long/path", &config, true);
+        assert_eq!(result.weighted_length, 3 + 23);
+        assert!(!result.is_valid);
+
+        // The valid range ends after the tenth grapheme, in UTF-16 units.
+        let text = "🇯🇵🇯🇵🇯🇵🇯🇵🇯🇵abcdefgh";
+        let result = parse(text, &config, false);
+        assert_eq!(result.weighted_length, 13);
+        assert!(!result.is_valid);
+        assert_eq!(result.valid_text_range, Range::new(0, 5 * 4 + 5 - 1));
+    }
+
+    #[test]
+    fn test_entity_weights() {
+        let mut by_scheme = std::collections::BTreeMap::new();
+        by_scheme.insert("http".to_string(), 30);
+        let config = Configuration {
+            entity_weights: Some(twitter_text_config::EntityWeights {
+                transformed_url_length_by_scheme: by_scheme,
+                emoji_weight: Some(100),
+                federated_mention_local_part_only: true,
+            }),
+            ..Configuration::default()
+        };
+        for backend in [
+            ParserBackend::Nom,
+            ParserBackend::External,
+            ParserBackend::Pest,
+        ] {
+            let weigh = |text| parse_with_parser_backend(text, &config, true, backend);
+            assert_eq!(weigh("a http://example.com").weighted_length, 2 + 30);
+            assert_eq!(weigh("a https://example.com").weighted_length, 2 + 23);
+            assert_eq!(weigh("a 😀").weighted_length, 2 + 1);
+            let result = weigh("hi @alice@mastodon.social");
+            assert_eq!(result.weighted_length, 3 + 6, "{:?}", backend);
+            assert_eq!(result.display_text_range, Range::new(0, 24));
+            assert_eq!(weigh("hi @alice").weighted_length, 3 + 6);
+        }
+
+        // Extracted federated mentions keep their full indices.
+        let text = "hi ＠bob@a.example x";
+        let extractor = ValidatingExtractor::new_with_nfc_input(&config, text);
+        let result = extractor.extract_entities_with_indices_federated(text);
+        // The full-width ＠ weighs 2.
+        assert_eq!(result.parse_results.weighted_length, 3 + 5 + 2);
+        assert_eq!(result.entities.len(), 1);
+        assert_eq!(result.entities[0].start, 3);
+        assert_eq!(result.entities[0].end, 17);
+
+        // Without entity weights the whole mention counts.
+        let result = parse("hi @alice@mastodon.social", &Configuration::default(), true);
+        assert_eq!(result.weighted_length, 25);
+    }
 }
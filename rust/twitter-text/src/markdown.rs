@@ -0,0 +1,335 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Markdown output for auto-linked entities.
+//!
+//! [MarkdownAutolinker] links the same entities as [Autolinker], with the same
+//! URL bases, domain policy, link text modifier and display URLs, but writes
+//! CommonMark links instead of HTML: `[#tag](…)`, `[@user](…)` and
+//! `<https://…>`. Markdown metacharacters in the surrounding text and in link
+//! text are backslash-escaped, so the output shows the original text.
+
+use crate::autolinker::{walk_entities, Autolinker};
+use crate::entity::{Entity, Type};
+use crate::extractor::Extract;
+
+/// Adds Markdown links to hashtag, cashtag, username, list and URL references
+/// in Tweet text.
+pub struct MarkdownAutolinker<'a> {
+    /// The link settings. HTML-only settings such as classes, `no_follow`,
+    /// symbol tags and the attribute modifier don't apply. The symbol is always
+    /// part of the link text.
+    pub autolinker: Autolinker<'a>,
+}
+
+impl Default for MarkdownAutolinker<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> MarkdownAutolinker<'a> {
+    /// A [MarkdownAutolinker] with default properties.
+    pub fn new() -> MarkdownAutolinker<'a> {
+        Self::with_autolinker(Autolinker::new(false))
+    }
+
+    /// A [MarkdownAutolinker] that takes its settings from `autolinker`.
+    pub fn with_autolinker(autolinker: Autolinker<'a>) -> MarkdownAutolinker<'a> {
+        MarkdownAutolinker { autolinker }
+    }
+
+    /// Auto-link all entities.
+    pub fn autolink(&self, text: &str) -> String {
+        let entities = self
+            .autolinker
+            .extractor
+            .extract_entities_with_indices(text);
+        self.autolink_entities(text, &entities)
+    }
+
+    /// Link the given entities, whose offsets are in UTF-16 code units.
+    pub fn autolink_entities(&self, text: &str, entities: &[Entity]) -> String {
+        let mut buf = String::with_capacity(text.len() * 2);
+        let mut line_start = true;
+        walk_entities(text, entities, |piece, entity| {
+            line_start = match entity {
+                None => escape_text(piece, line_start, &mut buf),
+                Some(entity) => self.link_entity(entity, piece, line_start, &mut buf),
+            };
+        });
+        buf
+    }
+
+    // Returns true if the output ends at the start of a line.
+    fn link_entity(
+        &self,
+        entity: &Entity,
+        source: &str,
+        line_start: bool,
+        buf: &mut String,
+    ) -> bool {
+        let autolinker = &self.autolinker;
+        let value = entity.get_value();
+        match entity.get_type() {
+            Type::URL => self.link_to_url(entity, buf),
+            Type::HASHTAG => self.link(entity, source, &autolinker.hashtag_href(value), None, buf),
+            Type::CASHTAG => self.link(entity, source, &autolinker.cashtag_href(value), None, buf),
+            Type::MENTION => self.link(entity, source, &autolinker.mention_href(entity), None, buf),
+            Type::FEDERATEDMENTION => match autolinker.federated_mention_href(entity) {
+                Some(href) => self.link(entity, source, &href, None, buf),
+                None => return escape_text(source, line_start, buf),
+            },
+        }
+        false
+    }
+
+    fn link_to_url(&self, entity: &Entity, buf: &mut String) {
+        let url = entity.get_value();
+        if self.autolinker.drops_url(entity) {
+            escape_text(url, false, buf);
+            return;
+        }
+        let display_url = entity.get_display_url();
+        let expanded_url = entity.get_expanded_url();
+        if !display_url.is_empty() && !expanded_url.is_empty() {
+            self.link(entity, display_url, url, Some(expanded_url), buf);
+        } else if self.autolinker.link_text_modifier.is_none() && is_autolink_safe(url) {
+            buf.push('<');
+            buf.push_str(url);
+            buf.push('>');
+        } else {
+            self.link(entity, url, url, None, buf);
+        }
+    }
+
+    fn link(&self, entity: &Entity, text: &str, href: &str, title: Option<&str>, buf: &mut String) {
+        let mut link_text = String::with_capacity(text.len());
+        escape_text(text, false, &mut link_text);
+        if let Some(modifier) = &self.autolinker.link_text_modifier {
+            link_text = modifier.modify(entity, &link_text);
+        }
+        // A "!" right before the link would make it an image.
+        if buf.ends_with('!') {
+            buf.insert(buf.len() - 1, '\\');
+        }
+        buf.push('[');
+        buf.push_str(&link_text);
+        buf.push_str("](");
+        escape_destination(href, buf);
+        if let Some(title) = title {
+            buf.push_str(" \"");
+            escape_title(title, buf);
+            buf.push('"');
+        }
+        buf.push(')');
+    }
+}
+
+// Backslash-escape the characters that would start Markdown syntax. `line_start`
+// says whether `s` begins a line, where block markers like "#", "-" and "1."
+// also need escaping. Returns true if `s` ends at the start of a line.
+fn escape_text(s: &str, line_start: bool, buf: &mut String) -> bool {
+    let mut line_start = line_start;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '&' | '~' | '|' => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            '#' | '+' | '-' | '=' if line_start => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            '0'..='9' if line_start => {
+                // An ordered list marker: digits followed by "." or ")".
+                buf.push(c);
+                while let Some(&d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+                    buf.push(d);
+                    chars.next();
+                }
+                if let Some(&marker) = chars.peek().filter(|&&m| m == '.' || m == ')') {
+                    buf.push('\\');
+                    buf.push(marker);
+                    chars.next();
+                }
+            }
+            _ => buf.push(c),
+        }
+        line_start = c == '\n' || (line_start && (c == ' ' || c == '\t'));
+    }
+    line_start
+}
+
+// A link destination: parentheses and backslashes are escaped, and characters
+// that would end the destination are percent-encoded.
+fn escape_destination(url: &str, buf: &mut String) {
+    for c in url.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            ' ' => buf.push_str("%20"),
+            '<' => buf.push_str("%3C"),
+            '>' => buf.push_str("%3E"),
+            _ => buf.push(c),
+        }
+    }
+}
+
+fn escape_title(title: &str, buf: &mut String) {
+    for c in title.chars() {
+        if c == '"' || c == '\\' {
+            buf.push('\\');
+        }
+        buf.push(c);
+    }
+}
+
+// Returns true if `url` can be written as a CommonMark autolink, `<url>`.
+fn is_autolink_safe(url: &str) -> bool {
+    url.contains("://")
+        && !url
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || c == '<' || c == '>')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::autolinker::LinkTextModifier;
+    use crate::domain_policy::DomainPolicy;
+
+    fn escape(s: &str) -> String {
+        let mut buf = String::new();
+        escape_text(s, true, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(escape("plain text."), "plain text.");
+        assert_eq!(
+            escape("*bold* _em_ `code`"),
+            "\\*bold\\* \\_em\\_ \\`code\\`"
+        );
+        assert_eq!(
+            escape("[x](y) <b> a&b ~s~ |"),
+            "\\[x\\](y) \\<b\\> a\\&b \\~s\\~ \\|"
+        );
+        assert_eq!(escape("# not a heading"), "\\# not a heading");
+        assert_eq!(escape("a\n - b\n+ c"), "a\n \\- b\n\\+ c");
+        assert_eq!(escape("1. one\n12) twelve 3."), "1\\. one\n12\\) twelve 3.");
+        assert_eq!(escape("x - y # z"), "x - y # z");
+    }
+
+    #[test]
+    fn test_autolink() {
+        let linker = MarkdownAutolinker::new();
+        assert_eq!(
+            linker.autolink("#rust by @user_1 on $TWTR"),
+            "[#rust](https://twitter.com/search?q=%23rust) by \
+             [@user\\_1](https://twitter.com/user_1) on \
+             [$TWTR](https://twitter.com/search?q=%24TWTR)"
+        );
+        assert_eq!(
+            linker.autolink("@user/my_list see https://example.com/a_b"),
+            "[@user/my\\_list](https://twitter.com/user/my_list) see <https://example.com/a_b>"
+        );
+        // A hashtag that starts the text isn't escaped as a heading.
+        assert_eq!(
+            linker.autolink("#tag\n# not a tag"),
+            "[#tag](https://twitter.com/search?q=%23tag)\n\\# not a tag"
+        );
+        // A "!" before a link is escaped so the link isn't an image.
+        assert_eq!(
+            linker.autolink("wow!#rust!"),
+            "wow\\![#rust](https://twitter.com/search?q=%23rust)!"
+        );
+        assert_eq!(
+            linker.autolink("wow!#rust"),
+            "wow\\![#rust](https://twitter.com/search?q=%23rust)"
+        );
+    }
+
+    #[test]
+    fn test_url_bases_and_parens() {
+        let mut linker = MarkdownAutolinker::new();
+        linker.autolinker.hashtag_url_base = "https://example.social/tags/";
+        linker.autolinker.username_url_base = "https://example.social/@";
+        assert_eq!(
+            linker.autolink("#tag @bob"),
+            "[#tag](https://example.social/tags/tag) [@bob](https://example.social/@bob)"
+        );
+
+        let text = "https://en.wikipedia.org/wiki/Rust_(language)";
+        let entity = Entity::new(Type::URL, text, 0, 45);
+        struct Upper;
+        impl LinkTextModifier for Upper {
+            fn modify(&self, _entity: &Entity, text: &str) -> String {
+                text.to_uppercase()
+            }
+        }
+        linker.autolinker.link_text_modifier = Some(Box::new(Upper));
+        assert_eq!(
+            linker.autolink_entities(text, &[entity]),
+            "[HTTPS://EN.WIKIPEDIA.ORG/WIKI/RUST\\_(LANGUAGE)]\
+             (https://en.wikipedia.org/wiki/Rust_\\(language\\))"
+        );
+    }
+
+    #[test]
+    fn test_display_url() {
+        let linker = MarkdownAutolinker::new();
+        let entity = Entity {
+            t: Type::URL,
+            start: 3,
+            end: 22,
+            value: "http://t.co/0JG5Mcq",
+            list_slug: "",
+            display_url: "blog.twitter.com/2011/05/twitte…",
+            expanded_url: "http://blog.twitter.com/2011/05/twitter-for-mac-update.html",
+            host_span: None,
+        };
+        assert_eq!(
+            linker.autolink_entities("hi http://t.co/0JG5Mcq", &[entity]),
+            "hi [blog.twitter.com/2011/05/twitte…](http://t.co/0JG5Mcq \
+             \"http://blog.twitter.com/2011/05/twitter-for-mac-update.html\")"
+        );
+    }
+
+    #[test]
+    fn test_unlinked_entities() {
+        let mut linker = MarkdownAutolinker::new();
+        let policy = DomainPolicy::blocklist(["evil.com"]).unwrap();
+        linker.autolinker.extractor.set_domain_policy(Some(policy));
+        assert_eq!(
+            linker.autolink("see http://evil.com/a_b"),
+            "see http://evil.com/a\\_b"
+        );
+
+        let text = "hi @a_b@example.social";
+        let entity = Entity::new(Type::FEDERATEDMENTION, "@a_b@example.social", 3, 22);
+        assert_eq!(
+            linker.autolink_entities(text, std::slice::from_ref(&entity)),
+            "hi [@a\\_b@example.social](https://example.social/@a_b)"
+        );
+        linker.autolinker.federated_mention_url_template = "";
+        assert_eq!(
+            linker.autolink_entities(text, &[entity]),
+            "hi @a\\_b@example.social"
+        );
+    }
+
+    #[test]
+    fn test_utf16_offsets() {
+        let linker = MarkdownAutolinker::new();
+        assert_eq!(
+            linker.autolink("🔥*🔥* #火"),
+            "🔥\\*🔥\\* [#火](https://twitter.com/search?q=%23火)"
+        );
+    }
+}
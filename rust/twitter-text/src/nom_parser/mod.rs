@@ -19,6 +19,8 @@ pub mod url;
 
 pub use entity::{NomEntity, NomEntityType};
 
+use crate::tld_set::TldSet;
+
 /// Get the last character of a string slice efficiently.
 /// For ASCII-only strings this is O(1), otherwise O(n) in the worst case.
 #[inline]
@@ -49,6 +51,12 @@ fn last_char(s: &str) -> Option<char> {
 /// # Returns
 /// A vector of `NomEntity` structs, sorted by start position.
 pub fn parse_tweet(input: &str) -> Vec<NomEntity<'_>> {
+    parse_tweet_with_tlds(input, &TldSet::builtin())
+}
+
+/// Parse a tweet and return all entities found, using `tlds` wherever the
+/// parser consults the TLD list.
+pub fn parse_tweet_with_tlds<'a>(input: &'a str, tlds: &TldSet) -> Vec<NomEntity<'a>> {
     // Pre-allocate for typical tweet entity count
     let mut entities = Vec::with_capacity(32);
     let mut pos = 0;
@@ -105,7 +113,9 @@ pub fn parse_tweet(input: &str) -> Vec<NomEntity<'_>> {
             let current_char = b as char;
 
             // Try to match an entity at this position
-            if let Some((entity, consumed)) = try_parse_entity(input, remaining, pos, prev_char) {
+            if let Some((entity, consumed)) =
+                try_parse_entity(input, remaining, pos, prev_char, tlds)
+            {
                 entities.push(entity);
                 // Update prev_char to last char of consumed entity
                 prev_char = last_char(&input[pos..pos + consumed]);
@@ -115,7 +125,7 @@ pub fn parse_tweet(input: &str) -> Vec<NomEntity<'_>> {
                 if b == b'$' {
                     // $ followed by URL-like content should be skipped
                     let after_dollar = &remaining[1..];
-                    let skip = skip_invalid_cashtag_url(after_dollar);
+                    let skip = skip_invalid_cashtag_url(after_dollar, tlds);
                     prev_char = last_char(&input[pos..pos + 1 + skip]);
                     pos += 1 + skip;
                 } else if b == b'@' {
@@ -152,7 +162,9 @@ pub fn parse_tweet(input: &str) -> Vec<NomEntity<'_>> {
             let char_len = c.len_utf8();
 
             // Try to match an entity at this position
-            if let Some((entity, consumed)) = try_parse_entity(input, remaining, pos, prev_char) {
+            if let Some((entity, consumed)) =
+                try_parse_entity(input, remaining, pos, prev_char, tlds)
+            {
                 entities.push(entity);
                 prev_char = last_char(&input[pos..pos + consumed]);
                 pos += consumed;
@@ -459,6 +471,16 @@ pub fn parse_hashtags_only(input: &str) -> Vec<NomEntity<'_>> {
 /// Parse a tweet and return only URL entities (with and without protocol).
 /// This is optimized for the case where only URLs are needed.
 pub fn parse_urls_only(input: &str, include_without_protocol: bool) -> Vec<NomEntity<'_>> {
+    parse_urls_only_with_tlds(input, include_without_protocol, &TldSet::builtin())
+}
+
+/// Parse a tweet and return only URL entities, using `tlds` wherever the
+/// parser consults the TLD list.
+pub fn parse_urls_only_with_tlds<'a>(
+    input: &'a str,
+    include_without_protocol: bool,
+    tlds: &TldSet,
+) -> Vec<NomEntity<'a>> {
     let mut entities = Vec::with_capacity(4);
     let bytes = input.as_bytes();
     let mut pos = 0;
@@ -536,7 +558,9 @@ pub fn parse_urls_only(input: &str, include_without_protocol: bool) -> Vec<NomEn
 
             // Try protocol URL (http:// or https://)
             if b == b'h' || b == b'H' {
-                if let Ok((_, (matched, host_start, host_end))) = url::parse_url(remaining) {
+                if let Ok((_, (matched, host_start, host_end))) =
+                    url::parse_url_with_tlds(remaining, tlds)
+                {
                     let consumed = matched.len();
                     entities.push(NomEntity::new_url(
                         NomEntityType::Url,
@@ -726,9 +750,9 @@ fn find_fullwidth_at(bytes: &[u8]) -> Option<usize> {
 /// When we see a $ that doesn't start a valid cashtag, check if what follows
 /// looks like a URL and return how many bytes to skip (not including the $).
 /// This prevents extracting partial URLs from things like "$twitter.com" or "$http://t.co".
-fn skip_invalid_cashtag_url(input: &str) -> usize {
+fn skip_invalid_cashtag_url(input: &str, tlds: &TldSet) -> usize {
     // Check for protocol URL: $http://... or $https://...
-    if let Ok((_, (matched, _, _))) = url::parse_url(input) {
+    if let Ok((_, (matched, _, _))) = url::parse_url_with_tlds(input, tlds) {
         return matched.len();
     }
 
@@ -787,11 +811,13 @@ fn looks_like_email_continuation(after: &str) -> bool {
 /// * `input` - The remaining text starting at the current position
 /// * `offset` - The byte offset into full_input where input starts
 /// * `prev_char` - The character immediately before this position (if any)
+/// * `tlds` - The TLD set used to reject hyphenated TLDs in protocol URLs
 fn try_parse_entity<'a>(
     full_input: &'a str,
     input: &'a str,
     offset: usize,
     prev_char: Option<char>,
+    tlds: &TldSet,
 ) -> Option<(NomEntity<'a>, usize)> {
     // Try each entity type in priority order
     // URLs should come first to avoid hashtag/mention ambiguity in URLs
@@ -949,7 +975,8 @@ fn try_parse_entity<'a>(
                 }
             }
 
-            if let Ok((_, (matched, host_start, host_end))) = url::parse_url(input) {
+            if let Ok((_, (matched, host_start, host_end))) = url::parse_url_with_tlds(input, tlds)
+            {
                 let consumed = matched.len();
                 let entity = NomEntity::new_url(
                     NomEntityType::Url,
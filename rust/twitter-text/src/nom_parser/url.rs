@@ -13,7 +13,9 @@
 use nom::IResult;
 
 use super::common;
-use crate::tlds::is_valid_tld_case_insensitive;
+use crate::tld_set::TldSet;
+use crate::url_parts::{HostKind, UrlParts};
+use std::ops::Range;
 
 /// Check if a character is an invalid TLD suffix.
 /// TLDs cannot be immediately followed by alphanumeric, @, or hyphen.
@@ -405,7 +407,7 @@ fn uwp_domain_segment_continue_unicode(input: &str, start_pos: usize) -> IResult
 ///
 /// Valid: test_underscore.twitter.com (underscore in subdomain)
 /// Invalid: foo.bar_baz.com (underscore in domain segment before TLD)
-fn domain(input: &str) -> IResult<&str, (&str, &str)> {
+fn domain<'a>(input: &'a str, tlds: &TldSet) -> IResult<&'a str, (&'a str, &'a str)> {
     let start = input;
 
     // Match one or more segments separated by dots, ending with a TLD
@@ -465,7 +467,7 @@ fn domain(input: &str) -> IResult<&str, (&str, &str)> {
             // we should reject this domain (it's a hyphenated TLD typo)
             if let Some(hyphen_pos) = segment.find('-') {
                 let before_hyphen = &segment[..hyphen_pos];
-                if is_valid_tld_case_insensitive(before_hyphen) {
+                if tlds.contains(before_hyphen) {
                     // Valid TLD followed by hyphen - this is a hyphenated TLD error
                     return Err(nom::Err::Error(nom::error::Error::new(
                         input,
@@ -557,7 +559,7 @@ fn tco_url(input: &str) -> IResult<&str, (&str, usize, usize)> {
 }
 
 /// Parse a normal URL (with protocol, not t.co).
-fn normal_url(input: &str) -> IResult<&str, (&str, usize, usize)> {
+fn normal_url<'a>(input: &'a str, tlds: &TldSet) -> IResult<&'a str, (&'a str, usize, usize)> {
     let start = input;
 
     let (remaining, _) = protocol(input)?;
@@ -572,7 +574,7 @@ fn normal_url(input: &str) -> IResult<&str, (&str, usize, usize)> {
     let host_start = start.len() - remaining.len();
 
     // Host: domain, IPv4, or IPv6
-    let (remaining, _) = host(remaining)?;
+    let (remaining, _) = host(remaining, tlds)?;
     let host_end = start.len() - remaining.len();
 
     // Check for invalid TLD suffix (hyphen after TLD indicates hyphenated TLD, which is invalid)
@@ -693,7 +695,7 @@ fn looks_like_ipv4(input: &str) -> bool {
 }
 
 /// Parse host (domain or IP).
-fn host(input: &str) -> IResult<&str, &str> {
+fn host<'a>(input: &'a str, tlds: &TldSet) -> IResult<&'a str, &'a str> {
     // Check for t.co first and reject it (handled by tco_url)
     if input.starts_with("t.co") {
         return Err(nom::Err::Error(nom::error::Error::new(
@@ -714,7 +716,7 @@ fn host(input: &str) -> IResult<&str, &str> {
     }
 
     // Otherwise, parse as domain
-    domain(input).map(|(r, (d, _))| (r, d))
+    domain(input, tlds).map(|(r, (d, _))| (r, d))
 }
 
 /// Parse IPv4 address using direct byte scanning.
@@ -1297,13 +1299,147 @@ pub fn parse_url_without_protocol(input: &str) -> IResult<&str, (&str, usize, us
 /// Parse a full URL (with protocol).
 /// Returns (matched_url, host_start, host_end).
 pub fn parse_url(input: &str) -> IResult<&str, (&str, usize, usize)> {
+    parse_url_with_tlds(input, &TldSet::builtin())
+}
+
+/// Parse a full URL (with protocol), checking hyphenated TLDs against `tlds`.
+/// Returns (matched_url, host_start, host_end).
+pub fn parse_url_with_tlds<'a>(
+    input: &'a str,
+    tlds: &TldSet,
+) -> IResult<&'a str, (&'a str, usize, usize)> {
     // Try t.co first
     if let Ok(result) = tco_url(input) {
         return Ok(result);
     }
 
     // Then normal URL
-    normal_url(input)
+    normal_url(input, tlds)
+}
+
+/// Find the host span of an already-extracted URL, with or without protocol.
+/// Returns (host_start, host_end) as byte offsets into `url`.
+pub fn host_span(url: &str) -> Option<(usize, usize)> {
+    if let Ok((_, (_, host_start, host_end))) = parse_url(url) {
+        return Some((host_start, host_end));
+    }
+    if let Ok((_, (_, host_start, host_end))) = parse_url_without_protocol(url) {
+        return Some((host_start, host_end));
+    }
+    None
+}
+
+/// Decompose an already-extracted URL, with or without protocol, into its
+/// component spans. Returns None if `url` doesn't start with a URL.
+pub(crate) fn url_parts(url: &str) -> Option<UrlParts<'_>> {
+    let offset = |remaining: &str| url.len() - remaining.len();
+
+    let (remaining, scheme) = match protocol(url) {
+        Ok((after, matched)) => (after, Some(0..matched.len() - 3)),
+        Err(_) => (url, None),
+    };
+
+    // Only URLs with a protocol have userinfo
+    let (remaining, userinfo) = match userinfo(remaining) {
+        Ok((after, _)) if scheme.is_some() => (after, Some(offset(remaining)..offset(after) - 1)),
+        _ => (remaining, None),
+    };
+
+    let host_start = offset(remaining);
+    let is_tco = match tco_domain(remaining) {
+        Ok((after, _)) => !after
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphanumeric() || c == '.' || c == '-' || c == '_'),
+        Err(_) => false,
+    };
+    let (remaining, host_kind) = if is_tco {
+        (&remaining[4..], HostKind::TCo)
+    } else if scheme.is_some() {
+        let (after, host) = host(remaining, &TldSet::builtin()).ok()?;
+        let kind = if host.starts_with('[') {
+            HostKind::Ipv6
+        } else if looks_like_ipv4(host) {
+            HostKind::Ipv4
+        } else {
+            HostKind::Domain
+        };
+        (after, kind)
+    } else {
+        let (after, _) = uwp_domain_and_tld(remaining).ok()?;
+        (after, HostKind::Domain)
+    };
+    let host = host_start..offset(remaining);
+    Some(url_parts_after_host(url, scheme, userinfo, host, host_kind))
+}
+
+/// Decompose an already-extracted URL around the host span the extractor
+/// found, so no TLD needs to be checked again. Returns None if `host` isn't
+/// a span of `url` after the scheme and userinfo.
+pub(crate) fn url_parts_with_host(url: &str, host: Range<usize>) -> Option<UrlParts<'_>> {
+    let host_str = url.get(host.clone())?;
+    let (after_scheme, scheme) = match protocol(url) {
+        Ok((after, matched)) => (url.len() - after.len(), Some(0..matched.len() - 3)),
+        Err(_) => (0, None),
+    };
+    let userinfo = match host.start.checked_sub(after_scheme)? {
+        0 => None,
+        _ if scheme.is_some() && url[..host.start].ends_with('@') => {
+            Some(after_scheme..host.start - 1)
+        }
+        _ => return None,
+    };
+    let host_kind = if host_str.eq_ignore_ascii_case("t.co") {
+        HostKind::TCo
+    } else if host_str.starts_with('[') {
+        HostKind::Ipv6
+    } else if looks_like_ipv4(host_str) {
+        HostKind::Ipv4
+    } else {
+        HostKind::Domain
+    };
+    Some(url_parts_after_host(url, scheme, userinfo, host, host_kind))
+}
+
+// Parse the port, path, query and fragment that follow the host.
+fn url_parts_after_host(
+    url: &str,
+    scheme: Option<Range<usize>>,
+    userinfo: Option<Range<usize>>,
+    host: Range<usize>,
+    host_kind: HostKind,
+) -> UrlParts<'_> {
+    let offset = |remaining: &str| url.len() - remaining.len();
+    let remaining = &url[host.end..];
+
+    let (remaining, port) = match port(remaining) {
+        Ok((after, _)) => (after, Some(offset(remaining) + 1..offset(after))),
+        Err(_) => (remaining, None),
+    };
+    let (remaining, path) = match path(remaining) {
+        Ok((after, _)) => (after, Some(offset(remaining)..offset(after))),
+        Err(_) => (remaining, None),
+    };
+    let (remaining, query) = match query(remaining) {
+        Ok((after, _)) => (after, Some(offset(remaining) + 1..offset(after))),
+        Err(_) => (remaining, None),
+    };
+    let fragment = match fragment(remaining) {
+        Ok((after, _)) => Some(offset(remaining) + 1..offset(after)),
+        Err(_) => None,
+    };
+
+    UrlParts {
+        url,
+        scheme,
+        userinfo,
+        host,
+        host_kind,
+        port,
+        path,
+        query,
+        fragment,
+    }
 }
 
 /// Check if the character before a potential URL is valid.
@@ -0,0 +1,480 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! HTML for posts in API JSON.
+//!
+//! [PostRenderer] links a post with the entities the server assigned to it,
+//! rather than extracting them again. Both API versions are read:
+//!
+//! - v1.1: `full_text` or `text`, with `entities` holding `hashtags`,
+//!   `symbols`, `user_mentions`, `urls` and `media`, each with `indices`. The
+//!   text of an `extended_tweet` is used when present. v1.1 text has `&`, `<`
+//!   and `>` HTML-escaped, and the indices count the unescaped text.
+//! - v2: `text`, with `entities` holding `hashtags`, `cashtags`, `mentions` and
+//!   `urls`, each with `start` and `end`. A `data` wrapper and `note_tweet` are
+//!   read too. URLs with a `media_key` are media links.
+//!
+//! In both, offsets count Unicode code points. Only the text inside
+//! `display_text_range` is rendered, and media links at the end of it are
+//! left out. Mentions with a user id get a `data-user-id` attribute. Entities
+//! that are out of range or overlap an earlier one are skipped.
+//!
+//! The text and entity fields come from the JSON, so posts are always linked
+//! in [strict](crate::autolinker::Autolinker::strict) mode.
+
+use crate::autolinker::{AutolinkOptions, LinkAttributeModifier};
+use crate::entity::{Entity, Type};
+use serde_json::Value;
+use std::error::Error;
+use std::fmt;
+
+/// Renders posts in API JSON to auto-linked HTML.
+///
+/// ```
+/// use twitter_text::post::PostRenderer;
+///
+/// let renderer = PostRenderer::new();
+/// let html = renderer
+///     .render_json(
+///         r#"{"text": "hi @jack", "entities": {"mentions": [
+///             {"start": 3, "end": 8, "username": "jack", "id": "12"}
+///         ]}}"#,
+///     )
+///     .unwrap();
+/// assert_eq!(
+///     html,
+///     "hi @<a class=\"tweet-url username\" href=\"https://twitter.com/jack\" \
+///      data-user-id=\"12\">jack</a>"
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct PostRenderer {
+    /// The link settings. `strict` is always on: the post text, display URLs
+    /// and every other string from the JSON are HTML-escaped, and unsafe
+    /// hrefs and attributes are dropped.
+    pub options: AutolinkOptions,
+}
+
+/// Errors from rendering a post.
+#[derive(Debug)]
+pub enum PostError {
+    /// The JSON couldn't be parsed.
+    Json(serde_json::Error),
+    /// The post has no `full_text` or `text` string.
+    MissingText,
+}
+
+impl fmt::Display for PostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PostError::Json(e) => write!(f, "error parsing post json: {}", e),
+            PostError::MissingText => write!(f, "post has no text"),
+        }
+    }
+}
+
+impl Error for PostError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PostError::Json(e) => Some(e),
+            PostError::MissingText => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for PostError {
+    fn from(e: serde_json::Error) -> Self {
+        PostError::Json(e)
+    }
+}
+
+impl PostRenderer {
+    /// A [PostRenderer] with default options.
+    pub fn new() -> PostRenderer {
+        Self::with_options(AutolinkOptions::default())
+    }
+
+    /// A [PostRenderer] that links with `options`.
+    pub fn with_options(options: AutolinkOptions) -> PostRenderer {
+        PostRenderer { options }
+    }
+
+    /// Parse a post from JSON and render it.
+    pub fn render_json(&self, json: &str) -> Result<String, PostError> {
+        let post: Value = serde_json::from_str(json)?;
+        self.render(&post)
+    }
+
+    /// Render a v1.1 or v2 post object.
+    pub fn render(&self, post: &Value) -> Result<String, PostError> {
+        let post = post.get("data").filter(|d| d.is_object()).unwrap_or(post);
+        let v1 = is_v1(post);
+        let source = ["extended_tweet", "note_tweet"]
+            .iter()
+            .find_map(|key| post.get(key).filter(|s| s.is_object()))
+            .unwrap_or(post);
+        let text = source
+            .get("full_text")
+            .or_else(|| source.get("text"))
+            .and_then(Value::as_str)
+            .ok_or(PostError::MissingText)?;
+        let text = if v1 {
+            text.replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&amp;", "&")
+        } else {
+            text.to_string()
+        };
+        let chars: Vec<char> = text.chars().collect();
+
+        let mut entities = post_entities(source, chars.len());
+        entities.sort_by_key(|e| e.start);
+        let (start, end) = display_range(source, post, &chars, &entities);
+        let mut last_end = start;
+        entities.retain(|e| {
+            let keep = e.start >= last_end && e.end <= end;
+            if keep {
+                last_end = e.end;
+            }
+            keep
+        });
+
+        // Copy the displayed text, recording where each code point lands in
+        // UTF-16 code units. Strict mode escapes it as it is written.
+        let display: String = chars[start..end].iter().collect();
+        let mut offsets = Vec::with_capacity(end - start + 1);
+        let mut utf16 = 0;
+        for &c in &chars[start..end] {
+            offsets.push(utf16);
+            utf16 += c.len_utf16() as i32;
+        }
+        offsets.push(utf16);
+
+        let linked: Vec<Entity> = entities
+            .iter()
+            .map(|e| Entity {
+                t: e.t,
+                start: offsets[e.start - start],
+                end: offsets[e.end - start],
+                value: e.value,
+                list_slug: "",
+                display_url: e.display_url,
+                expanded_url: e.expanded_url,
+                host_span: None,
+            })
+            .collect();
+        let user_ids: Vec<(i32, String)> = entities
+            .iter()
+            .zip(&linked)
+            .filter_map(|(e, l)| e.user_id.clone().map(|id| (l.start, id)))
+            .collect();
+
+        let mut autolinker = self.options.autolinker();
+        autolinker.strict = true;
+        let inner = autolinker.link_attribute_modifier.take();
+        autolinker.link_attribute_modifier = Some(Box::new(UserIds {
+            user_ids: &user_ids,
+            inner,
+        }));
+        Ok(autolinker.autolink_entities(&display, &linked))
+    }
+}
+
+// An entity from the post, with offsets in code points.
+struct PostEntity<'v> {
+    t: Type,
+    start: usize,
+    end: usize,
+    value: &'v str,
+    display_url: &'v str,
+    expanded_url: &'v str,
+    user_id: Option<String>,
+    media: bool,
+}
+
+// A post is v1.1 if it has v1.1-only fields or its entities have indices.
+fn is_v1(post: &Value) -> bool {
+    if ["id_str", "full_text", "extended_tweet"]
+        .iter()
+        .any(|key| post.get(key).is_some())
+    {
+        return true;
+    }
+    post.get("entities")
+        .and_then(Value::as_object)
+        .is_some_and(|entities| {
+            entities
+                .values()
+                .filter_map(Value::as_array)
+                .flatten()
+                .any(|e| e.get("indices").is_some())
+        })
+}
+
+// The entity lists of both API versions: the list's key, the entity type and
+// the keys of the entity's value.
+const ENTITY_LISTS: &[(&str, Type, &[&str])] = &[
+    ("hashtags", Type::HASHTAG, &["text", "tag"]),
+    ("symbols", Type::CASHTAG, &["text"]),
+    ("cashtags", Type::CASHTAG, &["tag"]),
+    ("user_mentions", Type::MENTION, &["screen_name"]),
+    ("mentions", Type::MENTION, &["username"]),
+    ("urls", Type::URL, &["url"]),
+    ("media", Type::URL, &["url"]),
+];
+
+fn post_entities(source: &Value, len: usize) -> Vec<PostEntity<'_>> {
+    let mut entities = Vec::new();
+    for &(key, t, value_keys) in ENTITY_LISTS {
+        let items = source
+            .get("entities")
+            .and_then(|lists| lists.get(key))
+            .and_then(Value::as_array);
+        for item in items.into_iter().flatten() {
+            let (start, end) = match offsets(item) {
+                Some((start, end)) if start < end && end <= len => (start, end),
+                _ => continue,
+            };
+            let value = match string(item, value_keys) {
+                Some(value) => value,
+                None => continue,
+            };
+            let user_id = match item.get("id_str").or_else(|| item.get("id")) {
+                Some(Value::String(id)) if t == Type::MENTION => Some(id.clone()),
+                Some(Value::Number(id)) if t == Type::MENTION => Some(id.to_string()),
+                _ => None,
+            };
+            entities.push(PostEntity {
+                t,
+                start,
+                end,
+                value,
+                display_url: string(item, &["display_url"]).unwrap_or(""),
+                expanded_url: string(item, &["expanded_url"]).unwrap_or(""),
+                user_id,
+                media: key == "media" || item.get("media_key").is_some(),
+            });
+        }
+    }
+    entities
+}
+
+// The first of `keys` whose value is a string.
+fn string<'v>(item: &'v Value, keys: &[&str]) -> Option<&'v str> {
+    keys.iter()
+        .find_map(|key| item.get(key).and_then(Value::as_str))
+}
+
+// The [start, end) code point offsets of a v1.1 or v2 entity.
+fn offsets(item: &Value) -> Option<(usize, usize)> {
+    let index = |v: &Value| v.as_u64().map(|i| i as usize);
+    if let Some(indices) = item.get("indices").and_then(Value::as_array) {
+        return Some((index(indices.first()?)?, index(indices.get(1)?)?));
+    }
+    Some((index(item.get("start")?)?, index(item.get("end")?)?))
+}
+
+// The code point range to render: display_text_range if the post has one,
+// without trailing media links.
+fn display_range(
+    source: &Value,
+    post: &Value,
+    chars: &[char],
+    entities: &[PostEntity],
+) -> (usize, usize) {
+    let range = source
+        .get("display_text_range")
+        .or_else(|| post.get("display_text_range"))
+        .and_then(Value::as_array);
+    let mut start = 0;
+    let mut end = chars.len();
+    if let Some(range) = range {
+        let index = |i: usize| range.get(i).and_then(Value::as_u64).map(|i| i as usize);
+        if let (Some(s), Some(e)) = (index(0), index(1)) {
+            if s <= e && e <= chars.len() {
+                start = s;
+                end = e;
+            }
+        }
+    }
+    let trim_end = |end: usize| {
+        let mut end = end;
+        while end > start && chars[end - 1].is_whitespace() {
+            end -= 1;
+        }
+        end
+    };
+    end = trim_end(end);
+    while let Some(media) = entities
+        .iter()
+        .rev()
+        .find(|e| e.media && e.start >= start && e.end == end)
+    {
+        end = trim_end(media.start);
+    }
+    (start, end)
+}
+
+// Adds data-user-id to mentions, after the options' own modifier runs.
+struct UserIds<'i> {
+    user_ids: &'i [(i32, String)],
+    inner: Option<Box<dyn LinkAttributeModifier + 'i>>,
+}
+
+impl LinkAttributeModifier for UserIds<'_> {
+    fn modify(&self, entity: &Entity, attributes: &mut Vec<(String, String)>) {
+        if let Some(inner) = &self.inner {
+            inner.modify(entity, attributes);
+        }
+        if let Some((_, id)) = self.user_ids.iter().find(|(s, _)| *s == entity.get_start()) {
+            if entity.get_type() == Type::MENTION {
+                attributes.push(("data-user-id".to_string(), id.clone()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_v1() {
+        // A reply whose text was escaped by the API, with a trailing photo.
+        let json = r#"{
+            "id_str": "1",
+            "full_text": "@jack Tom &amp; Jerry 😀 #cats https://t.co/abc https://t.co/pic",
+            "display_text_range": [6, 59],
+            "entities": {
+                "hashtags": [{"text": "cats", "indices": [20, 25]}],
+                "user_mentions": [
+                    {"screen_name": "jack", "name": "jack", "id": 12, "id_str": "12", "indices": [0, 5]}
+                ],
+                "urls": [{
+                    "url": "https://t.co/abc",
+                    "expanded_url": "https://example.com/a?b=1&c=2",
+                    "display_url": "example.com/a?b=1&c=2",
+                    "indices": [26, 42]
+                }],
+                "media": [{
+                    "url": "https://t.co/pic",
+                    "expanded_url": "https://twitter.com/jack/status/1/photo/1",
+                    "display_url": "pic.twitter.com/pic",
+                    "indices": [43, 59]
+                }]
+            }
+        }"#;
+        let html = PostRenderer::new().render_json(json).unwrap();
+        assert!(html.starts_with(
+            "Tom &amp; Jerry 😀 <a href=\"https://twitter.com/search?q=%23cats\" \
+             title=\"#cats\" class=\"tweet-url hashtag\">#cats</a> <a href=\"https://t.co/abc\" \
+             title=\"https://example.com/a?b=1&amp;c=2\">"
+        ));
+        assert!(html.ends_with("</a>"));
+        assert!(!html.contains("jack"));
+        assert!(!html.contains("t.co/pic"));
+    }
+
+    #[test]
+    fn test_v2() {
+        // Offsets count code points, so the emoji is one.
+        let json = r#"{"data": {
+            "text": "😀 <b> @bob #tag https://t.co/pic",
+            "entities": {
+                "mentions": [{"start": 6, "end": 10, "username": "bob", "id": "2244994945"}],
+                "hashtags": [{"start": 11, "end": 15, "tag": "tag"}],
+                "urls": [{
+                    "start": 16,
+                    "end": 32,
+                    "url": "https://t.co/pic",
+                    "expanded_url": "https://x.com/bob/status/1/photo/1",
+                    "display_url": "pic.x.com/pic",
+                    "media_key": "3_1"
+                }]
+            }
+        }}"#;
+        assert_eq!(
+            PostRenderer::new().render_json(json).unwrap(),
+            "😀 &lt;b&gt; @<a class=\"tweet-url username\" href=\"https://twitter.com/bob\" \
+             data-user-id=\"2244994945\">bob</a> <a href=\"https://twitter.com/search?q=%23tag\" \
+             title=\"#tag\" class=\"tweet-url hashtag\">#tag</a>"
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let json = r#"{
+            "text": "a&b @amy",
+            "note_tweet": {
+                "text": "a&b @amy, a long post",
+                "entities": {"mentions": [{"start": 4, "end": 8, "username": "amy", "id": "7"}]}
+            }
+        }"#;
+        let options = AutolinkOptions {
+            strict: true,
+            username_include_symbol: true,
+            ..AutolinkOptions::default()
+        };
+        assert_eq!(
+            PostRenderer::with_options(options)
+                .render_json(json)
+                .unwrap(),
+            "a&amp;b <a class=\"tweet-url username\" href=\"https://twitter.com/amy\" \
+             data-user-id=\"7\">@amy</a>, a long post"
+        );
+
+        // Overlapping and out of range entities are skipped.
+        let json = r##"{"text": "#a #b", "entities": {"hashtags": [
+            {"start": 0, "end": 2, "tag": "a"},
+            {"start": 1, "end": 2, "tag": "x"},
+            {"start": 3, "end": 9, "tag": "b"}
+        ]}}"##;
+        assert_eq!(
+            PostRenderer::new().render_json(json).unwrap(),
+            "<a href=\"https://twitter.com/search?q=%23a\" title=\"#a\" \
+             class=\"tweet-url hashtag\">#a</a> #b"
+        );
+    }
+
+    #[test]
+    fn test_markup_in_entities() {
+        // Markup in the JSON strings is escaped, even without strict options.
+        let json = r#"{
+            "text": "<b>hi</b> https://t.co/abc https://t.co/def",
+            "entities": {"urls": [
+                {
+                    "start": 10,
+                    "end": 26,
+                    "url": "https://t.co/abc",
+                    "expanded_url": "https://example.com/\"><script>",
+                    "display_url": "<img src=x onerror=alert(1)>"
+                },
+                {
+                    "start": 27,
+                    "end": 43,
+                    "url": "javascript:alert(1)",
+                    "expanded_url": "",
+                    "display_url": ""
+                }
+            ]}
+        }"#;
+        let html = PostRenderer::new().render_json(json).unwrap();
+        assert_eq!(
+            html,
+            "&lt;b&gt;hi&lt;/b&gt; <a href=\"https://t.co/abc\" \
+             title=\"https://example.com/&quot;&gt;&lt;script&gt;\">\
+             &lt;img src=x onerror=alert(1)&gt;</a> <a>javascript:alert(1)</a>"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let renderer = PostRenderer::new();
+        assert!(matches!(renderer.render_json("{"), Err(PostError::Json(_))));
+        assert!(matches!(
+            renderer.render_json(r#"{"id": "1"}"#),
+            Err(PostError::MissingText)
+        ));
+    }
+}
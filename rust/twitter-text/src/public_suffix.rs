@@ -0,0 +1,278 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Public Suffix List support.
+//!
+//! Parses the list published at <https://publicsuffix.org/list/public_suffix_list.dat>
+//! and finds the public suffix (eTLD) and registrable domain (eTLD+1) of a host,
+//! e.g. `news.bbc.co.uk` → `bbc.co.uk`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const NORMAL: u8 = 1;
+const WILDCARD: u8 = 2;
+const EXCEPTION: u8 = 4;
+
+const PRIVATE_SECTION_START: &str = "// ===BEGIN PRIVATE DOMAINS===";
+
+/// A parsed Public Suffix List.
+#[derive(Debug, Clone, Default)]
+pub struct PublicSuffixList {
+    // Rule text without the "*." or "!" prefix, mapped to the kinds of rule
+    // that share it. Labels are lowercase Unicode.
+    rules: HashMap<String, u8>,
+}
+
+impl PublicSuffixList {
+    /// Parse the Public Suffix List, including the PRIVATE section
+    /// (suffixes like `github.io` that are operated by companies).
+    pub fn parse(input: &str) -> PublicSuffixList {
+        Self::parse_impl(input, true)
+    }
+
+    /// Parse only the ICANN section of the Public Suffix List.
+    pub fn parse_icann(input: &str) -> PublicSuffixList {
+        Self::parse_impl(input, false)
+    }
+
+    /// Load and parse a Public Suffix List file, including the PRIVATE section.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<PublicSuffixList> {
+        Ok(Self::parse(&fs::read_to_string(path)?))
+    }
+
+    fn parse_impl(input: &str, include_private: bool) -> PublicSuffixList {
+        let mut rules = HashMap::new();
+        for line in input.lines() {
+            let line = line.trim();
+            if line.starts_with(PRIVATE_SECTION_START) && !include_private {
+                break;
+            }
+            // Rules are the first whitespace-delimited token on a line.
+            let rule = match line.split_whitespace().next() {
+                Some(rule) if !rule.starts_with("//") => rule,
+                _ => continue,
+            };
+            let (kind, rule) = if let Some(rest) = rule.strip_prefix('!') {
+                (EXCEPTION, rest)
+            } else if let Some(rest) = rule.strip_prefix("*.") {
+                (WILDCARD, rest)
+            } else {
+                (NORMAL, rule)
+            };
+            let key = rule
+                .split('.')
+                .map(normalize_label)
+                .collect::<Vec<_>>()
+                .join(".");
+            *rules.entry(key).or_insert(0) |= kind;
+        }
+        PublicSuffixList { rules }
+    }
+
+    /// The number of distinct rules in the list.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns true if the list has no rules. An empty list still applies the
+    /// implicit `*` rule, treating every TLD as a public suffix.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// The public suffix (eTLD) of `host`, as a slice of `host`.
+    ///
+    /// Punycode labels are matched against the list in their Unicode form, so
+    /// `xn--` hosts and their Unicode equivalents give the same answer.
+    pub fn public_suffix<'h>(&self, host: &'h str) -> Option<&'h str> {
+        let labels = split_labels(host)?;
+        let count = self.public_suffix_label_count(&labels);
+        Some(suffix_of(host, &labels, count))
+    }
+
+    /// The registrable domain (eTLD+1) of `host`, as a slice of `host`.
+    ///
+    /// Returns `None` for IP literals and for hosts that are themselves a
+    /// public suffix.
+    pub fn registrable_domain<'h>(&self, host: &'h str) -> Option<&'h str> {
+        let labels = split_labels(host)?;
+        let count = self.public_suffix_label_count(&labels) + 1;
+        if count > labels.len() {
+            return None;
+        }
+        Some(suffix_of(host, &labels, count))
+    }
+
+    fn public_suffix_label_count(&self, labels: &[(usize, &str)]) -> usize {
+        let normalized: Vec<String> = labels.iter().map(|(_, l)| normalize_label(l)).collect();
+        // The implicit "*" rule matches the TLD.
+        let mut longest = 1;
+        let mut exception = None;
+        let mut key = String::new();
+        for k in 1..=normalized.len() {
+            let label = &normalized[normalized.len() - k];
+            key = if key.is_empty() {
+                label.clone()
+            } else {
+                format!("{}.{}", label, key)
+            };
+            let flags = match self.rules.get(&key) {
+                Some(flags) => *flags,
+                None => continue,
+            };
+            if flags & EXCEPTION != 0 {
+                // Exception rules win, and the suffix excludes their leftmost label.
+                exception = Some(k - 1);
+            }
+            if flags & NORMAL != 0 {
+                longest = longest.max(k);
+            }
+            if flags & WILDCARD != 0 && k < normalized.len() {
+                longest = longest.max(k + 1);
+            }
+        }
+        exception.unwrap_or(longest)
+    }
+}
+
+/// Lowercase a label and decode it from punycode if needed.
+fn normalize_label(label: &str) -> String {
+    let lowered = label.to_lowercase();
+    if lowered.starts_with("xn--") {
+        let (unicode, result) = idna::domain_to_unicode(&lowered);
+        if result.is_ok() {
+            return unicode;
+        }
+    }
+    lowered
+}
+
+fn is_label_separator(c: char) -> bool {
+    // The dots UTS #46 maps to U+002E.
+    matches!(c, '.' | '\u{3002}' | '\u{ff0e}' | '\u{ff61}')
+}
+
+/// Split a host into (byte offset, label) pairs. Returns `None` for empty
+/// labels and IP literals.
+fn split_labels(host: &str) -> Option<Vec<(usize, &str)>> {
+    let host = host.strip_suffix('.').unwrap_or(host);
+    if host.is_empty() || host.starts_with('[') {
+        return None;
+    }
+    let mut labels = Vec::new();
+    let mut start = 0;
+    for (i, c) in host.char_indices() {
+        if is_label_separator(c) {
+            labels.push((start, &host[start..i]));
+            start = i + c.len_utf8();
+        }
+    }
+    labels.push((start, &host[start..]));
+    if labels.iter().any(|(_, l)| l.is_empty()) {
+        return None;
+    }
+    if labels
+        .iter()
+        .all(|(_, l)| l.bytes().all(|b| b.is_ascii_digit()))
+    {
+        // IPv4 address
+        return None;
+    }
+    Some(labels)
+}
+
+fn suffix_of<'h>(host: &'h str, labels: &[(usize, &str)], count: usize) -> &'h str {
+    let (start, _) = labels[labels.len() - count];
+    let (last_start, last) = labels[labels.len() - 1];
+    &host[start..last_start + last.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIST: &str = "\
+// ===BEGIN ICANN DOMAINS===
+com
+uk
+co.uk
+jp
+*.kawasaki.jp
+!city.kawasaki.jp
+// ck : https://en.wikipedia.org/wiki/.ck
+*.ck
+!www.ck
+рф
+// ===END ICANN DOMAINS===
+// ===BEGIN PRIVATE DOMAINS===
+github.io
+// ===END PRIVATE DOMAINS===
+";
+
+    #[test]
+    fn test_registrable_domain() {
+        let psl = PublicSuffixList::parse(LIST);
+        assert_eq!(psl.registrable_domain("news.bbc.co.uk"), Some("bbc.co.uk"));
+        assert_eq!(psl.registrable_domain("bbc.co.uk"), Some("bbc.co.uk"));
+        assert_eq!(psl.registrable_domain("co.uk"), None);
+        assert_eq!(
+            psl.registrable_domain("www.Example.COM"),
+            Some("Example.COM")
+        );
+        assert_eq!(
+            psl.registrable_domain("a.b.example.unlisted"),
+            Some("example.unlisted")
+        );
+        assert_eq!(
+            psl.registrable_domain("foo.github.io"),
+            Some("foo.github.io")
+        );
+    }
+
+    #[test]
+    fn test_wildcard_and_exception_rules() {
+        let psl = PublicSuffixList::parse(LIST);
+        assert_eq!(psl.public_suffix("a.b.kawasaki.jp"), Some("b.kawasaki.jp"));
+        assert_eq!(
+            psl.registrable_domain("a.b.kawasaki.jp"),
+            Some("a.b.kawasaki.jp")
+        );
+        assert_eq!(psl.registrable_domain("b.kawasaki.jp"), None);
+        assert_eq!(
+            psl.registrable_domain("www.city.kawasaki.jp"),
+            Some("city.kawasaki.jp")
+        );
+        assert_eq!(psl.registrable_domain("www.ck"), Some("www.ck"));
+        assert_eq!(psl.registrable_domain("foo.bar.ck"), Some("foo.bar.ck"));
+    }
+
+    #[test]
+    fn test_icann_only() {
+        let psl = PublicSuffixList::parse_icann(LIST);
+        assert_eq!(psl.registrable_domain("foo.github.io"), Some("github.io"));
+    }
+
+    #[test]
+    fn test_idn_hosts() {
+        let psl = PublicSuffixList::parse(LIST);
+        assert_eq!(psl.registrable_domain("www.пример.рф"), Some("пример.рф"));
+        assert_eq!(
+            psl.registrable_domain("www.xn--e1afmkfd.xn--p1ai"),
+            Some("xn--e1afmkfd.xn--p1ai")
+        );
+        assert_eq!(psl.public_suffix("xn--e1afmkfd.xn--p1ai"), Some("xn--p1ai"));
+    }
+
+    #[test]
+    fn test_ip_and_invalid_hosts() {
+        let psl = PublicSuffixList::parse(LIST);
+        assert_eq!(psl.registrable_domain("192.168.0.1"), None);
+        assert_eq!(psl.registrable_domain("[::1]"), None);
+        assert_eq!(psl.registrable_domain(""), None);
+        assert_eq!(psl.registrable_domain("a..com"), None);
+    }
+}
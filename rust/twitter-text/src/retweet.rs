@@ -0,0 +1,249 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Old-style retweets.
+//!
+//! Before retweets were part of the API, people copied a post and credited
+//! its author in the text: `RT @user: the post`, `MT @user: a modified post`
+//! or `the post via @user`. Long posts were cut short with `…` to fit.
+//! [parse_retweet_prefix] finds the credited user and the copied text.
+
+use crate::entity::Entity;
+use crate::extractor::{Extract, Extractor};
+use twitter_text_config::Range;
+
+/// How a post credits the user it copies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RetweetKind {
+    /// `RT @user: text`
+    Retweet,
+    /// `MT @user: text`, a retweet that was edited.
+    Modified,
+    /// `text via @user` or `text (via @user)`
+    Via,
+}
+
+/// An old-style retweet found by [parse_retweet_prefix].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetweetPrefix<'t> {
+    pub kind: RetweetKind,
+    /// The screen name of the credited user, without the `@`.
+    pub screen_name: &'t str,
+    /// The copied text in UTF-16 code units, without the credit, the
+    /// surrounding whitespace or a trailing `…`. Like
+    /// [DisplayText::range](crate::display_text::DisplayText::range), the end
+    /// is inclusive, so the range is empty when `end < start`.
+    pub body: Range,
+    /// Whether the copied text ends with `…`, meaning it was cut short.
+    pub truncated: bool,
+}
+
+/// Parse an old-style retweet: `RT @user: text` or `MT @user: text` at the
+/// start of `text`, or `via @user` at its end. The colon after the user is
+/// optional, and full-width forms like `ＲＴ ＠user：` are recognised too.
+/// Returns `None` if `text` doesn't credit a user this way.
+pub fn parse_retweet_prefix(text: &str) -> Option<RetweetPrefix<'_>> {
+    let mentions: Vec<Entity> = Extractor::new()
+        .extract_mentions_or_lists_with_indices(text)
+        .into_iter()
+        .filter(|e| e.list_slug.is_empty())
+        .collect();
+    let chars: Vec<char> = text.chars().map(fold_width).collect();
+    let mut offsets = Vec::with_capacity(chars.len() + 1);
+    let mut offset = 0;
+    for c in text.chars() {
+        offsets.push(offset);
+        offset += c.len_utf16() as i32;
+    }
+    offsets.push(offset);
+    let text = Chars { chars, offsets };
+
+    text.parse_prefix(&mentions)
+        .or_else(|| text.parse_via(&mentions))
+}
+
+struct Chars {
+    chars: Vec<char>,
+    /// The UTF-16 offset of each char, followed by the length of the text.
+    offsets: Vec<i32>,
+}
+
+impl Chars {
+    fn parse_prefix<'t>(&self, mentions: &[Entity<'t>]) -> Option<RetweetPrefix<'t>> {
+        let start = self.skip_space(0);
+        let kind = match self.chars.get(start..start + 2)? {
+            ['R' | 'r', 'T' | 't'] => RetweetKind::Retweet,
+            ['M' | 'm', 'T' | 't'] => RetweetKind::Modified,
+            _ => return None,
+        };
+        let mut i = start + 2;
+        if self.chars.get(i) == Some(&':') {
+            i += 1;
+        }
+        i = self.skip_space(i);
+        let mention = mentions.iter().find(|e| e.start == self.offsets[i])?;
+
+        let mut body_start = self.index(mention.end);
+        if self.chars.get(body_start) == Some(&':') {
+            body_start += 1;
+        }
+        let body_start = self.skip_space(body_start);
+        Some(self.result(kind, mention, body_start, self.chars.len()))
+    }
+
+    fn parse_via<'t>(&self, mentions: &[Entity<'t>]) -> Option<RetweetPrefix<'t>> {
+        let mention = mentions.last()?;
+        let mut end = self.skip_space(self.index(mention.end));
+        let parenthesized = self.chars.get(end) == Some(&')');
+        if parenthesized {
+            end = self.skip_space(end + 1);
+        }
+        if end != self.chars.len() {
+            return None;
+        }
+
+        let mut i = self.trim_space(0, self.index(mention.start));
+        let is_via = i >= 3
+            && self.chars[i - 3..i]
+                .iter()
+                .zip("via".chars())
+                .all(|(c, v)| c.to_ascii_lowercase() == v)
+            && (i == 3 || !self.chars[i - 4].is_alphanumeric());
+        if !is_via {
+            return None;
+        }
+        i -= 3;
+        if parenthesized {
+            if i == 0 || self.chars[i - 1] != '(' {
+                return None;
+            }
+            i -= 1;
+        }
+        let body_start = self.skip_space(0);
+        Some(self.result(RetweetKind::Via, mention, body_start, i))
+    }
+
+    fn result<'t>(
+        &self,
+        kind: RetweetKind,
+        mention: &Entity<'t>,
+        start: usize,
+        end: usize,
+    ) -> RetweetPrefix<'t> {
+        let mut end = self.trim_space(start, end);
+        let truncated = end > start && self.chars[end - 1] == '…';
+        if truncated {
+            end = self.trim_space(start, end - 1);
+        }
+        RetweetPrefix {
+            kind,
+            screen_name: mention.value,
+            body: Range::new(self.offsets[start], self.offsets[end] - 1),
+            truncated,
+        }
+    }
+
+    /// The char index of a UTF-16 offset from an entity.
+    fn index(&self, offset: i32) -> usize {
+        self.offsets.partition_point(|&o| o < offset)
+    }
+
+    fn skip_space(&self, mut i: usize) -> usize {
+        while i < self.chars.len() && self.chars[i].is_whitespace() {
+            i += 1;
+        }
+        i
+    }
+
+    fn trim_space(&self, start: usize, mut i: usize) -> usize {
+        while i > start && self.chars[i - 1].is_whitespace() {
+            i -= 1;
+        }
+        i
+    }
+}
+
+/// Map full-width ASCII forms like `Ｒ` and `：` to their ASCII equivalents.
+fn fold_width(c: char) -> char {
+    match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Option<(RetweetKind, &str, i32, i32, bool)> {
+        parse_retweet_prefix(text).map(|rt| {
+            (
+                rt.kind,
+                rt.screen_name,
+                rt.body.start(),
+                rt.body.end(),
+                rt.truncated,
+            )
+        })
+    }
+
+    #[test]
+    fn test_prefix() {
+        use RetweetKind::*;
+        assert_eq!(
+            parse("RT @jack: hello"),
+            Some((Retweet, "jack", 10, 14, false))
+        );
+        assert_eq!(
+            parse("rt @jack hello"),
+            Some((Retweet, "jack", 9, 13, false))
+        );
+        assert_eq!(
+            parse(" RT: @jack:hello "),
+            Some((Retweet, "jack", 11, 15, false))
+        );
+        assert_eq!(
+            parse("MT @jack: hi"),
+            Some((Modified, "jack", 10, 11, false))
+        );
+        assert_eq!(parse("RT @jack"), Some((Retweet, "jack", 8, 7, false)));
+        assert_eq!(
+            parse("ＲＴ ＠jack：hello"),
+            Some((Retweet, "jack", 9, 13, false))
+        );
+
+        assert_eq!(parse("RTS @jack: hello"), None);
+        assert_eq!(parse("RT jack: hello"), None);
+        assert_eq!(parse("RT @jack/list: hello"), None);
+        assert_eq!(parse("hello @jack"), None);
+    }
+
+    #[test]
+    fn test_via() {
+        use RetweetKind::*;
+        assert_eq!(parse("hello via @jack"), Some((Via, "jack", 0, 4, false)));
+        assert_eq!(
+            parse("hello (Via @jack) "),
+            Some((Via, "jack", 0, 4, false))
+        );
+        assert_eq!(parse(" hello via ＠jack"), Some((Via, "jack", 1, 5, false)));
+
+        assert_eq!(parse("hello via @jack today"), None);
+        assert_eq!(parse("trivia @jack"), None);
+        assert_eq!(parse("hello via @jack)"), None);
+    }
+
+    #[test]
+    fn test_truncated() {
+        let rt = parse_retweet_prefix("RT @jack: a long post 😀 that was cut …").unwrap();
+        assert_eq!(rt.body, Range::new(10, 36));
+        assert!(rt.truncated);
+        let rt = parse_retweet_prefix("a long post… via @jack").unwrap();
+        assert_eq!(rt.body, Range::new(0, 10));
+        assert!(rt.truncated);
+        let rt = parse_retweet_prefix("RT @jack: …").unwrap();
+        assert_eq!(rt.body, Range::new(10, 9));
+        assert!(rt.truncated);
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Checks for the Autolinker's strict mode.
+
+/// Attributes that strict mode writes, besides `data-*` attributes.
+const ALLOWED_ATTRIBUTES: &[&str] = &[
+    "alt",
+    "class",
+    "dir",
+    "draggable",
+    "href",
+    "id",
+    "lang",
+    "rel",
+    "src",
+    "target",
+    "title",
+];
+
+/// Returns true if strict mode writes an attribute named `name`.
+pub(crate) fn is_allowed_attribute(name: &str) -> bool {
+    if let Some(rest) = name.strip_prefix("data-") {
+        return !rest.is_empty()
+            && rest
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_');
+    }
+    ALLOWED_ATTRIBUTES.contains(&name)
+}
+
+/// Returns true if `href`, or an image `src`, is relative or uses http or
+/// https. Like browsers, ASCII whitespace and control characters are ignored
+/// when finding the scheme, so "java\tscript:" is caught.
+pub(crate) fn is_safe_href(href: impl Iterator<Item = char>) -> bool {
+    // The number of scheme characters seen, and whether they spell a prefix
+    // of "https".
+    let mut len = 0;
+    let mut http_prefix = true;
+    for c in href.filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control()) {
+        match c {
+            ':' => return http_prefix && (len == 4 || len == 5),
+            // A path, query or fragment before any ":" means a relative URL.
+            '/' | '?' | '#' => return true,
+            _ => {
+                http_prefix &= "https".chars().nth(len) == Some(c.to_ascii_lowercase());
+                len += 1;
+            }
+        }
+    }
+    true
+}
+
+/// Returns true if `tag` is an HTML tag name: an ASCII letter followed by
+/// letters, digits or hyphens.
+pub(crate) fn is_tag_name(tag: &str) -> bool {
+    let mut bytes = tag.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+/// Returns true if `attrs` is a list of `class` and `style` attributes with
+/// quoted values, like `style='position:absolute;left:-9999px;'`.
+pub(crate) fn is_safe_attribute_list(attrs: &str) -> bool {
+    let mut rest = attrs.trim();
+    while !rest.is_empty() {
+        let (name, value) = match rest.split_once('=') {
+            Some(parts) => parts,
+            None => return false,
+        };
+        if name != "class" && name != "style" {
+            return false;
+        }
+        let quote = match value.chars().next() {
+            Some(quote @ ('\'' | '"')) => quote,
+            _ => return false,
+        };
+        let end = match value[1..].find(quote) {
+            Some(end) => end + 1,
+            None => return false,
+        };
+        if value[1..end].contains(['<', '>', '&']) {
+            return false;
+        }
+        rest = &value[end + 1..];
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return false;
+        }
+        rest = rest.trim_start();
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_allowed_attribute() {
+        assert!(is_allowed_attribute("href"));
+        assert!(is_allowed_attribute("src"));
+        assert!(is_allowed_attribute("data-screen-name"));
+        assert!(!is_allowed_attribute("data-"));
+        assert!(!is_allowed_attribute("data-X"));
+        assert!(!is_allowed_attribute("onclick"));
+        assert!(!is_allowed_attribute("style"));
+    }
+
+    #[test]
+    fn test_is_safe_href() {
+        let safe = |href: &str| is_safe_href(href.chars());
+        assert!(safe("https://twitter.com/a"));
+        assert!(safe("HTTP://example.com"));
+        assert!(safe("/search?q=a:b"));
+        assert!(safe("example.com/a:b"));
+        assert!(safe(""));
+        assert!(!safe("javascript:alert(1)"));
+        assert!(!safe(" java\tscript:alert(1)"));
+        assert!(!safe("data:text/html,x"));
+        assert!(!safe("vbscript:x"));
+    }
+
+    #[test]
+    fn test_is_tag_name() {
+        assert!(is_tag_name("b"));
+        assert!(is_tag_name("my-tag1"));
+        assert!(!is_tag_name(""));
+        assert!(!is_tag_name("1b"));
+        assert!(!is_tag_name("b onclick=x"));
+        assert!(!is_tag_name("b>"));
+    }
+
+    #[test]
+    fn test_is_safe_attribute_list() {
+        assert!(is_safe_attribute_list(
+            "style='position:absolute;left:-9999px;'"
+        ));
+        assert!(is_safe_attribute_list("class=\"a b\" style='x'"));
+        assert!(!is_safe_attribute_list("onclick='x'"));
+        assert!(!is_safe_attribute_list("style='x'onclick='y'"));
+        assert!(!is_safe_attribute_list("style='x"));
+        assert!(!is_safe_attribute_list("style=x"));
+        assert!(!is_safe_attribute_list("style='</span>'"));
+    }
+}
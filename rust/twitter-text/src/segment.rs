@@ -0,0 +1,214 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Renderer-agnostic autolinker output.
+//!
+//! [Autolinker::segments](crate::autolinker::Autolinker::segments) splits text
+//! into [Segment]s: runs of plain text and links with their targets already
+//! built, for UI frameworks that create their own views instead of parsing
+//! HTML. Offsets are in UTF-16 code units, like [Entity](crate::entity::Entity)
+//! offsets.
+
+/// How a display URL sits inside its expanded URL, for t.co links.
+///
+/// `before`, `display` and `after` concatenate to the expanded URL. A renderer
+/// shows `display` between the optional ellipses, and can keep `before` and
+/// `after` in the copied text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DisplayUrlParts {
+    pub before: String,
+    pub display: String,
+    pub after: String,
+    pub leading_ellipsis: bool,
+    pub trailing_ellipsis: bool,
+}
+
+impl DisplayUrlParts {
+    /// Split `expanded_url` around `display_url` with its leading and trailing
+    /// ellipses removed.
+    /// Returns None if the display URL isn't part of the expanded URL, as for
+    /// pic.twitter.com links.
+    pub fn split(display_url: &str, expanded_url: &str) -> Option<DisplayUrlParts> {
+        let (before, display, after) = split_display_url(display_url, expanded_url)?;
+        Some(DisplayUrlParts {
+            before: before.to_string(),
+            display: display.to_string(),
+            after: after.to_string(),
+            leading_ellipsis: display_url.starts_with('…'),
+            trailing_ellipsis: display_url.ends_with('…'),
+        })
+    }
+}
+
+/// The borrowed `before`, `display` and `after` parts of [DisplayUrlParts::split].
+pub(crate) fn split_display_url<'u>(
+    display_url: &str,
+    expanded_url: &'u str,
+) -> Option<(&'u str, &'u str, &'u str)> {
+    let display = display_url.trim_matches('…');
+    let index = expanded_url.find(display)?;
+    let end = index + display.len();
+    Some((
+        &expanded_url[..index],
+        &expanded_url[index..end],
+        &expanded_url[end..],
+    ))
+}
+
+/// A piece of auto-linked text. Each segment carries the source `text` it
+/// covers and its `start` and `end` offsets in UTF-16 code units.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Segment {
+    /// Text that isn't linked, including URLs dropped by a domain policy and
+    /// federated mentions when there is no federated mention URL template.
+    Text { text: String, start: i32, end: i32 },
+    /// A #hashtag. `symbol` is the hash character used in the text; `rtl` is
+    /// set when the text contains right-to-left script.
+    Hashtag {
+        text: String,
+        start: i32,
+        end: i32,
+        symbol: char,
+        tag: String,
+        href: String,
+        rtl: bool,
+    },
+    /// A $cashtag.
+    Cashtag {
+        text: String,
+        start: i32,
+        end: i32,
+        tag: String,
+        href: String,
+    },
+    /// An @mention. When `include_symbol` is false, the link covers the screen
+    /// name and the symbol is shown before it.
+    Mention {
+        text: String,
+        start: i32,
+        end: i32,
+        symbol: char,
+        screen_name: String,
+        href: String,
+        include_symbol: bool,
+    },
+    /// An @user/list reference. `list_slug` includes the leading "/".
+    List {
+        text: String,
+        start: i32,
+        end: i32,
+        symbol: char,
+        screen_name: String,
+        list_slug: String,
+        href: String,
+        include_symbol: bool,
+    },
+    /// A Mastodon-style @user@domain mention.
+    FederatedMention {
+        text: String,
+        start: i32,
+        end: i32,
+        symbol: char,
+        user: String,
+        domain: String,
+        href: String,
+    },
+    /// A URL. `display` is the text to show and `expanded` the full URL;
+    /// both are the URL itself unless the entity has display and expanded
+    /// URLs. `display_parts` is set when the display URL can be located in
+    /// the expanded URL.
+    Url {
+        text: String,
+        start: i32,
+        end: i32,
+        href: String,
+        display: String,
+        expanded: String,
+        display_parts: Option<DisplayUrlParts>,
+    },
+}
+
+impl Segment {
+    /// The source text of the segment.
+    pub fn text(&self) -> &str {
+        match self {
+            Segment::Text { text, .. }
+            | Segment::Hashtag { text, .. }
+            | Segment::Cashtag { text, .. }
+            | Segment::Mention { text, .. }
+            | Segment::List { text, .. }
+            | Segment::FederatedMention { text, .. }
+            | Segment::Url { text, .. } => text,
+        }
+    }
+
+    /// The start of the segment, in UTF-16 code units.
+    pub fn start(&self) -> i32 {
+        match self {
+            Segment::Text { start, .. }
+            | Segment::Hashtag { start, .. }
+            | Segment::Cashtag { start, .. }
+            | Segment::Mention { start, .. }
+            | Segment::List { start, .. }
+            | Segment::FederatedMention { start, .. }
+            | Segment::Url { start, .. } => *start,
+        }
+    }
+
+    /// The end of the segment, in UTF-16 code units.
+    pub fn end(&self) -> i32 {
+        match self {
+            Segment::Text { end, .. }
+            | Segment::Hashtag { end, .. }
+            | Segment::Cashtag { end, .. }
+            | Segment::Mention { end, .. }
+            | Segment::List { end, .. }
+            | Segment::FederatedMention { end, .. }
+            | Segment::Url { end, .. } => *end,
+        }
+    }
+
+    /// The link target, or None for [Segment::Text].
+    pub fn href(&self) -> Option<&str> {
+        match self {
+            Segment::Text { .. } => None,
+            Segment::Hashtag { href, .. }
+            | Segment::Cashtag { href, .. }
+            | Segment::Mention { href, .. }
+            | Segment::List { href, .. }
+            | Segment::FederatedMention { href, .. }
+            | Segment::Url { href, .. } => Some(href),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_display_url() {
+        let parts = DisplayUrlParts::split(
+            "blog.twitter.com/2011/05/twitte…",
+            "http://blog.twitter.com/2011/05/twitter-for-mac-update.html",
+        )
+        .unwrap();
+        assert_eq!(parts.before, "http://");
+        assert_eq!(parts.display, "blog.twitter.com/2011/05/twitte");
+        assert_eq!(parts.after, "r-for-mac-update.html");
+        assert!(!parts.leading_ellipsis);
+        assert!(parts.trailing_ellipsis);
+
+        // Non-ASCII before the display URL.
+        let parts = DisplayUrlParts::split("…例え.jp/a", "https://www.例え.jp/a").unwrap();
+        assert_eq!(parts.before, "https://www.");
+        assert_eq!(parts.after, "");
+        assert!(parts.leading_ellipsis);
+
+        assert_eq!(
+            DisplayUrlParts::split("pic.twitter.com/xyz", "https://twitter.com/u/status/1"),
+            None
+        );
+    }
+}
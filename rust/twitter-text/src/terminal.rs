@@ -0,0 +1,394 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Terminal output for auto-linked entities.
+//!
+//! [TerminalAutolinker] finds the same entities as [Autolinker] and colors them
+//! with ANSI SGR sequences. Each entity can also be an OSC 8 hyperlink to the
+//! href the [Autolinker] would use, which supporting terminals make clickable.
+//! Control characters and bidi overrides in the text are removed, so it can't
+//! write escape sequences of its own or reorder the output, and styles only
+//! change at grapheme cluster boundaries, so emoji sequences and wide CJK
+//! characters stay whole.
+
+use crate::autolinker::{walk_entities, Autolinker};
+use crate::entity::{Entity, Type};
+use crate::extractor::Extract;
+use crate::grapheme::GraphemeBreaker;
+use crate::io_write::write_io;
+use std::fmt;
+use std::io;
+
+/**
+ * Default SGR parameters for hashtags: cyan
+ */
+pub const DEFAULT_HASHTAG_STYLE: &str = "36";
+
+/**
+ * Default SGR parameters for cashtags: green
+ */
+pub const DEFAULT_CASHTAG_STYLE: &str = "32";
+
+/**
+ * Default SGR parameters for mentions and lists: magenta
+ */
+pub const DEFAULT_MENTION_STYLE: &str = "35";
+
+/**
+ * Default SGR parameters for URLs: underlined blue
+ */
+pub const DEFAULT_URL_STYLE: &str = "4;34";
+
+/// Colors hashtag, cashtag, username, list and URL references in Tweet text
+/// for display in a terminal.
+pub struct TerminalAutolinker<'a> {
+    /// The link settings. HTML-only settings such as classes, `no_follow`,
+    /// symbol tags and the attribute modifier don't apply.
+    pub autolinker: Autolinker<'a>,
+    /// SGR parameters for hashtags, like "1;36". A style that is empty or has
+    /// characters other than digits and ";" leaves the entity unstyled.
+    pub hashtag_style: &'a str,
+    pub cashtag_style: &'a str,
+    /// SGR parameters for mentions, lists and federated mentions.
+    pub mention_style: &'a str,
+    pub url_style: &'a str,
+    /// When true, each entity is an OSC 8 hyperlink.
+    pub hyperlinks: bool,
+}
+
+impl Default for TerminalAutolinker<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> TerminalAutolinker<'a> {
+    /// A [TerminalAutolinker] with default properties.
+    pub fn new() -> TerminalAutolinker<'a> {
+        Self::with_autolinker(Autolinker::new(false))
+    }
+
+    /// A [TerminalAutolinker] that takes its link settings from `autolinker`.
+    pub fn with_autolinker(autolinker: Autolinker<'a>) -> TerminalAutolinker<'a> {
+        TerminalAutolinker {
+            autolinker,
+            hashtag_style: DEFAULT_HASHTAG_STYLE,
+            cashtag_style: DEFAULT_CASHTAG_STYLE,
+            mention_style: DEFAULT_MENTION_STYLE,
+            url_style: DEFAULT_URL_STYLE,
+            hyperlinks: true,
+        }
+    }
+
+    /// Style and link all entities.
+    pub fn autolink(&self, text: &str) -> String {
+        let mut buf = String::with_capacity(text.len() * 2);
+        // Writing to a String can't fail.
+        let _ = self.autolink_into(text, &mut buf);
+        buf
+    }
+
+    /// Style and link the given entities, whose offsets are in UTF-16 code units.
+    pub fn autolink_entities(&self, text: &str, entities: &[Entity]) -> String {
+        let mut buf = String::with_capacity(text.len() * 2);
+        let _ = self.autolink_entities_into(text, entities, &mut buf);
+        buf
+    }
+
+    /// Like [TerminalAutolinker::autolink], but writes the result to `w`.
+    pub fn autolink_into<W: fmt::Write>(&self, text: &str, w: &mut W) -> fmt::Result {
+        let entities = self
+            .autolinker
+            .extractor
+            .extract_entities_with_indices(text);
+        self.autolink_entities_into(text, &entities, w)
+    }
+
+    /// Like [TerminalAutolinker::autolink_entities], but writes the result to `w`.
+    pub fn autolink_entities_into<W: fmt::Write>(
+        &self,
+        text: &str,
+        entities: &[Entity],
+        w: &mut W,
+    ) -> fmt::Result {
+        let mut out = TerminalWriter::new(w);
+        walk_entities(text, entities, |piece, entity| match entity {
+            None => out.write(piece, None),
+            Some(entity) => self.write_entity(entity, piece, &mut out),
+        });
+        out.finish()
+    }
+
+    /// Like [TerminalAutolinker::autolink], but writes the result to `w` as UTF-8.
+    pub fn autolink_to_writer<W: io::Write>(&self, text: &str, w: &mut W) -> io::Result<()> {
+        write_io(w, |mut f| self.autolink_into(text, &mut f))
+    }
+
+    fn write_entity(&self, entity: &Entity, source: &str, out: &mut TerminalWriter<'_, 'a>) {
+        let autolinker = &self.autolinker;
+        let value = entity.get_value();
+        let mut text = source;
+        let (style, href) = match entity.get_type() {
+            Type::URL => {
+                if autolinker.drops_url(entity) {
+                    return out.write(source, None);
+                }
+                if !entity.get_display_url().is_empty() {
+                    text = entity.get_display_url();
+                }
+                (self.url_style, Some(value.to_string()))
+            }
+            Type::HASHTAG => (self.hashtag_style, Some(autolinker.hashtag_href(value))),
+            Type::CASHTAG => (self.cashtag_style, Some(autolinker.cashtag_href(value))),
+            Type::MENTION => (self.mention_style, Some(autolinker.mention_href(entity))),
+            Type::FEDERATEDMENTION => (
+                self.mention_style,
+                autolinker.federated_mention_href(entity),
+            ),
+        };
+        let span = Span {
+            style: if is_sgr_parameters(style) { style } else { "" },
+            href: href.filter(|_| self.hyperlinks),
+        };
+        match &autolinker.link_text_modifier {
+            Some(modifier) => out.write(&modifier.modify(entity, text), Some(span)),
+            None => out.write(text, Some(span)),
+        }
+    }
+}
+
+// The style and hyperlink of an entity's text.
+struct Span<'s> {
+    style: &'s str,
+    href: Option<String>,
+}
+
+// Writes text with spans, keeping the first write error. A span starts and ends
+// at the first grapheme cluster boundary at or after its text, so escape
+// sequences never split a cluster.
+struct TerminalWriter<'w, 's> {
+    w: &'w mut dyn fmt::Write,
+    breaker: GraphemeBreaker,
+    // The span for the text being written, if it hasn't started yet.
+    next: Option<Option<Span<'s>>>,
+    styled: bool,
+    linked: bool,
+    result: fmt::Result,
+}
+
+impl<'w, 's> TerminalWriter<'w, 's> {
+    fn new(w: &'w mut dyn fmt::Write) -> TerminalWriter<'w, 's> {
+        TerminalWriter {
+            w,
+            breaker: GraphemeBreaker::new(),
+            next: None,
+            styled: false,
+            linked: false,
+            result: Ok(()),
+        }
+    }
+
+    fn write(&mut self, text: &str, span: Option<Span<'s>>) {
+        self.next = Some(span);
+        for c in text.chars().filter(|&c| !is_stripped(c)) {
+            if self.breaker.is_boundary(c) {
+                if let Some(span) = self.next.take() {
+                    self.close();
+                    if let Some(span) = span {
+                        self.open(span);
+                    }
+                }
+            }
+            if self.result.is_ok() {
+                self.result = self.w.write_char(c);
+            }
+        }
+    }
+
+    fn finish(mut self) -> fmt::Result {
+        self.close();
+        self.result
+    }
+
+    fn open(&mut self, span: Span) {
+        if !span.style.is_empty() {
+            self.put("\x1b[");
+            self.put(span.style);
+            self.put("m");
+            self.styled = true;
+        }
+        if let Some(href) = span.href {
+            self.put("\x1b]8;;");
+            // OSC 8 URIs are printable ASCII, so other bytes are percent-encoded.
+            for b in href.bytes() {
+                if self.result.is_ok() {
+                    self.result = if (0x21..0x7f).contains(&b) {
+                        self.w.write_char(b as char)
+                    } else {
+                        write!(self.w, "%{:02X}", b)
+                    };
+                }
+            }
+            self.put("\x1b\\");
+            self.linked = true;
+        }
+    }
+
+    fn close(&mut self) {
+        if self.linked {
+            self.put("\x1b]8;;\x1b\\");
+            self.linked = false;
+        }
+        if self.styled {
+            self.put("\x1b[0m");
+            self.styled = false;
+        }
+    }
+
+    fn put(&mut self, s: &str) {
+        if self.result.is_ok() {
+            self.result = self.w.write_str(s);
+        }
+    }
+}
+
+// Control characters other than newline and tab could move the cursor or start
+// escape sequences, and bidi embeddings, overrides and isolates could make the
+// text after an entity display as part of it, so they are left out of the
+// output.
+fn is_stripped(c: char) -> bool {
+    (c.is_control() && c != '\n' && c != '\t')
+        || matches!(c, '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+}
+
+fn is_sgr_parameters(style: &str) -> bool {
+    !style.is_empty() && style.bytes().all(|b| b.is_ascii_digit() || b == b';')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::autolinker::LinkTextModifier;
+
+    #[test]
+    fn test_autolink() {
+        let linker = TerminalAutolinker::new();
+        assert_eq!(
+            linker.autolink("#rust by @bob"),
+            "\x1b[36m\x1b]8;;https://twitter.com/search?q=%23rust\x1b\\#rust\
+             \x1b]8;;\x1b\\\x1b[0m by \x1b[35m\x1b]8;;https://twitter.com/bob\x1b\\@bob\
+             \x1b]8;;\x1b\\\x1b[0m"
+        );
+        assert_eq!(linker.autolink("no entities"), "no entities");
+    }
+
+    #[test]
+    fn test_styles_and_hyperlinks() {
+        let mut linker = TerminalAutolinker::new();
+        linker.hyperlinks = false;
+        linker.cashtag_style = "1;32";
+        linker.url_style = "4m\x1b[5";
+        assert_eq!(
+            linker.autolink("$TWTR https://example.com"),
+            "\x1b[1;32m$TWTR\x1b[0m https://example.com"
+        );
+
+        linker.hyperlinks = true;
+        linker.autolinker.hashtag_url_base = "https://example.social/tags/";
+        linker.hashtag_style = "";
+        assert_eq!(
+            linker.autolink("#tag"),
+            "\x1b]8;;https://example.social/tags/tag\x1b\\#tag\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_urls() {
+        let linker = TerminalAutolinker::new();
+        let entity = Entity {
+            t: Type::URL,
+            start: 0,
+            end: 19,
+            value: "http://t.co/0JG5Mcq",
+            list_slug: "",
+            display_url: "例え.jp/a…",
+            expanded_url: "http://例え.jp/abc",
+            host_span: None,
+        };
+        assert_eq!(
+            linker.autolink_entities("http://t.co/0JG5Mcq", &[entity]),
+            "\x1b[4;34m\x1b]8;;http://t.co/0JG5Mcq\x1b\\例え.jp/a…\x1b]8;;\x1b\\\x1b[0m"
+        );
+
+        // URIs are percent-encoded to printable ASCII.
+        let text = "http://例え.jp/a b";
+        let entity = Entity::new(Type::URL, text, 0, 17);
+        assert_eq!(
+            linker.autolink_entities(text, &[entity]),
+            "\x1b[4;34m\x1b]8;;http://%E4%BE%8B%E3%81%88.jp/a%20b\x1b\\http://例え.jp/a b\
+             \x1b]8;;\x1b\\\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_control_characters() {
+        let mut linker = TerminalAutolinker::new();
+        linker.hyperlinks = false;
+        assert_eq!(
+            linker.autolink("a\x1b[2J\rb\u{7}\u{9b}c\n\td #x"),
+            "a[2Jbc\n\td \x1b[36m#x\x1b[0m"
+        );
+
+        struct Escape;
+        impl LinkTextModifier for Escape {
+            fn modify(&self, _entity: &Entity, text: &str) -> String {
+                format!("\x1b[31m{}", text)
+            }
+        }
+        linker.autolinker.link_text_modifier = Some(Box::new(Escape));
+        assert_eq!(linker.autolink("#x"), "\x1b[36m[31m#x\x1b[0m");
+    }
+
+    #[test]
+    fn test_bidi_controls() {
+        let mut linker = TerminalAutolinker::new();
+        linker.hyperlinks = false;
+        assert_eq!(
+            linker.autolink("a\u{202E}b\u{202A}c\u{2066}d\u{2069} #x\u{2067}y\u{200F}"),
+            "abcd \x1b[36m#x\x1b[0my\u{200F}"
+        );
+    }
+
+    #[test]
+    fn test_grapheme_clusters() {
+        let mut linker = TerminalAutolinker::new();
+        linker.hyperlinks = false;
+        assert_eq!(linker.autolink("#日本語 🇯🇵"), "\x1b[36m#日本語\x1b[0m 🇯🇵");
+
+        // A skin tone modifier after an entity stays in the entity's span.
+        let text = "👍🏽 ok";
+        let entity = Entity::new(Type::HASHTAG, "👍", 0, 2);
+        assert_eq!(
+            linker.autolink_entities(text, &[entity]),
+            "\x1b[36m👍🏽\x1b[0m ok"
+        );
+
+        // A span that would start inside a cluster starts at the next one.
+        let text = "e\u{301}@bob";
+        let entity = Entity::new(Type::MENTION, "bob", 1, 6);
+        assert_eq!(
+            linker.autolink_entities(text, &[entity]),
+            "e\u{301}\x1b[35m@bob\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_autolink_to_writer() {
+        let mut linker = TerminalAutolinker::new();
+        linker.hyperlinks = false;
+        let mut out = Vec::new();
+        linker.autolink_to_writer("#日本", &mut out).unwrap();
+        assert_eq!(out, "\x1b[36m#日本\x1b[0m".as_bytes());
+    }
+}
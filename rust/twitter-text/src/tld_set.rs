@@ -0,0 +1,455 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Runtime-loadable TLD sets.
+//!
+//! By default, TLD validation uses the compile-time phf set in [crate::tlds].
+//! A [TldSet] can instead be loaded at runtime from the `tld_lib.yml` format
+//! used by `update_tlds.sh`, or from the IANA plain-text list
+//! (<https://data.iana.org/TLD/tlds-alpha-by-domain.txt>), so new delegations
+//! don't require a crate release.
+
+use crate::tlds::{tld_kind, TldKind};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Maximum TLD length in bytes for the stack-allocated lowercase buffer.
+const MAX_TLD_LEN: usize = 64;
+
+/// A set of valid top-level domains.
+///
+/// A set can also carry an allowlist of private TLDs (such as `corp` or `lan`)
+/// and a denylist of TLDs to reject (such as `zip`), which take precedence over
+/// the base list.
+///
+/// Cloning is cheap: loaded sets are reference-counted.
+#[derive(Debug, Clone, Default)]
+pub struct TldSet {
+    custom: Option<Arc<HashMap<String, TldKind>>>,
+    allowed: Option<Arc<HashMap<String, TldKind>>>,
+    denied: Option<Arc<HashSet<String>>>,
+}
+
+/// An error encountered while loading a [TldSet].
+#[derive(Debug)]
+pub enum TldSetError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The input could not be parsed. `line` is 1-based.
+    Parse { line: usize, message: String },
+    /// The input parsed, but contained no TLDs.
+    Empty,
+}
+
+impl fmt::Display for TldSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TldSetError::Io(e) => write!(f, "could not read TLD list: {}", e),
+            TldSetError::Parse { line, message } => {
+                write!(f, "invalid TLD list at line {}: {}", line, message)
+            }
+            TldSetError::Empty => write!(f, "TLD list is empty"),
+        }
+    }
+}
+
+impl std::error::Error for TldSetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TldSetError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for TldSetError {
+    fn from(e: io::Error) -> Self {
+        TldSetError::Io(e)
+    }
+}
+
+impl TldSet {
+    /// The built-in TLD set generated from `tld_lib.yml`.
+    pub const fn builtin() -> TldSet {
+        TldSet {
+            custom: None,
+            allowed: None,
+            denied: None,
+        }
+    }
+
+    /// Returns true if this is the built-in compile-time set.
+    pub fn is_builtin(&self) -> bool {
+        self.custom.is_none()
+    }
+
+    // Returns true if this is the built-in set with no allowlist or denylist.
+    pub(crate) fn is_unmodified_builtin(&self) -> bool {
+        self.custom.is_none() && self.allowed.is_none() && self.denied.is_none()
+    }
+
+    /// Parse the `tld_lib.yml` format: top-level category keys, each holding a
+    /// list of TLDs. Unicode TLDs are added along with their punycode form.
+    ///
+    /// TLDs under `country` are [TldKind::Country] and TLDs under `sponsored`
+    /// are [TldKind::Sponsored]. Other categories are [TldKind::Generic], unless
+    /// the built-in table knows the TLD as sponsored.
+    pub fn from_yaml(input: &str) -> Result<TldSet, TldSetError> {
+        let mut tlds = HashMap::new();
+        let mut category = None;
+        for (i, raw) in input.lines().enumerate() {
+            let line = strip_comment(raw).trim_end();
+            if line.trim().is_empty() || line == "---" {
+                continue;
+            }
+            let trimmed = line.trim_start();
+            if let Some(item) = trimmed.strip_prefix('-') {
+                let category = match category {
+                    Some(category) => category,
+                    None => return Err(parse_error(i, "list item outside of a category")),
+                };
+                let tld = unquote(item.trim());
+                if tld.is_empty() {
+                    return Err(parse_error(i, "empty TLD"));
+                }
+                let kind = match (category, tld_kind(tld)) {
+                    (TldKind::Generic, Some(TldKind::Sponsored)) => TldKind::Sponsored,
+                    (kind, _) => kind,
+                };
+                insert_tld(&mut tlds, tld, kind).map_err(|m| parse_error(i, &m))?;
+            } else if trimmed.len() == line.len() && trimmed.ends_with(':') {
+                category = Some(match &trimmed[..trimmed.len() - 1] {
+                    "country" => TldKind::Country,
+                    "sponsored" => TldKind::Sponsored,
+                    _ => TldKind::Generic,
+                });
+            } else {
+                return Err(parse_error(i, "expected a category key or a list item"));
+            }
+        }
+        Self::from_set(tlds)
+    }
+
+    /// Parse the IANA plain-text list: one TLD per line, `#` comment lines.
+    ///
+    /// The list has no categories, so each TLD takes its kind from the built-in
+    /// table. New two-letter TLDs are [TldKind::Country], others [TldKind::Generic].
+    pub fn from_iana(input: &str) -> Result<TldSet, TldSetError> {
+        let mut tlds = HashMap::new();
+        for (i, raw) in input.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.contains(char::is_whitespace) || line.contains('.') {
+                return Err(parse_error(i, "expected a single TLD per line"));
+            }
+            insert_tld(&mut tlds, line, infer_kind(line)).map_err(|m| parse_error(i, &m))?;
+        }
+        Self::from_set(tlds)
+    }
+
+    /// Load a `tld_lib.yml`-format file.
+    pub fn from_yaml_path<P: AsRef<Path>>(path: P) -> Result<TldSet, TldSetError> {
+        Self::from_yaml(&fs::read_to_string(path)?)
+    }
+
+    /// Load an IANA plain-text list file.
+    pub fn from_iana_path<P: AsRef<Path>>(path: P) -> Result<TldSet, TldSetError> {
+        Self::from_iana(&fs::read_to_string(path)?)
+    }
+
+    /// Build a set from an iterator of TLDs. Entries are lowercased, and both
+    /// the Unicode and punycode forms of IDN TLDs are added. Kinds are
+    /// assigned as in [TldSet::from_iana].
+    pub fn from_tlds<I, S>(tlds: I) -> Result<TldSet, TldSetError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::from_set(kind_map(tlds)?)
+    }
+
+    fn from_set(tlds: HashMap<String, TldKind>) -> Result<TldSet, TldSetError> {
+        if tlds.is_empty() {
+            return Err(TldSetError::Empty);
+        }
+        Ok(TldSet {
+            custom: Some(Arc::new(tlds)),
+            allowed: None,
+            denied: None,
+        })
+    }
+
+    /// Accept these TLDs in addition to the base list, e.g. `corp`,
+    /// `internal` and `lan` for internal deployments. Replaces any previous
+    /// allowlist. Kinds are assigned as in [TldSet::from_iana].
+    pub fn with_allowed<I, S>(mut self, tlds: I) -> Result<TldSet, TldSetError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let allowed = kind_map(tlds)?;
+        self.allowed = if allowed.is_empty() {
+            None
+        } else {
+            Some(Arc::new(allowed))
+        };
+        Ok(self)
+    }
+
+    /// Reject these TLDs even if they are in the base list or the allowlist,
+    /// e.g. `zip` and `mov`, which produce false positives on filenames.
+    /// Replaces any previous denylist.
+    pub fn with_denied<I, S>(mut self, tlds: I) -> Result<TldSet, TldSetError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let denied: HashSet<String> = kind_map(tlds)?.into_keys().collect();
+        self.denied = if denied.is_empty() {
+            None
+        } else {
+            Some(Arc::new(denied))
+        };
+        Ok(self)
+    }
+
+    /// Check if a string is a TLD in this set, case-insensitively.
+    #[inline]
+    pub fn contains(&self, tld: &str) -> bool {
+        self.kind(tld).is_some()
+    }
+
+    /// Look up the category of a TLD in this set, case-insensitively.
+    /// Returns None if the string is not a TLD in this set.
+    #[inline]
+    pub fn kind(&self, tld: &str) -> Option<TldKind> {
+        if self.allowed.is_none() && self.denied.is_none() {
+            return match &self.custom {
+                None => tld_kind(tld),
+                Some(map) => with_lowercase(tld, |lowered| map.get(lowered).copied()),
+            };
+        }
+        with_lowercase(tld, |lowered| {
+            if self.denied.as_ref().is_some_and(|d| d.contains(lowered)) {
+                return None;
+            }
+            if let Some(kind) = self.allowed.as_ref().and_then(|a| a.get(lowered)) {
+                return Some(*kind);
+            }
+            match &self.custom {
+                None => crate::tlds::TLDS.get(lowered).copied(),
+                Some(map) => map.get(lowered).copied(),
+            }
+        })
+    }
+
+    /// Check if a TLD is on this set's denylist, case-insensitively.
+    pub fn is_denied(&self, tld: &str) -> bool {
+        match &self.denied {
+            None => false,
+            Some(denied) => with_lowercase(tld, |lowered| denied.contains(lowered)),
+        }
+    }
+
+    /// The number of entries in the base list, counting both forms of IDN TLDs.
+    pub fn len(&self) -> usize {
+        match &self.custom {
+            None => crate::tlds::TLDS.len(),
+            Some(set) => set.len(),
+        }
+    }
+
+    /// Returns true if the set has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn parse_error(index: usize, message: &str) -> TldSetError {
+    TldSetError::Parse {
+        line: index + 1,
+        message: message.to_string(),
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    match line.find(" #") {
+        Some(pos) => &line[..pos],
+        None if line.trim_start().starts_with('#') => "",
+        None => line,
+    }
+}
+
+fn unquote(s: &str) -> &str {
+    for q in ['"', '\''] {
+        if s.len() >= 2 && s.starts_with(q) && s.ends_with(q) {
+            return &s[1..s.len() - 1];
+        }
+    }
+    s
+}
+
+/// The kind of a TLD from an uncategorized list.
+fn infer_kind(tld: &str) -> TldKind {
+    tld_kind(tld).unwrap_or_else(|| {
+        if tld.len() == 2 && tld.bytes().all(|b| b.is_ascii_alphabetic()) {
+            TldKind::Country
+        } else {
+            TldKind::Generic
+        }
+    })
+}
+
+fn kind_map<I, S>(tlds: I) -> Result<HashMap<String, TldKind>, TldSetError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut map = HashMap::new();
+    for (i, tld) in tlds.into_iter().enumerate() {
+        let tld = tld.as_ref();
+        insert_tld(&mut map, tld, infer_kind(tld)).map_err(|m| parse_error(i, &m))?;
+    }
+    Ok(map)
+}
+
+/// Insert a TLD in lowercase, along with its alternate IDN form. IDN ccTLDs
+/// appear in more than one category, and the country category wins.
+fn insert_tld(tlds: &mut HashMap<String, TldKind>, tld: &str, kind: TldKind) -> Result<(), String> {
+    let mut insert = |key: String| {
+        let entry = tlds.entry(key).or_insert(kind);
+        if kind == TldKind::Country {
+            *entry = kind;
+        }
+    };
+    let lowered = tld.to_lowercase();
+    if lowered.contains('.') || lowered.contains(char::is_whitespace) {
+        return Err(format!("invalid TLD '{}'", tld));
+    }
+    if lowered.starts_with("xn--") {
+        let (unicode, result) = idna::domain_to_unicode(&lowered);
+        if result.is_err() {
+            return Err(format!("invalid punycode TLD '{}'", tld));
+        }
+        insert(unicode);
+    } else if !lowered.is_ascii() {
+        match idna::domain_to_ascii(&lowered) {
+            Ok(ascii) => insert(ascii),
+            Err(_) => return Err(format!("invalid IDN TLD '{}'", tld)),
+        }
+    }
+    insert(lowered);
+    Ok(())
+}
+
+/// Lowercase `s` into a stack buffer when possible and pass it to `f`.
+#[inline]
+fn with_lowercase<T, F: Fn(&str) -> T>(s: &str, f: F) -> T {
+    if s.bytes().all(|b| !b.is_ascii_uppercase()) && s.is_ascii() {
+        return f(s);
+    }
+    if s.len() <= MAX_TLD_LEN && s.is_ascii() {
+        let mut buf = [0u8; MAX_TLD_LEN];
+        for (i, b) in s.bytes().enumerate() {
+            buf[i] = b.to_ascii_lowercase();
+        }
+        // Safety: lowercasing ASCII bytes keeps them ASCII
+        let lowered = unsafe { std::str::from_utf8_unchecked(&buf[..s.len()]) };
+        return f(lowered);
+    }
+    f(&s.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_is_default() {
+        let tlds = TldSet::default();
+        assert!(tlds.is_builtin());
+        assert!(tlds.contains("com"));
+        assert!(tlds.contains("COM"));
+        assert!(!tlds.contains("notarealtld"));
+        assert_eq!(tlds.len(), crate::tlds::TLDS.len());
+    }
+
+    #[test]
+    fn test_from_yaml() {
+        let yaml = "---\ncountry:\n- 한국\n- uk # United Kingdom\ngeneric:\n- com\n- newgtld\n";
+        let tlds = TldSet::from_yaml(yaml).unwrap();
+        assert!(tlds.contains("newgtld"));
+        assert!(tlds.contains("NewGTLD"));
+        assert!(tlds.contains("uk"));
+        assert!(tlds.contains("한국"));
+        assert!(tlds.contains("xn--3e0b707e"));
+        assert!(!tlds.contains("org"));
+        assert_eq!(tlds.kind("uk"), Some(TldKind::Country));
+        assert_eq!(tlds.kind("xn--3e0b707e"), Some(TldKind::Country));
+        assert_eq!(tlds.kind("newgtld"), Some(TldKind::Generic));
+    }
+
+    #[test]
+    fn test_from_yaml_errors() {
+        assert!(matches!(
+            TldSet::from_yaml("- com\n"),
+            Err(TldSetError::Parse { line: 1, .. })
+        ));
+        assert!(matches!(
+            TldSet::from_yaml("generic:\n- com\nbogus\n"),
+            Err(TldSetError::Parse { line: 3, .. })
+        ));
+        assert!(matches!(
+            TldSet::from_yaml("---\n"),
+            Err(TldSetError::Empty)
+        ));
+    }
+
+    #[test]
+    fn test_from_iana() {
+        let list = "# Version 2025010100, Last Updated Wed Jan  1 07:07:01 2025 UTC\nCOM\nNEWGTLD\nXN--P1AI\n";
+        let tlds = TldSet::from_iana(list).unwrap();
+        assert_eq!(tlds.len(), 4);
+        assert!(tlds.contains("com"));
+        assert!(tlds.contains("newgtld"));
+        assert!(tlds.contains("xn--p1ai"));
+        assert!(tlds.contains("рф"));
+        assert!(tlds.contains("РФ"));
+        assert!(!tlds.contains("net"));
+        assert_eq!(tlds.kind("rf"), None);
+        assert_eq!(tlds.kind("рф"), Some(TldKind::Country));
+        assert_eq!(tlds.kind("com"), Some(TldKind::Generic));
+    }
+
+    #[test]
+    fn test_allowed_and_denied() {
+        let tlds = TldSet::default()
+            .with_allowed(["corp", "Internal", "lan"])
+            .unwrap()
+            .with_denied(["zip", "MOV", "lan"])
+            .unwrap();
+        assert!(tlds.contains("corp"));
+        assert_eq!(tlds.kind("corp"), Some(TldKind::Generic));
+        assert!(tlds.contains("INTERNAL"));
+        assert!(tlds.contains("com"));
+        assert!(!tlds.contains("zip"));
+        assert!(!tlds.contains("Mov"));
+        assert!(!tlds.contains("lan"));
+        assert!(tlds.is_denied("ZIP"));
+        assert!(!tlds.is_denied("corp"));
+        assert!(TldSet::default().contains("zip"));
+        assert!(TldSet::default().with_allowed(["bad.tld"]).is_err());
+    }
+
+    #[test]
+    fn test_from_iana_rejects_domains() {
+        assert!(TldSet::from_iana("example.com\n").is_err());
+    }
+}
@@ -10,1758 +10,1770 @@
 //! This file is generated by tld_gen_phf.py from tld_lib.yml.
 //! Do not edit by hand.
 
-use phf::phf_set;
+use phf::phf_map;
 
-/// A compile-time perfect hash set of all valid TLDs.
+/// The category of a TLD, following the groups in tld_lib.yml and the
+/// IANA Root Zone Database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TldKind {
+    /// Country-code TLDs, including IDN ccTLDs such as "рф".
+    Country,
+    /// Generic TLDs.
+    Generic,
+    /// Sponsored generic TLDs, such as "edu" and "gov".
+    Sponsored,
+}
+
+/// A compile-time perfect hash map of all valid TLDs to their category.
 /// Case-insensitive matching is handled by lowercasing input before lookup.
-pub static TLDS: phf::Set<&'static str> = phf_set! {
-    "aaa",
-    "aarp",
-    "abarth",
-    "abb",
-    "abbott",
-    "abbvie",
-    "abc",
-    "able",
-    "abogado",
-    "abudhabi",
-    "ac",
-    "academy",
-    "accenture",
-    "accountant",
-    "accountants",
-    "aco",
-    "active",
-    "actor",
-    "ad",
-    "adac",
-    "ads",
-    "adult",
-    "ae",
-    "aeg",
-    "aero",
-    "aetna",
-    "af",
-    "afamilycompany",
-    "afl",
-    "africa",
-    "ag",
-    "agakhan",
-    "agency",
-    "ai",
-    "aig",
-    "aigo",
-    "airbus",
-    "airforce",
-    "airtel",
-    "akdn",
-    "al",
-    "alfaromeo",
-    "alibaba",
-    "alipay",
-    "allfinanz",
-    "allstate",
-    "ally",
-    "alsace",
-    "alstom",
-    "am",
-    "amazon",
-    "americanexpress",
-    "americanfamily",
-    "amex",
-    "amfam",
-    "amica",
-    "amsterdam",
-    "an",
-    "analytics",
-    "android",
-    "anquan",
-    "anz",
-    "ao",
-    "aol",
-    "apartments",
-    "app",
-    "apple",
-    "aq",
-    "aquarelle",
-    "ar",
-    "arab",
-    "aramco",
-    "archi",
-    "army",
-    "arpa",
-    "art",
-    "arte",
-    "as",
-    "asda",
-    "asia",
-    "associates",
-    "at",
-    "athleta",
-    "attorney",
-    "au",
-    "auction",
-    "audi",
-    "audible",
-    "audio",
-    "auspost",
-    "author",
-    "auto",
-    "autos",
-    "avianca",
-    "aw",
-    "aws",
-    "ax",
-    "axa",
-    "az",
-    "azure",
-    "ba",
-    "baby",
-    "baidu",
-    "banamex",
-    "bananarepublic",
-    "band",
-    "bank",
-    "bar",
-    "barcelona",
-    "barclaycard",
-    "barclays",
-    "barefoot",
-    "bargains",
-    "baseball",
-    "basketball",
-    "bauhaus",
-    "bayern",
-    "bb",
-    "bbc",
-    "bbt",
-    "bbva",
-    "bcg",
-    "bcn",
-    "bd",
-    "be",
-    "beats",
-    "beauty",
-    "beer",
-    "bentley",
-    "berlin",
-    "best",
-    "bestbuy",
-    "bet",
-    "bf",
-    "bg",
-    "bh",
-    "bharti",
-    "bi",
-    "bible",
-    "bid",
-    "bike",
-    "bing",
-    "bingo",
-    "bio",
-    "biz",
-    "bj",
-    "bl",
-    "black",
-    "blackfriday",
-    "blanco",
-    "blockbuster",
-    "blog",
-    "bloomberg",
-    "blue",
-    "bm",
-    "bms",
-    "bmw",
-    "bn",
-    "bnl",
-    "bnpparibas",
-    "bo",
-    "boats",
-    "boehringer",
-    "bofa",
-    "bom",
-    "bond",
-    "boo",
-    "book",
-    "booking",
-    "boots",
-    "bosch",
-    "bostik",
-    "boston",
-    "bot",
-    "boutique",
-    "box",
-    "bq",
-    "br",
-    "bradesco",
-    "bridgestone",
-    "broadway",
-    "broker",
-    "brother",
-    "brussels",
-    "bs",
-    "bt",
-    "budapest",
-    "bugatti",
-    "build",
-    "builders",
-    "business",
-    "buy",
-    "buzz",
-    "bv",
-    "bw",
-    "by",
-    "bz",
-    "bzh",
-    "ca",
-    "cab",
-    "cafe",
-    "cal",
-    "call",
-    "calvinklein",
-    "cam",
-    "camera",
-    "camp",
-    "cancerresearch",
-    "canon",
-    "capetown",
-    "capital",
-    "capitalone",
-    "car",
-    "caravan",
-    "cards",
-    "care",
-    "career",
-    "careers",
-    "cars",
-    "cartier",
-    "casa",
-    "case",
-    "caseih",
-    "cash",
-    "casino",
-    "cat",
-    "catering",
-    "catholic",
-    "cba",
-    "cbn",
-    "cbre",
-    "cbs",
-    "cc",
-    "cd",
-    "ceb",
-    "center",
-    "ceo",
-    "cern",
-    "cf",
-    "cfa",
-    "cfd",
-    "cg",
-    "ch",
-    "chanel",
-    "channel",
-    "charity",
-    "chase",
-    "chat",
-    "cheap",
-    "chintai",
-    "chloe",
-    "christmas",
-    "chrome",
-    "chrysler",
-    "church",
-    "ci",
-    "cipriani",
-    "circle",
-    "cisco",
-    "citadel",
-    "citi",
-    "citic",
-    "city",
-    "cityeats",
-    "ck",
-    "cl",
-    "claims",
-    "cleaning",
-    "click",
-    "clinic",
-    "clinique",
-    "clothing",
-    "cloud",
-    "club",
-    "clubmed",
-    "cm",
-    "cn",
-    "co",
-    "coach",
-    "codes",
-    "coffee",
-    "college",
-    "cologne",
-    "com",
-    "comcast",
-    "commbank",
-    "community",
-    "company",
-    "compare",
-    "computer",
-    "comsec",
-    "condos",
-    "construction",
-    "consulting",
-    "contact",
-    "contractors",
-    "cooking",
-    "cookingchannel",
-    "cool",
-    "coop",
-    "corsica",
-    "country",
-    "coupon",
-    "coupons",
-    "courses",
-    "cpa",
-    "cr",
-    "credit",
-    "creditcard",
-    "creditunion",
-    "cricket",
-    "crown",
-    "crs",
-    "cruise",
-    "cruises",
-    "csc",
-    "cu",
-    "cuisinella",
-    "cv",
-    "cw",
-    "cx",
-    "cy",
-    "cymru",
-    "cyou",
-    "cz",
-    "dabur",
-    "dad",
-    "dance",
-    "data",
-    "date",
-    "dating",
-    "datsun",
-    "day",
-    "dclk",
-    "dds",
-    "de",
-    "deal",
-    "dealer",
-    "deals",
-    "degree",
-    "delivery",
-    "dell",
-    "deloitte",
-    "delta",
-    "democrat",
-    "dental",
-    "dentist",
-    "desi",
-    "design",
-    "dev",
-    "dhl",
-    "diamonds",
-    "diet",
-    "digital",
-    "direct",
-    "directory",
-    "discount",
-    "discover",
-    "dish",
-    "diy",
-    "dj",
-    "dk",
-    "dm",
-    "dnp",
-    "do",
-    "docs",
-    "doctor",
-    "dodge",
-    "dog",
-    "doha",
-    "domains",
-    "doosan",
-    "dot",
-    "download",
-    "drive",
-    "dtv",
-    "dubai",
-    "duck",
-    "dunlop",
-    "duns",
-    "dupont",
-    "durban",
-    "dvag",
-    "dvr",
-    "dz",
-    "earth",
-    "eat",
-    "ec",
-    "eco",
-    "edeka",
-    "edu",
-    "education",
-    "ee",
-    "eg",
-    "eh",
-    "email",
-    "emerck",
-    "energy",
-    "engineer",
-    "engineering",
-    "enterprises",
-    "epost",
-    "epson",
-    "equipment",
-    "er",
-    "ericsson",
-    "erni",
-    "es",
-    "esq",
-    "estate",
-    "esurance",
-    "et",
-    "etisalat",
-    "eu",
-    "eurovision",
-    "eus",
-    "events",
-    "everbank",
-    "exchange",
-    "expert",
-    "exposed",
-    "express",
-    "extraspace",
-    "fage",
-    "fail",
-    "fairwinds",
-    "faith",
-    "family",
-    "fan",
-    "fans",
-    "farm",
-    "farmers",
-    "fashion",
-    "fast",
-    "fedex",
-    "feedback",
-    "ferrari",
-    "ferrero",
-    "fi",
-    "fiat",
-    "fidelity",
-    "fido",
-    "film",
-    "final",
-    "finance",
-    "financial",
-    "fire",
-    "firestone",
-    "firmdale",
-    "fish",
-    "fishing",
-    "fit",
-    "fitness",
-    "fj",
-    "fk",
-    "flickr",
-    "flights",
-    "flir",
-    "florist",
-    "flowers",
-    "flsmidth",
-    "fly",
-    "fm",
-    "fo",
-    "foo",
-    "food",
-    "foodnetwork",
-    "football",
-    "ford",
-    "forex",
-    "forsale",
-    "forum",
-    "foundation",
-    "fox",
-    "fr",
-    "free",
-    "fresenius",
-    "frl",
-    "frogans",
-    "frontdoor",
-    "frontier",
-    "ftr",
-    "fujitsu",
-    "fujixerox",
-    "fun",
-    "fund",
-    "furniture",
-    "futbol",
-    "fyi",
-    "ga",
-    "gal",
-    "gallery",
-    "gallo",
-    "gallup",
-    "game",
-    "games",
-    "gap",
-    "garden",
-    "gay",
-    "gb",
-    "gbiz",
-    "gd",
-    "gdn",
-    "ge",
-    "gea",
-    "gent",
-    "genting",
-    "george",
-    "gf",
-    "gg",
-    "ggee",
-    "gh",
-    "gi",
-    "gift",
-    "gifts",
-    "gives",
-    "giving",
-    "gl",
-    "glade",
-    "glass",
-    "gle",
-    "global",
-    "globo",
-    "gm",
-    "gmail",
-    "gmbh",
-    "gmo",
-    "gmx",
-    "gn",
-    "godaddy",
-    "gold",
-    "goldpoint",
-    "golf",
-    "goo",
-    "goodhands",
-    "goodyear",
-    "goog",
-    "google",
-    "gop",
-    "got",
-    "gov",
-    "gp",
-    "gq",
-    "gr",
-    "grainger",
-    "graphics",
-    "gratis",
-    "green",
-    "gripe",
-    "grocery",
-    "group",
-    "gs",
-    "gt",
-    "gu",
-    "guardian",
-    "gucci",
-    "guge",
-    "guide",
-    "guitars",
-    "guru",
-    "gw",
-    "gy",
-    "hair",
-    "hamburg",
-    "hangout",
-    "haus",
-    "hbo",
-    "hdfc",
-    "hdfcbank",
-    "health",
-    "healthcare",
-    "help",
-    "helsinki",
-    "here",
-    "hermes",
-    "hgtv",
-    "hiphop",
-    "hisamitsu",
-    "hitachi",
-    "hiv",
-    "hk",
-    "hkt",
-    "hm",
-    "hn",
-    "hockey",
-    "holdings",
-    "holiday",
-    "homedepot",
-    "homegoods",
-    "homes",
-    "homesense",
-    "honda",
-    "honeywell",
-    "horse",
-    "hospital",
-    "host",
-    "hosting",
-    "hot",
-    "hoteles",
-    "hotels",
-    "hotmail",
-    "house",
-    "how",
-    "hr",
-    "hsbc",
-    "ht",
-    "htc",
-    "hu",
-    "hughes",
-    "hyatt",
-    "hyundai",
-    "ibm",
-    "icbc",
-    "ice",
-    "icu",
-    "id",
-    "ie",
-    "ieee",
-    "ifm",
-    "iinet",
-    "ikano",
-    "il",
-    "im",
-    "imamat",
-    "imdb",
-    "immo",
-    "immobilien",
-    "in",
-    "inc",
-    "industries",
-    "infiniti",
-    "info",
-    "ing",
-    "ink",
-    "institute",
-    "insurance",
-    "insure",
-    "int",
-    "intel",
-    "international",
-    "intuit",
-    "investments",
-    "io",
-    "ipiranga",
-    "iq",
-    "ir",
-    "irish",
-    "is",
-    "iselect",
-    "ismaili",
-    "ist",
-    "istanbul",
-    "it",
-    "itau",
-    "itv",
-    "iveco",
-    "iwc",
-    "jaguar",
-    "java",
-    "jcb",
-    "jcp",
-    "je",
-    "jeep",
-    "jetzt",
-    "jewelry",
-    "jio",
-    "jlc",
-    "jll",
-    "jm",
-    "jmp",
-    "jnj",
-    "jo",
-    "jobs",
-    "joburg",
-    "jot",
-    "joy",
-    "jp",
-    "jpmorgan",
-    "jprs",
-    "juegos",
-    "juniper",
-    "kaufen",
-    "kddi",
-    "ke",
-    "kerryhotels",
-    "kerrylogistics",
-    "kerryproperties",
-    "kfh",
-    "kg",
-    "kh",
-    "ki",
-    "kia",
-    "kids",
-    "kim",
-    "kinder",
-    "kindle",
-    "kitchen",
-    "kiwi",
-    "km",
-    "kn",
-    "koeln",
-    "komatsu",
-    "kosher",
-    "kp",
-    "kpmg",
-    "kpn",
-    "kr",
-    "krd",
-    "kred",
-    "kuokgroup",
-    "kw",
-    "ky",
-    "kyoto",
-    "kz",
-    "la",
-    "lacaixa",
-    "ladbrokes",
-    "lamborghini",
-    "lamer",
-    "lancaster",
-    "lancia",
-    "lancome",
-    "land",
-    "landrover",
-    "lanxess",
-    "lasalle",
-    "lat",
-    "latino",
-    "latrobe",
-    "law",
-    "lawyer",
-    "lb",
-    "lc",
-    "lds",
-    "lease",
-    "leclerc",
-    "lefrak",
-    "legal",
-    "lego",
-    "lexus",
-    "lgbt",
-    "li",
-    "liaison",
-    "lidl",
-    "life",
-    "lifeinsurance",
-    "lifestyle",
-    "lighting",
-    "like",
-    "lilly",
-    "limited",
-    "limo",
-    "lincoln",
-    "linde",
-    "link",
-    "lipsy",
-    "live",
-    "living",
-    "lixil",
-    "lk",
-    "llc",
-    "llp",
-    "loan",
-    "loans",
-    "locker",
-    "locus",
-    "loft",
-    "lol",
-    "london",
-    "lotte",
-    "lotto",
-    "love",
-    "lpl",
-    "lplfinancial",
-    "lr",
-    "ls",
-    "lt",
-    "ltd",
-    "ltda",
-    "lu",
-    "lundbeck",
-    "lupin",
-    "luxe",
-    "luxury",
-    "lv",
-    "ly",
-    "ma",
-    "macys",
-    "madrid",
-    "maif",
-    "maison",
-    "makeup",
-    "man",
-    "management",
-    "mango",
-    "map",
-    "market",
-    "marketing",
-    "markets",
-    "marriott",
-    "marshalls",
-    "maserati",
-    "mattel",
-    "mba",
-    "mc",
-    "mcd",
-    "mcdonalds",
-    "mckinsey",
-    "md",
-    "me",
-    "med",
-    "media",
-    "meet",
-    "melbourne",
-    "meme",
-    "memorial",
-    "men",
-    "menu",
-    "meo",
-    "merckmsd",
-    "metlife",
-    "mf",
-    "mg",
-    "mh",
-    "miami",
-    "microsoft",
-    "mil",
-    "mini",
-    "mint",
-    "mit",
-    "mitsubishi",
-    "mk",
-    "ml",
-    "mlb",
-    "mls",
-    "mm",
-    "mma",
-    "mn",
-    "mo",
-    "mobi",
-    "mobile",
-    "mobily",
-    "moda",
-    "moe",
-    "moi",
-    "mom",
-    "monash",
-    "money",
-    "monster",
-    "montblanc",
-    "mopar",
-    "mormon",
-    "mortgage",
-    "moscow",
-    "moto",
-    "motorcycles",
-    "mov",
-    "movie",
-    "movistar",
-    "mp",
-    "mq",
-    "mr",
-    "ms",
-    "msd",
-    "mt",
-    "mtn",
-    "mtpc",
-    "mtr",
-    "mu",
-    "museum",
-    "music",
-    "mutual",
-    "mutuelle",
-    "mv",
-    "mw",
-    "mx",
-    "my",
-    "mz",
-    "na",
-    "nab",
-    "nadex",
-    "nagoya",
-    "name",
-    "nationwide",
-    "natura",
-    "navy",
-    "nba",
-    "nc",
-    "ne",
-    "nec",
-    "net",
-    "netbank",
-    "netflix",
-    "network",
-    "neustar",
-    "new",
-    "newholland",
-    "news",
-    "next",
-    "nextdirect",
-    "nexus",
-    "nf",
-    "nfl",
-    "ng",
-    "ngo",
-    "nhk",
-    "ni",
-    "nico",
-    "nike",
-    "nikon",
-    "ninja",
-    "nissan",
-    "nissay",
-    "nl",
-    "no",
-    "nokia",
-    "northwesternmutual",
-    "norton",
-    "now",
-    "nowruz",
-    "nowtv",
-    "np",
-    "nr",
-    "nra",
-    "nrw",
-    "ntt",
-    "nu",
-    "nyc",
-    "nz",
-    "obi",
-    "observer",
-    "off",
-    "office",
-    "okinawa",
-    "olayan",
-    "olayangroup",
-    "oldnavy",
-    "ollo",
-    "om",
-    "omega",
-    "one",
-    "ong",
-    "onion",
-    "onl",
-    "online",
-    "onyourside",
-    "ooo",
-    "open",
-    "oracle",
-    "orange",
-    "org",
-    "organic",
-    "orientexpress",
-    "origins",
-    "osaka",
-    "otsuka",
-    "ott",
-    "ovh",
-    "pa",
-    "page",
-    "pamperedchef",
-    "panasonic",
-    "panerai",
-    "paris",
-    "pars",
-    "partners",
-    "parts",
-    "party",
-    "passagens",
-    "pay",
-    "pccw",
-    "pe",
-    "pet",
-    "pf",
-    "pfizer",
-    "pg",
-    "ph",
-    "pharmacy",
-    "phd",
-    "philips",
-    "phone",
-    "photo",
-    "photography",
-    "photos",
-    "physio",
-    "piaget",
-    "pics",
-    "pictet",
-    "pictures",
-    "pid",
-    "pin",
-    "ping",
-    "pink",
-    "pioneer",
-    "pizza",
-    "pk",
-    "pl",
-    "place",
-    "play",
-    "playstation",
-    "plumbing",
-    "plus",
-    "pm",
-    "pn",
-    "pnc",
-    "pohl",
-    "poker",
-    "politie",
-    "porn",
-    "post",
-    "pr",
-    "pramerica",
-    "praxi",
-    "press",
-    "prime",
-    "pro",
-    "prod",
-    "productions",
-    "prof",
-    "progressive",
-    "promo",
-    "properties",
-    "property",
-    "protection",
-    "pru",
-    "prudential",
-    "ps",
-    "pt",
-    "pub",
-    "pw",
-    "pwc",
-    "py",
-    "qa",
-    "qpon",
-    "quebec",
-    "quest",
-    "qvc",
-    "racing",
-    "radio",
-    "raid",
-    "re",
-    "read",
-    "realestate",
-    "realtor",
-    "realty",
-    "recipes",
-    "red",
-    "redstone",
-    "redumbrella",
-    "rehab",
-    "reise",
-    "reisen",
-    "reit",
-    "reliance",
-    "ren",
-    "rent",
-    "rentals",
-    "repair",
-    "report",
-    "republican",
-    "rest",
-    "restaurant",
-    "review",
-    "reviews",
-    "rexroth",
-    "rich",
-    "richardli",
-    "ricoh",
-    "rightathome",
-    "ril",
-    "rio",
-    "rip",
-    "rmit",
-    "ro",
-    "rocher",
-    "rocks",
-    "rodeo",
-    "rogers",
-    "room",
-    "rs",
-    "rsvp",
-    "ru",
-    "rugby",
-    "ruhr",
-    "run",
-    "rw",
-    "rwe",
-    "ryukyu",
-    "sa",
-    "saarland",
-    "safe",
-    "safety",
-    "sakura",
-    "sale",
-    "salon",
-    "samsclub",
-    "samsung",
-    "sandvik",
-    "sandvikcoromant",
-    "sanofi",
-    "sap",
-    "sapo",
-    "sarl",
-    "sas",
-    "save",
-    "saxo",
-    "sb",
-    "sbi",
-    "sbs",
-    "sc",
-    "sca",
-    "scb",
-    "schaeffler",
-    "schmidt",
-    "scholarships",
-    "school",
-    "schule",
-    "schwarz",
-    "science",
-    "scjohnson",
-    "scor",
-    "scot",
-    "sd",
-    "se",
-    "search",
-    "seat",
-    "secure",
-    "security",
-    "seek",
-    "select",
-    "sener",
-    "services",
-    "ses",
-    "seven",
-    "sew",
-    "sex",
-    "sexy",
-    "sfr",
-    "sg",
-    "sh",
-    "shangrila",
-    "sharp",
-    "shaw",
-    "shell",
-    "shia",
-    "shiksha",
-    "shoes",
-    "shop",
-    "shopping",
-    "shouji",
-    "show",
-    "showtime",
-    "shriram",
-    "si",
-    "silk",
-    "sina",
-    "singles",
-    "site",
-    "sj",
-    "sk",
-    "ski",
-    "skin",
-    "sky",
-    "skype",
-    "sl",
-    "sling",
-    "sm",
-    "smart",
-    "smile",
-    "sn",
-    "sncf",
-    "so",
-    "soccer",
-    "social",
-    "softbank",
-    "software",
-    "sohu",
-    "solar",
-    "solutions",
-    "song",
-    "sony",
-    "soy",
-    "spa",
-    "space",
-    "spiegel",
-    "sport",
-    "spot",
-    "spreadbetting",
-    "sr",
-    "srl",
-    "srt",
-    "ss",
-    "st",
-    "stada",
-    "staples",
-    "star",
-    "starhub",
-    "statebank",
-    "statefarm",
-    "statoil",
-    "stc",
-    "stcgroup",
-    "stockholm",
-    "storage",
-    "store",
-    "stream",
-    "studio",
-    "study",
-    "style",
-    "su",
-    "sucks",
-    "supplies",
-    "supply",
-    "support",
-    "surf",
-    "surgery",
-    "suzuki",
-    "sv",
-    "swatch",
-    "swiftcover",
-    "swiss",
-    "sx",
-    "sy",
-    "sydney",
-    "symantec",
-    "systems",
-    "sz",
-    "tab",
-    "taipei",
-    "talk",
-    "taobao",
-    "target",
-    "tatamotors",
-    "tatar",
-    "tattoo",
-    "tax",
-    "taxi",
-    "tc",
-    "tci",
-    "td",
-    "tdk",
-    "team",
-    "tech",
-    "technology",
-    "tel",
-    "telecity",
-    "telefonica",
-    "temasek",
-    "tennis",
-    "teva",
-    "tf",
-    "tg",
-    "th",
-    "thd",
-    "theater",
-    "theatre",
-    "tiaa",
-    "tickets",
-    "tienda",
-    "tiffany",
-    "tips",
-    "tires",
-    "tirol",
-    "tj",
-    "tjmaxx",
-    "tjx",
-    "tk",
-    "tkmaxx",
-    "tl",
-    "tm",
-    "tmall",
-    "tn",
-    "to",
-    "today",
-    "tokyo",
-    "tools",
-    "top",
-    "toray",
-    "toshiba",
-    "total",
-    "tours",
-    "town",
-    "toyota",
-    "toys",
-    "tp",
-    "tr",
-    "trade",
-    "trading",
-    "training",
-    "travel",
-    "travelchannel",
-    "travelers",
-    "travelersinsurance",
-    "trust",
-    "trv",
-    "tt",
-    "tube",
-    "tui",
-    "tunes",
-    "tushu",
-    "tv",
-    "tvs",
-    "tw",
-    "tz",
-    "ua",
-    "ubank",
-    "ubs",
-    "uconnect",
-    "ug",
-    "uk",
-    "um",
-    "unicom",
-    "university",
-    "uno",
-    "uol",
-    "ups",
-    "us",
-    "uy",
-    "uz",
-    "va",
-    "vacations",
-    "vana",
-    "vanguard",
-    "vc",
-    "ve",
-    "vegas",
-    "ventures",
-    "verisign",
-    "versicherung",
-    "vet",
-    "vg",
-    "vi",
-    "viajes",
-    "video",
-    "vig",
-    "viking",
-    "villas",
-    "vin",
-    "vip",
-    "virgin",
-    "visa",
-    "vision",
-    "vista",
-    "vistaprint",
-    "viva",
-    "vivo",
-    "vlaanderen",
-    "vn",
-    "vodka",
-    "volkswagen",
-    "volvo",
-    "vote",
-    "voting",
-    "voto",
-    "voyage",
-    "vu",
-    "vuelos",
-    "wales",
-    "walmart",
-    "walter",
-    "wang",
-    "wanggou",
-    "warman",
-    "watch",
-    "watches",
-    "weather",
-    "weatherchannel",
-    "webcam",
-    "weber",
-    "website",
-    "wed",
-    "wedding",
-    "weibo",
-    "weir",
-    "wf",
-    "whoswho",
-    "wien",
-    "wiki",
-    "williamhill",
-    "win",
-    "windows",
-    "wine",
-    "winners",
-    "wme",
-    "wolterskluwer",
-    "woodside",
-    "work",
-    "works",
-    "world",
-    "wow",
-    "ws",
-    "wtc",
-    "wtf",
-    "xbox",
-    "xerox",
-    "xfinity",
-    "xihuan",
-    "xin",
-    "xperia",
-    "xxx",
-    "xyz",
-    "yachts",
-    "yahoo",
-    "yamaxun",
-    "yandex",
-    "ye",
-    "yodobashi",
-    "yoga",
-    "yokohama",
-    "you",
-    "youtube",
-    "yt",
-    "yun",
-    "za",
-    "zappos",
-    "zara",
-    "zero",
-    "zip",
-    "zippo",
-    "zm",
-    "zone",
-    "zuerich",
-    "zw",
-    "vermögensberater",
-    "vermögensberatung",
-    "ελ",
-    "ευ",
-    "бг",
-    "бел",
-    "дети",
-    "ею",
-    "католик",
-    "ком",
-    "мкд",
-    "мон",
-    "москва",
-    "онлайн",
-    "орг",
-    "рус",
-    "рф",
-    "сайт",
-    "срб",
-    "укр",
-    "қаз",
-    "հայ",
-    "ישראל",
-    "קום",
-    "ابوظبي",
-    "اتصالات",
-    "ارامكو",
-    "الاردن",
-    "البحرين",
-    "الجزائر",
-    "السعودية",
-    "العليان",
-    "المغرب",
-    "امارات",
-    "ایران",
-    "بارت",
-    "بازار",
-    "بيتك",
-    "بھارت",
-    "تونس",
-    "سودان",
-    "سورية",
-    "شبكة",
-    "عراق",
-    "عرب",
-    "عمان",
-    "فلسطين",
-    "قطر",
-    "كاثوليك",
-    "كوم",
-    "مصر",
-    "مليسيا",
-    "موبايلي",
-    "موريتانيا",
-    "موقع",
-    "همراه",
-    "پاکستان",
-    "ڀارت",
-    "कॉम",
-    "नेट",
-    "भारत",
-    "भारतम्",
-    "भारोत",
-    "संगठन",
-    "বাংলা",
-    "ভারত",
-    "ভাৰত",
-    "ਭਾਰਤ",
-    "ભારત",
-    "ଭାରତ",
-    "இந்தியா",
-    "இலங்கை",
-    "சிங்கப்பூர்",
-    "భారత్",
-    "ಭಾರತ",
-    "ഭാരതം",
-    "ලංකා",
-    "คอม",
-    "ไทย",
-    "ລາວ",
-    "გე",
-    "みんな",
-    "アマゾン",
-    "クラウド",
-    "グーグル",
-    "コム",
-    "ストア",
-    "セール",
-    "ファッション",
-    "ポイント",
-    "世界",
-    "中信",
-    "中国",
-    "中國",
-    "中文网",
-    "亚马逊",
-    "企业",
-    "佛山",
-    "信息",
-    "健康",
-    "八卦",
-    "公司",
-    "公益",
-    "台湾",
-    "台灣",
-    "商城",
-    "商店",
-    "商标",
-    "嘉里",
-    "嘉里大酒店",
-    "在线",
-    "大众汽车",
-    "大拿",
-    "天主教",
-    "娱乐",
-    "家電",
-    "工行",
-    "广东",
-    "微博",
-    "慈善",
-    "我爱你",
-    "手机",
-    "手表",
-    "招聘",
-    "政务",
-    "政府",
-    "新加坡",
-    "新闻",
-    "时尚",
-    "書籍",
-    "机构",
-    "淡马锡",
-    "游戏",
-    "澳門",
-    "点看",
-    "珠宝",
-    "移动",
-    "组织机构",
-    "网址",
-    "网店",
-    "网站",
-    "网络",
-    "联通",
-    "诺基亚",
-    "谷歌",
-    "购物",
-    "通販",
-    "集团",
-    "電訊盈科",
-    "飞利浦",
-    "食品",
-    "餐厅",
-    "香格里拉",
-    "香港",
-    "닷넷",
-    "닷컴",
-    "삼성",
-    "한국",
+pub static TLDS: phf::Map<&'static str, TldKind> = phf_map! {
+    "aaa" => TldKind::Generic,
+    "aarp" => TldKind::Generic,
+    "abarth" => TldKind::Generic,
+    "abb" => TldKind::Generic,
+    "abbott" => TldKind::Generic,
+    "abbvie" => TldKind::Generic,
+    "abc" => TldKind::Generic,
+    "able" => TldKind::Generic,
+    "abogado" => TldKind::Generic,
+    "abudhabi" => TldKind::Generic,
+    "ac" => TldKind::Country,
+    "academy" => TldKind::Generic,
+    "accenture" => TldKind::Generic,
+    "accountant" => TldKind::Generic,
+    "accountants" => TldKind::Generic,
+    "aco" => TldKind::Generic,
+    "active" => TldKind::Generic,
+    "actor" => TldKind::Generic,
+    "ad" => TldKind::Country,
+    "adac" => TldKind::Generic,
+    "ads" => TldKind::Generic,
+    "adult" => TldKind::Generic,
+    "ae" => TldKind::Country,
+    "aeg" => TldKind::Generic,
+    "aero" => TldKind::Sponsored,
+    "aetna" => TldKind::Generic,
+    "af" => TldKind::Country,
+    "afamilycompany" => TldKind::Generic,
+    "afl" => TldKind::Generic,
+    "africa" => TldKind::Generic,
+    "ag" => TldKind::Country,
+    "agakhan" => TldKind::Generic,
+    "agency" => TldKind::Generic,
+    "ai" => TldKind::Country,
+    "aig" => TldKind::Generic,
+    "aigo" => TldKind::Generic,
+    "airbus" => TldKind::Generic,
+    "airforce" => TldKind::Generic,
+    "airtel" => TldKind::Generic,
+    "akdn" => TldKind::Generic,
+    "al" => TldKind::Country,
+    "alfaromeo" => TldKind::Generic,
+    "alibaba" => TldKind::Generic,
+    "alipay" => TldKind::Generic,
+    "allfinanz" => TldKind::Generic,
+    "allstate" => TldKind::Generic,
+    "ally" => TldKind::Generic,
+    "alsace" => TldKind::Generic,
+    "alstom" => TldKind::Generic,
+    "am" => TldKind::Country,
+    "amazon" => TldKind::Generic,
+    "americanexpress" => TldKind::Generic,
+    "americanfamily" => TldKind::Generic,
+    "amex" => TldKind::Generic,
+    "amfam" => TldKind::Generic,
+    "amica" => TldKind::Generic,
+    "amsterdam" => TldKind::Generic,
+    "an" => TldKind::Country,
+    "analytics" => TldKind::Generic,
+    "android" => TldKind::Generic,
+    "anquan" => TldKind::Generic,
+    "anz" => TldKind::Generic,
+    "ao" => TldKind::Country,
+    "aol" => TldKind::Generic,
+    "apartments" => TldKind::Generic,
+    "app" => TldKind::Generic,
+    "apple" => TldKind::Generic,
+    "aq" => TldKind::Country,
+    "aquarelle" => TldKind::Generic,
+    "ar" => TldKind::Country,
+    "arab" => TldKind::Generic,
+    "aramco" => TldKind::Generic,
+    "archi" => TldKind::Generic,
+    "army" => TldKind::Generic,
+    "arpa" => TldKind::Generic,
+    "art" => TldKind::Generic,
+    "arte" => TldKind::Generic,
+    "as" => TldKind::Country,
+    "asda" => TldKind::Generic,
+    "asia" => TldKind::Sponsored,
+    "associates" => TldKind::Generic,
+    "at" => TldKind::Country,
+    "athleta" => TldKind::Generic,
+    "attorney" => TldKind::Generic,
+    "au" => TldKind::Country,
+    "auction" => TldKind::Generic,
+    "audi" => TldKind::Generic,
+    "audible" => TldKind::Generic,
+    "audio" => TldKind::Generic,
+    "auspost" => TldKind::Generic,
+    "author" => TldKind::Generic,
+    "auto" => TldKind::Generic,
+    "autos" => TldKind::Generic,
+    "avianca" => TldKind::Generic,
+    "aw" => TldKind::Country,
+    "aws" => TldKind::Generic,
+    "ax" => TldKind::Country,
+    "axa" => TldKind::Generic,
+    "az" => TldKind::Country,
+    "azure" => TldKind::Generic,
+    "ba" => TldKind::Country,
+    "baby" => TldKind::Generic,
+    "baidu" => TldKind::Generic,
+    "banamex" => TldKind::Generic,
+    "bananarepublic" => TldKind::Generic,
+    "band" => TldKind::Generic,
+    "bank" => TldKind::Generic,
+    "bar" => TldKind::Generic,
+    "barcelona" => TldKind::Generic,
+    "barclaycard" => TldKind::Generic,
+    "barclays" => TldKind::Generic,
+    "barefoot" => TldKind::Generic,
+    "bargains" => TldKind::Generic,
+    "baseball" => TldKind::Generic,
+    "basketball" => TldKind::Generic,
+    "bauhaus" => TldKind::Generic,
+    "bayern" => TldKind::Generic,
+    "bb" => TldKind::Country,
+    "bbc" => TldKind::Generic,
+    "bbt" => TldKind::Generic,
+    "bbva" => TldKind::Generic,
+    "bcg" => TldKind::Generic,
+    "bcn" => TldKind::Generic,
+    "bd" => TldKind::Country,
+    "be" => TldKind::Country,
+    "beats" => TldKind::Generic,
+    "beauty" => TldKind::Generic,
+    "beer" => TldKind::Generic,
+    "bentley" => TldKind::Generic,
+    "berlin" => TldKind::Generic,
+    "best" => TldKind::Generic,
+    "bestbuy" => TldKind::Generic,
+    "bet" => TldKind::Generic,
+    "bf" => TldKind::Country,
+    "bg" => TldKind::Country,
+    "bh" => TldKind::Country,
+    "bharti" => TldKind::Generic,
+    "bi" => TldKind::Country,
+    "bible" => TldKind::Generic,
+    "bid" => TldKind::Generic,
+    "bike" => TldKind::Generic,
+    "bing" => TldKind::Generic,
+    "bingo" => TldKind::Generic,
+    "bio" => TldKind::Generic,
+    "biz" => TldKind::Generic,
+    "bj" => TldKind::Country,
+    "bl" => TldKind::Country,
+    "black" => TldKind::Generic,
+    "blackfriday" => TldKind::Generic,
+    "blanco" => TldKind::Generic,
+    "blockbuster" => TldKind::Generic,
+    "blog" => TldKind::Generic,
+    "bloomberg" => TldKind::Generic,
+    "blue" => TldKind::Generic,
+    "bm" => TldKind::Country,
+    "bms" => TldKind::Generic,
+    "bmw" => TldKind::Generic,
+    "bn" => TldKind::Country,
+    "bnl" => TldKind::Generic,
+    "bnpparibas" => TldKind::Generic,
+    "bo" => TldKind::Country,
+    "boats" => TldKind::Generic,
+    "boehringer" => TldKind::Generic,
+    "bofa" => TldKind::Generic,
+    "bom" => TldKind::Generic,
+    "bond" => TldKind::Generic,
+    "boo" => TldKind::Generic,
+    "book" => TldKind::Generic,
+    "booking" => TldKind::Generic,
+    "boots" => TldKind::Generic,
+    "bosch" => TldKind::Generic,
+    "bostik" => TldKind::Generic,
+    "boston" => TldKind::Generic,
+    "bot" => TldKind::Generic,
+    "boutique" => TldKind::Generic,
+    "box" => TldKind::Generic,
+    "bq" => TldKind::Country,
+    "br" => TldKind::Country,
+    "bradesco" => TldKind::Generic,
+    "bridgestone" => TldKind::Generic,
+    "broadway" => TldKind::Generic,
+    "broker" => TldKind::Generic,
+    "brother" => TldKind::Generic,
+    "brussels" => TldKind::Generic,
+    "bs" => TldKind::Country,
+    "bt" => TldKind::Country,
+    "budapest" => TldKind::Generic,
+    "bugatti" => TldKind::Generic,
+    "build" => TldKind::Generic,
+    "builders" => TldKind::Generic,
+    "business" => TldKind::Generic,
+    "buy" => TldKind::Generic,
+    "buzz" => TldKind::Generic,
+    "bv" => TldKind::Country,
+    "bw" => TldKind::Country,
+    "by" => TldKind::Country,
+    "bz" => TldKind::Country,
+    "bzh" => TldKind::Generic,
+    "ca" => TldKind::Country,
+    "cab" => TldKind::Generic,
+    "cafe" => TldKind::Generic,
+    "cal" => TldKind::Generic,
+    "call" => TldKind::Generic,
+    "calvinklein" => TldKind::Generic,
+    "cam" => TldKind::Generic,
+    "camera" => TldKind::Generic,
+    "camp" => TldKind::Generic,
+    "cancerresearch" => TldKind::Generic,
+    "canon" => TldKind::Generic,
+    "capetown" => TldKind::Generic,
+    "capital" => TldKind::Generic,
+    "capitalone" => TldKind::Generic,
+    "car" => TldKind::Generic,
+    "caravan" => TldKind::Generic,
+    "cards" => TldKind::Generic,
+    "care" => TldKind::Generic,
+    "career" => TldKind::Generic,
+    "careers" => TldKind::Generic,
+    "cars" => TldKind::Generic,
+    "cartier" => TldKind::Generic,
+    "casa" => TldKind::Generic,
+    "case" => TldKind::Generic,
+    "caseih" => TldKind::Generic,
+    "cash" => TldKind::Generic,
+    "casino" => TldKind::Generic,
+    "cat" => TldKind::Sponsored,
+    "catering" => TldKind::Generic,
+    "catholic" => TldKind::Generic,
+    "cba" => TldKind::Generic,
+    "cbn" => TldKind::Generic,
+    "cbre" => TldKind::Generic,
+    "cbs" => TldKind::Generic,
+    "cc" => TldKind::Country,
+    "cd" => TldKind::Country,
+    "ceb" => TldKind::Generic,
+    "center" => TldKind::Generic,
+    "ceo" => TldKind::Generic,
+    "cern" => TldKind::Generic,
+    "cf" => TldKind::Country,
+    "cfa" => TldKind::Generic,
+    "cfd" => TldKind::Generic,
+    "cg" => TldKind::Country,
+    "ch" => TldKind::Country,
+    "chanel" => TldKind::Generic,
+    "channel" => TldKind::Generic,
+    "charity" => TldKind::Generic,
+    "chase" => TldKind::Generic,
+    "chat" => TldKind::Generic,
+    "cheap" => TldKind::Generic,
+    "chintai" => TldKind::Generic,
+    "chloe" => TldKind::Generic,
+    "christmas" => TldKind::Generic,
+    "chrome" => TldKind::Generic,
+    "chrysler" => TldKind::Generic,
+    "church" => TldKind::Generic,
+    "ci" => TldKind::Country,
+    "cipriani" => TldKind::Generic,
+    "circle" => TldKind::Generic,
+    "cisco" => TldKind::Generic,
+    "citadel" => TldKind::Generic,
+    "citi" => TldKind::Generic,
+    "citic" => TldKind::Generic,
+    "city" => TldKind::Generic,
+    "cityeats" => TldKind::Generic,
+    "ck" => TldKind::Country,
+    "cl" => TldKind::Country,
+    "claims" => TldKind::Generic,
+    "cleaning" => TldKind::Generic,
+    "click" => TldKind::Generic,
+    "clinic" => TldKind::Generic,
+    "clinique" => TldKind::Generic,
+    "clothing" => TldKind::Generic,
+    "cloud" => TldKind::Generic,
+    "club" => TldKind::Generic,
+    "clubmed" => TldKind::Generic,
+    "cm" => TldKind::Country,
+    "cn" => TldKind::Country,
+    "co" => TldKind::Country,
+    "coach" => TldKind::Generic,
+    "codes" => TldKind::Generic,
+    "coffee" => TldKind::Generic,
+    "college" => TldKind::Generic,
+    "cologne" => TldKind::Generic,
+    "com" => TldKind::Generic,
+    "comcast" => TldKind::Generic,
+    "commbank" => TldKind::Generic,
+    "community" => TldKind::Generic,
+    "company" => TldKind::Generic,
+    "compare" => TldKind::Generic,
+    "computer" => TldKind::Generic,
+    "comsec" => TldKind::Generic,
+    "condos" => TldKind::Generic,
+    "construction" => TldKind::Generic,
+    "consulting" => TldKind::Generic,
+    "contact" => TldKind::Generic,
+    "contractors" => TldKind::Generic,
+    "cooking" => TldKind::Generic,
+    "cookingchannel" => TldKind::Generic,
+    "cool" => TldKind::Generic,
+    "coop" => TldKind::Sponsored,
+    "corsica" => TldKind::Generic,
+    "country" => TldKind::Generic,
+    "coupon" => TldKind::Generic,
+    "coupons" => TldKind::Generic,
+    "courses" => TldKind::Generic,
+    "cpa" => TldKind::Generic,
+    "cr" => TldKind::Country,
+    "credit" => TldKind::Generic,
+    "creditcard" => TldKind::Generic,
+    "creditunion" => TldKind::Generic,
+    "cricket" => TldKind::Generic,
+    "crown" => TldKind::Generic,
+    "crs" => TldKind::Generic,
+    "cruise" => TldKind::Generic,
+    "cruises" => TldKind::Generic,
+    "csc" => TldKind::Generic,
+    "cu" => TldKind::Country,
+    "cuisinella" => TldKind::Generic,
+    "cv" => TldKind::Country,
+    "cw" => TldKind::Country,
+    "cx" => TldKind::Country,
+    "cy" => TldKind::Country,
+    "cymru" => TldKind::Generic,
+    "cyou" => TldKind::Generic,
+    "cz" => TldKind::Country,
+    "dabur" => TldKind::Generic,
+    "dad" => TldKind::Generic,
+    "dance" => TldKind::Generic,
+    "data" => TldKind::Generic,
+    "date" => TldKind::Generic,
+    "dating" => TldKind::Generic,
+    "datsun" => TldKind::Generic,
+    "day" => TldKind::Generic,
+    "dclk" => TldKind::Generic,
+    "dds" => TldKind::Generic,
+    "de" => TldKind::Country,
+    "deal" => TldKind::Generic,
+    "dealer" => TldKind::Generic,
+    "deals" => TldKind::Generic,
+    "degree" => TldKind::Generic,
+    "delivery" => TldKind::Generic,
+    "dell" => TldKind::Generic,
+    "deloitte" => TldKind::Generic,
+    "delta" => TldKind::Generic,
+    "democrat" => TldKind::Generic,
+    "dental" => TldKind::Generic,
+    "dentist" => TldKind::Generic,
+    "desi" => TldKind::Generic,
+    "design" => TldKind::Generic,
+    "dev" => TldKind::Generic,
+    "dhl" => TldKind::Generic,
+    "diamonds" => TldKind::Generic,
+    "diet" => TldKind::Generic,
+    "digital" => TldKind::Generic,
+    "direct" => TldKind::Generic,
+    "directory" => TldKind::Generic,
+    "discount" => TldKind::Generic,
+    "discover" => TldKind::Generic,
+    "dish" => TldKind::Generic,
+    "diy" => TldKind::Generic,
+    "dj" => TldKind::Country,
+    "dk" => TldKind::Country,
+    "dm" => TldKind::Country,
+    "dnp" => TldKind::Generic,
+    "do" => TldKind::Country,
+    "docs" => TldKind::Generic,
+    "doctor" => TldKind::Generic,
+    "dodge" => TldKind::Generic,
+    "dog" => TldKind::Generic,
+    "doha" => TldKind::Generic,
+    "domains" => TldKind::Generic,
+    "doosan" => TldKind::Generic,
+    "dot" => TldKind::Generic,
+    "download" => TldKind::Generic,
+    "drive" => TldKind::Generic,
+    "dtv" => TldKind::Generic,
+    "dubai" => TldKind::Generic,
+    "duck" => TldKind::Generic,
+    "dunlop" => TldKind::Generic,
+    "duns" => TldKind::Generic,
+    "dupont" => TldKind::Generic,
+    "durban" => TldKind::Generic,
+    "dvag" => TldKind::Generic,
+    "dvr" => TldKind::Generic,
+    "dz" => TldKind::Country,
+    "earth" => TldKind::Generic,
+    "eat" => TldKind::Generic,
+    "ec" => TldKind::Country,
+    "eco" => TldKind::Generic,
+    "edeka" => TldKind::Generic,
+    "edu" => TldKind::Sponsored,
+    "education" => TldKind::Generic,
+    "ee" => TldKind::Country,
+    "eg" => TldKind::Country,
+    "eh" => TldKind::Country,
+    "email" => TldKind::Generic,
+    "emerck" => TldKind::Generic,
+    "energy" => TldKind::Generic,
+    "engineer" => TldKind::Generic,
+    "engineering" => TldKind::Generic,
+    "enterprises" => TldKind::Generic,
+    "epost" => TldKind::Generic,
+    "epson" => TldKind::Generic,
+    "equipment" => TldKind::Generic,
+    "er" => TldKind::Country,
+    "ericsson" => TldKind::Generic,
+    "erni" => TldKind::Generic,
+    "es" => TldKind::Country,
+    "esq" => TldKind::Generic,
+    "estate" => TldKind::Generic,
+    "esurance" => TldKind::Generic,
+    "et" => TldKind::Country,
+    "etisalat" => TldKind::Generic,
+    "eu" => TldKind::Country,
+    "eurovision" => TldKind::Generic,
+    "eus" => TldKind::Generic,
+    "events" => TldKind::Generic,
+    "everbank" => TldKind::Generic,
+    "exchange" => TldKind::Generic,
+    "expert" => TldKind::Generic,
+    "exposed" => TldKind::Generic,
+    "express" => TldKind::Generic,
+    "extraspace" => TldKind::Generic,
+    "fage" => TldKind::Generic,
+    "fail" => TldKind::Generic,
+    "fairwinds" => TldKind::Generic,
+    "faith" => TldKind::Generic,
+    "family" => TldKind::Generic,
+    "fan" => TldKind::Generic,
+    "fans" => TldKind::Generic,
+    "farm" => TldKind::Generic,
+    "farmers" => TldKind::Generic,
+    "fashion" => TldKind::Generic,
+    "fast" => TldKind::Generic,
+    "fedex" => TldKind::Generic,
+    "feedback" => TldKind::Generic,
+    "ferrari" => TldKind::Generic,
+    "ferrero" => TldKind::Generic,
+    "fi" => TldKind::Country,
+    "fiat" => TldKind::Generic,
+    "fidelity" => TldKind::Generic,
+    "fido" => TldKind::Generic,
+    "film" => TldKind::Generic,
+    "final" => TldKind::Generic,
+    "finance" => TldKind::Generic,
+    "financial" => TldKind::Generic,
+    "fire" => TldKind::Generic,
+    "firestone" => TldKind::Generic,
+    "firmdale" => TldKind::Generic,
+    "fish" => TldKind::Generic,
+    "fishing" => TldKind::Generic,
+    "fit" => TldKind::Generic,
+    "fitness" => TldKind::Generic,
+    "fj" => TldKind::Country,
+    "fk" => TldKind::Country,
+    "flickr" => TldKind::Generic,
+    "flights" => TldKind::Generic,
+    "flir" => TldKind::Generic,
+    "florist" => TldKind::Generic,
+    "flowers" => TldKind::Generic,
+    "flsmidth" => TldKind::Generic,
+    "fly" => TldKind::Generic,
+    "fm" => TldKind::Country,
+    "fo" => TldKind::Country,
+    "foo" => TldKind::Generic,
+    "food" => TldKind::Generic,
+    "foodnetwork" => TldKind::Generic,
+    "football" => TldKind::Generic,
+    "ford" => TldKind::Generic,
+    "forex" => TldKind::Generic,
+    "forsale" => TldKind::Generic,
+    "forum" => TldKind::Generic,
+    "foundation" => TldKind::Generic,
+    "fox" => TldKind::Generic,
+    "fr" => TldKind::Country,
+    "free" => TldKind::Generic,
+    "fresenius" => TldKind::Generic,
+    "frl" => TldKind::Generic,
+    "frogans" => TldKind::Generic,
+    "frontdoor" => TldKind::Generic,
+    "frontier" => TldKind::Generic,
+    "ftr" => TldKind::Generic,
+    "fujitsu" => TldKind::Generic,
+    "fujixerox" => TldKind::Generic,
+    "fun" => TldKind::Generic,
+    "fund" => TldKind::Generic,
+    "furniture" => TldKind::Generic,
+    "futbol" => TldKind::Generic,
+    "fyi" => TldKind::Generic,
+    "ga" => TldKind::Country,
+    "gal" => TldKind::Generic,
+    "gallery" => TldKind::Generic,
+    "gallo" => TldKind::Generic,
+    "gallup" => TldKind::Generic,
+    "game" => TldKind::Generic,
+    "games" => TldKind::Generic,
+    "gap" => TldKind::Generic,
+    "garden" => TldKind::Generic,
+    "gay" => TldKind::Generic,
+    "gb" => TldKind::Country,
+    "gbiz" => TldKind::Generic,
+    "gd" => TldKind::Country,
+    "gdn" => TldKind::Generic,
+    "ge" => TldKind::Country,
+    "gea" => TldKind::Generic,
+    "gent" => TldKind::Generic,
+    "genting" => TldKind::Generic,
+    "george" => TldKind::Generic,
+    "gf" => TldKind::Country,
+    "gg" => TldKind::Country,
+    "ggee" => TldKind::Generic,
+    "gh" => TldKind::Country,
+    "gi" => TldKind::Country,
+    "gift" => TldKind::Generic,
+    "gifts" => TldKind::Generic,
+    "gives" => TldKind::Generic,
+    "giving" => TldKind::Generic,
+    "gl" => TldKind::Country,
+    "glade" => TldKind::Generic,
+    "glass" => TldKind::Generic,
+    "gle" => TldKind::Generic,
+    "global" => TldKind::Generic,
+    "globo" => TldKind::Generic,
+    "gm" => TldKind::Country,
+    "gmail" => TldKind::Generic,
+    "gmbh" => TldKind::Generic,
+    "gmo" => TldKind::Generic,
+    "gmx" => TldKind::Generic,
+    "gn" => TldKind::Country,
+    "godaddy" => TldKind::Generic,
+    "gold" => TldKind::Generic,
+    "goldpoint" => TldKind::Generic,
+    "golf" => TldKind::Generic,
+    "goo" => TldKind::Generic,
+    "goodhands" => TldKind::Generic,
+    "goodyear" => TldKind::Generic,
+    "goog" => TldKind::Generic,
+    "google" => TldKind::Generic,
+    "gop" => TldKind::Generic,
+    "got" => TldKind::Generic,
+    "gov" => TldKind::Sponsored,
+    "gp" => TldKind::Country,
+    "gq" => TldKind::Country,
+    "gr" => TldKind::Country,
+    "grainger" => TldKind::Generic,
+    "graphics" => TldKind::Generic,
+    "gratis" => TldKind::Generic,
+    "green" => TldKind::Generic,
+    "gripe" => TldKind::Generic,
+    "grocery" => TldKind::Generic,
+    "group" => TldKind::Generic,
+    "gs" => TldKind::Country,
+    "gt" => TldKind::Country,
+    "gu" => TldKind::Country,
+    "guardian" => TldKind::Generic,
+    "gucci" => TldKind::Generic,
+    "guge" => TldKind::Generic,
+    "guide" => TldKind::Generic,
+    "guitars" => TldKind::Generic,
+    "guru" => TldKind::Generic,
+    "gw" => TldKind::Country,
+    "gy" => TldKind::Country,
+    "hair" => TldKind::Generic,
+    "hamburg" => TldKind::Generic,
+    "hangout" => TldKind::Generic,
+    "haus" => TldKind::Generic,
+    "hbo" => TldKind::Generic,
+    "hdfc" => TldKind::Generic,
+    "hdfcbank" => TldKind::Generic,
+    "health" => TldKind::Generic,
+    "healthcare" => TldKind::Generic,
+    "help" => TldKind::Generic,
+    "helsinki" => TldKind::Generic,
+    "here" => TldKind::Generic,
+    "hermes" => TldKind::Generic,
+    "hgtv" => TldKind::Generic,
+    "hiphop" => TldKind::Generic,
+    "hisamitsu" => TldKind::Generic,
+    "hitachi" => TldKind::Generic,
+    "hiv" => TldKind::Generic,
+    "hk" => TldKind::Country,
+    "hkt" => TldKind::Generic,
+    "hm" => TldKind::Country,
+    "hn" => TldKind::Country,
+    "hockey" => TldKind::Generic,
+    "holdings" => TldKind::Generic,
+    "holiday" => TldKind::Generic,
+    "homedepot" => TldKind::Generic,
+    "homegoods" => TldKind::Generic,
+    "homes" => TldKind::Generic,
+    "homesense" => TldKind::Generic,
+    "honda" => TldKind::Generic,
+    "honeywell" => TldKind::Generic,
+    "horse" => TldKind::Generic,
+    "hospital" => TldKind::Generic,
+    "host" => TldKind::Generic,
+    "hosting" => TldKind::Generic,
+    "hot" => TldKind::Generic,
+    "hoteles" => TldKind::Generic,
+    "hotels" => TldKind::Generic,
+    "hotmail" => TldKind::Generic,
+    "house" => TldKind::Generic,
+    "how" => TldKind::Generic,
+    "hr" => TldKind::Country,
+    "hsbc" => TldKind::Generic,
+    "ht" => TldKind::Country,
+    "htc" => TldKind::Generic,
+    "hu" => TldKind::Country,
+    "hughes" => TldKind::Generic,
+    "hyatt" => TldKind::Generic,
+    "hyundai" => TldKind::Generic,
+    "ibm" => TldKind::Generic,
+    "icbc" => TldKind::Generic,
+    "ice" => TldKind::Generic,
+    "icu" => TldKind::Generic,
+    "id" => TldKind::Country,
+    "ie" => TldKind::Country,
+    "ieee" => TldKind::Generic,
+    "ifm" => TldKind::Generic,
+    "iinet" => TldKind::Generic,
+    "ikano" => TldKind::Generic,
+    "il" => TldKind::Country,
+    "im" => TldKind::Country,
+    "imamat" => TldKind::Generic,
+    "imdb" => TldKind::Generic,
+    "immo" => TldKind::Generic,
+    "immobilien" => TldKind::Generic,
+    "in" => TldKind::Country,
+    "inc" => TldKind::Generic,
+    "industries" => TldKind::Generic,
+    "infiniti" => TldKind::Generic,
+    "info" => TldKind::Generic,
+    "ing" => TldKind::Generic,
+    "ink" => TldKind::Generic,
+    "institute" => TldKind::Generic,
+    "insurance" => TldKind::Generic,
+    "insure" => TldKind::Generic,
+    "int" => TldKind::Sponsored,
+    "intel" => TldKind::Generic,
+    "international" => TldKind::Generic,
+    "intuit" => TldKind::Generic,
+    "investments" => TldKind::Generic,
+    "io" => TldKind::Country,
+    "ipiranga" => TldKind::Generic,
+    "iq" => TldKind::Country,
+    "ir" => TldKind::Country,
+    "irish" => TldKind::Generic,
+    "is" => TldKind::Country,
+    "iselect" => TldKind::Generic,
+    "ismaili" => TldKind::Generic,
+    "ist" => TldKind::Generic,
+    "istanbul" => TldKind::Generic,
+    "it" => TldKind::Country,
+    "itau" => TldKind::Generic,
+    "itv" => TldKind::Generic,
+    "iveco" => TldKind::Generic,
+    "iwc" => TldKind::Generic,
+    "jaguar" => TldKind::Generic,
+    "java" => TldKind::Generic,
+    "jcb" => TldKind::Generic,
+    "jcp" => TldKind::Generic,
+    "je" => TldKind::Country,
+    "jeep" => TldKind::Generic,
+    "jetzt" => TldKind::Generic,
+    "jewelry" => TldKind::Generic,
+    "jio" => TldKind::Generic,
+    "jlc" => TldKind::Generic,
+    "jll" => TldKind::Generic,
+    "jm" => TldKind::Country,
+    "jmp" => TldKind::Generic,
+    "jnj" => TldKind::Generic,
+    "jo" => TldKind::Country,
+    "jobs" => TldKind::Sponsored,
+    "joburg" => TldKind::Generic,
+    "jot" => TldKind::Generic,
+    "joy" => TldKind::Generic,
+    "jp" => TldKind::Country,
+    "jpmorgan" => TldKind::Generic,
+    "jprs" => TldKind::Generic,
+    "juegos" => TldKind::Generic,
+    "juniper" => TldKind::Generic,
+    "kaufen" => TldKind::Generic,
+    "kddi" => TldKind::Generic,
+    "ke" => TldKind::Country,
+    "kerryhotels" => TldKind::Generic,
+    "kerrylogistics" => TldKind::Generic,
+    "kerryproperties" => TldKind::Generic,
+    "kfh" => TldKind::Generic,
+    "kg" => TldKind::Country,
+    "kh" => TldKind::Country,
+    "ki" => TldKind::Country,
+    "kia" => TldKind::Generic,
+    "kids" => TldKind::Generic,
+    "kim" => TldKind::Generic,
+    "kinder" => TldKind::Generic,
+    "kindle" => TldKind::Generic,
+    "kitchen" => TldKind::Generic,
+    "kiwi" => TldKind::Generic,
+    "km" => TldKind::Country,
+    "kn" => TldKind::Country,
+    "koeln" => TldKind::Generic,
+    "komatsu" => TldKind::Generic,
+    "kosher" => TldKind::Generic,
+    "kp" => TldKind::Country,
+    "kpmg" => TldKind::Generic,
+    "kpn" => TldKind::Generic,
+    "kr" => TldKind::Country,
+    "krd" => TldKind::Generic,
+    "kred" => TldKind::Generic,
+    "kuokgroup" => TldKind::Generic,
+    "kw" => TldKind::Country,
+    "ky" => TldKind::Country,
+    "kyoto" => TldKind::Generic,
+    "kz" => TldKind::Country,
+    "la" => TldKind::Country,
+    "lacaixa" => TldKind::Generic,
+    "ladbrokes" => TldKind::Generic,
+    "lamborghini" => TldKind::Generic,
+    "lamer" => TldKind::Generic,
+    "lancaster" => TldKind::Generic,
+    "lancia" => TldKind::Generic,
+    "lancome" => TldKind::Generic,
+    "land" => TldKind::Generic,
+    "landrover" => TldKind::Generic,
+    "lanxess" => TldKind::Generic,
+    "lasalle" => TldKind::Generic,
+    "lat" => TldKind::Generic,
+    "latino" => TldKind::Generic,
+    "latrobe" => TldKind::Generic,
+    "law" => TldKind::Generic,
+    "lawyer" => TldKind::Generic,
+    "lb" => TldKind::Country,
+    "lc" => TldKind::Country,
+    "lds" => TldKind::Generic,
+    "lease" => TldKind::Generic,
+    "leclerc" => TldKind::Generic,
+    "lefrak" => TldKind::Generic,
+    "legal" => TldKind::Generic,
+    "lego" => TldKind::Generic,
+    "lexus" => TldKind::Generic,
+    "lgbt" => TldKind::Generic,
+    "li" => TldKind::Country,
+    "liaison" => TldKind::Generic,
+    "lidl" => TldKind::Generic,
+    "life" => TldKind::Generic,
+    "lifeinsurance" => TldKind::Generic,
+    "lifestyle" => TldKind::Generic,
+    "lighting" => TldKind::Generic,
+    "like" => TldKind::Generic,
+    "lilly" => TldKind::Generic,
+    "limited" => TldKind::Generic,
+    "limo" => TldKind::Generic,
+    "lincoln" => TldKind::Generic,
+    "linde" => TldKind::Generic,
+    "link" => TldKind::Generic,
+    "lipsy" => TldKind::Generic,
+    "live" => TldKind::Generic,
+    "living" => TldKind::Generic,
+    "lixil" => TldKind::Generic,
+    "lk" => TldKind::Country,
+    "llc" => TldKind::Generic,
+    "llp" => TldKind::Generic,
+    "loan" => TldKind::Generic,
+    "loans" => TldKind::Generic,
+    "locker" => TldKind::Generic,
+    "locus" => TldKind::Generic,
+    "loft" => TldKind::Generic,
+    "lol" => TldKind::Generic,
+    "london" => TldKind::Generic,
+    "lotte" => TldKind::Generic,
+    "lotto" => TldKind::Generic,
+    "love" => TldKind::Generic,
+    "lpl" => TldKind::Generic,
+    "lplfinancial" => TldKind::Generic,
+    "lr" => TldKind::Country,
+    "ls" => TldKind::Country,
+    "lt" => TldKind::Country,
+    "ltd" => TldKind::Generic,
+    "ltda" => TldKind::Generic,
+    "lu" => TldKind::Country,
+    "lundbeck" => TldKind::Generic,
+    "lupin" => TldKind::Generic,
+    "luxe" => TldKind::Generic,
+    "luxury" => TldKind::Generic,
+    "lv" => TldKind::Country,
+    "ly" => TldKind::Country,
+    "ma" => TldKind::Country,
+    "macys" => TldKind::Generic,
+    "madrid" => TldKind::Generic,
+    "maif" => TldKind::Generic,
+    "maison" => TldKind::Generic,
+    "makeup" => TldKind::Generic,
+    "man" => TldKind::Generic,
+    "management" => TldKind::Generic,
+    "mango" => TldKind::Generic,
+    "map" => TldKind::Generic,
+    "market" => TldKind::Generic,
+    "marketing" => TldKind::Generic,
+    "markets" => TldKind::Generic,
+    "marriott" => TldKind::Generic,
+    "marshalls" => TldKind::Generic,
+    "maserati" => TldKind::Generic,
+    "mattel" => TldKind::Generic,
+    "mba" => TldKind::Generic,
+    "mc" => TldKind::Country,
+    "mcd" => TldKind::Generic,
+    "mcdonalds" => TldKind::Generic,
+    "mckinsey" => TldKind::Generic,
+    "md" => TldKind::Country,
+    "me" => TldKind::Country,
+    "med" => TldKind::Generic,
+    "media" => TldKind::Generic,
+    "meet" => TldKind::Generic,
+    "melbourne" => TldKind::Generic,
+    "meme" => TldKind::Generic,
+    "memorial" => TldKind::Generic,
+    "men" => TldKind::Generic,
+    "menu" => TldKind::Generic,
+    "meo" => TldKind::Generic,
+    "merckmsd" => TldKind::Generic,
+    "metlife" => TldKind::Generic,
+    "mf" => TldKind::Country,
+    "mg" => TldKind::Country,
+    "mh" => TldKind::Country,
+    "miami" => TldKind::Generic,
+    "microsoft" => TldKind::Generic,
+    "mil" => TldKind::Sponsored,
+    "mini" => TldKind::Generic,
+    "mint" => TldKind::Generic,
+    "mit" => TldKind::Generic,
+    "mitsubishi" => TldKind::Generic,
+    "mk" => TldKind::Country,
+    "ml" => TldKind::Country,
+    "mlb" => TldKind::Generic,
+    "mls" => TldKind::Generic,
+    "mm" => TldKind::Country,
+    "mma" => TldKind::Generic,
+    "mn" => TldKind::Country,
+    "mo" => TldKind::Country,
+    "mobi" => TldKind::Generic,
+    "mobile" => TldKind::Generic,
+    "mobily" => TldKind::Generic,
+    "moda" => TldKind::Generic,
+    "moe" => TldKind::Generic,
+    "moi" => TldKind::Generic,
+    "mom" => TldKind::Generic,
+    "monash" => TldKind::Generic,
+    "money" => TldKind::Generic,
+    "monster" => TldKind::Generic,
+    "montblanc" => TldKind::Generic,
+    "mopar" => TldKind::Generic,
+    "mormon" => TldKind::Generic,
+    "mortgage" => TldKind::Generic,
+    "moscow" => TldKind::Generic,
+    "moto" => TldKind::Generic,
+    "motorcycles" => TldKind::Generic,
+    "mov" => TldKind::Generic,
+    "movie" => TldKind::Generic,
+    "movistar" => TldKind::Generic,
+    "mp" => TldKind::Country,
+    "mq" => TldKind::Country,
+    "mr" => TldKind::Country,
+    "ms" => TldKind::Country,
+    "msd" => TldKind::Generic,
+    "mt" => TldKind::Country,
+    "mtn" => TldKind::Generic,
+    "mtpc" => TldKind::Generic,
+    "mtr" => TldKind::Generic,
+    "mu" => TldKind::Country,
+    "museum" => TldKind::Sponsored,
+    "music" => TldKind::Generic,
+    "mutual" => TldKind::Generic,
+    "mutuelle" => TldKind::Generic,
+    "mv" => TldKind::Country,
+    "mw" => TldKind::Country,
+    "mx" => TldKind::Country,
+    "my" => TldKind::Country,
+    "mz" => TldKind::Country,
+    "na" => TldKind::Country,
+    "nab" => TldKind::Generic,
+    "nadex" => TldKind::Generic,
+    "nagoya" => TldKind::Generic,
+    "name" => TldKind::Generic,
+    "nationwide" => TldKind::Generic,
+    "natura" => TldKind::Generic,
+    "navy" => TldKind::Generic,
+    "nba" => TldKind::Generic,
+    "nc" => TldKind::Country,
+    "ne" => TldKind::Country,
+    "nec" => TldKind::Generic,
+    "net" => TldKind::Generic,
+    "netbank" => TldKind::Generic,
+    "netflix" => TldKind::Generic,
+    "network" => TldKind::Generic,
+    "neustar" => TldKind::Generic,
+    "new" => TldKind::Generic,
+    "newholland" => TldKind::Generic,
+    "news" => TldKind::Generic,
+    "next" => TldKind::Generic,
+    "nextdirect" => TldKind::Generic,
+    "nexus" => TldKind::Generic,
+    "nf" => TldKind::Country,
+    "nfl" => TldKind::Generic,
+    "ng" => TldKind::Country,
+    "ngo" => TldKind::Generic,
+    "nhk" => TldKind::Generic,
+    "ni" => TldKind::Country,
+    "nico" => TldKind::Generic,
+    "nike" => TldKind::Generic,
+    "nikon" => TldKind::Generic,
+    "ninja" => TldKind::Generic,
+    "nissan" => TldKind::Generic,
+    "nissay" => TldKind::Generic,
+    "nl" => TldKind::Country,
+    "no" => TldKind::Country,
+    "nokia" => TldKind::Generic,
+    "northwesternmutual" => TldKind::Generic,
+    "norton" => TldKind::Generic,
+    "now" => TldKind::Generic,
+    "nowruz" => TldKind::Generic,
+    "nowtv" => TldKind::Generic,
+    "np" => TldKind::Country,
+    "nr" => TldKind::Country,
+    "nra" => TldKind::Generic,
+    "nrw" => TldKind::Generic,
+    "ntt" => TldKind::Generic,
+    "nu" => TldKind::Country,
+    "nyc" => TldKind::Generic,
+    "nz" => TldKind::Country,
+    "obi" => TldKind::Generic,
+    "observer" => TldKind::Generic,
+    "off" => TldKind::Generic,
+    "office" => TldKind::Generic,
+    "okinawa" => TldKind::Generic,
+    "olayan" => TldKind::Generic,
+    "olayangroup" => TldKind::Generic,
+    "oldnavy" => TldKind::Generic,
+    "ollo" => TldKind::Generic,
+    "om" => TldKind::Country,
+    "omega" => TldKind::Generic,
+    "one" => TldKind::Generic,
+    "ong" => TldKind::Generic,
+    "onion" => TldKind::Generic,
+    "onl" => TldKind::Generic,
+    "online" => TldKind::Generic,
+    "onyourside" => TldKind::Generic,
+    "ooo" => TldKind::Generic,
+    "open" => TldKind::Generic,
+    "oracle" => TldKind::Generic,
+    "orange" => TldKind::Generic,
+    "org" => TldKind::Generic,
+    "organic" => TldKind::Generic,
+    "orientexpress" => TldKind::Generic,
+    "origins" => TldKind::Generic,
+    "osaka" => TldKind::Generic,
+    "otsuka" => TldKind::Generic,
+    "ott" => TldKind::Generic,
+    "ovh" => TldKind::Generic,
+    "pa" => TldKind::Country,
+    "page" => TldKind::Generic,
+    "pamperedchef" => TldKind::Generic,
+    "panasonic" => TldKind::Generic,
+    "panerai" => TldKind::Generic,
+    "paris" => TldKind::Generic,
+    "pars" => TldKind::Generic,
+    "partners" => TldKind::Generic,
+    "parts" => TldKind::Generic,
+    "party" => TldKind::Generic,
+    "passagens" => TldKind::Generic,
+    "pay" => TldKind::Generic,
+    "pccw" => TldKind::Generic,
+    "pe" => TldKind::Country,
+    "pet" => TldKind::Generic,
+    "pf" => TldKind::Country,
+    "pfizer" => TldKind::Generic,
+    "pg" => TldKind::Country,
+    "ph" => TldKind::Country,
+    "pharmacy" => TldKind::Generic,
+    "phd" => TldKind::Generic,
+    "philips" => TldKind::Generic,
+    "phone" => TldKind::Generic,
+    "photo" => TldKind::Generic,
+    "photography" => TldKind::Generic,
+    "photos" => TldKind::Generic,
+    "physio" => TldKind::Generic,
+    "piaget" => TldKind::Generic,
+    "pics" => TldKind::Generic,
+    "pictet" => TldKind::Generic,
+    "pictures" => TldKind::Generic,
+    "pid" => TldKind::Generic,
+    "pin" => TldKind::Generic,
+    "ping" => TldKind::Generic,
+    "pink" => TldKind::Generic,
+    "pioneer" => TldKind::Generic,
+    "pizza" => TldKind::Generic,
+    "pk" => TldKind::Country,
+    "pl" => TldKind::Country,
+    "place" => TldKind::Generic,
+    "play" => TldKind::Generic,
+    "playstation" => TldKind::Generic,
+    "plumbing" => TldKind::Generic,
+    "plus" => TldKind::Generic,
+    "pm" => TldKind::Country,
+    "pn" => TldKind::Country,
+    "pnc" => TldKind::Generic,
+    "pohl" => TldKind::Generic,
+    "poker" => TldKind::Generic,
+    "politie" => TldKind::Generic,
+    "porn" => TldKind::Generic,
+    "post" => TldKind::Sponsored,
+    "pr" => TldKind::Country,
+    "pramerica" => TldKind::Generic,
+    "praxi" => TldKind::Generic,
+    "press" => TldKind::Generic,
+    "prime" => TldKind::Generic,
+    "pro" => TldKind::Generic,
+    "prod" => TldKind::Generic,
+    "productions" => TldKind::Generic,
+    "prof" => TldKind::Generic,
+    "progressive" => TldKind::Generic,
+    "promo" => TldKind::Generic,
+    "properties" => TldKind::Generic,
+    "property" => TldKind::Generic,
+    "protection" => TldKind::Generic,
+    "pru" => TldKind::Generic,
+    "prudential" => TldKind::Generic,
+    "ps" => TldKind::Country,
+    "pt" => TldKind::Country,
+    "pub" => TldKind::Generic,
+    "pw" => TldKind::Country,
+    "pwc" => TldKind::Generic,
+    "py" => TldKind::Country,
+    "qa" => TldKind::Country,
+    "qpon" => TldKind::Generic,
+    "quebec" => TldKind::Generic,
+    "quest" => TldKind::Generic,
+    "qvc" => TldKind::Generic,
+    "racing" => TldKind::Generic,
+    "radio" => TldKind::Generic,
+    "raid" => TldKind::Generic,
+    "re" => TldKind::Country,
+    "read" => TldKind::Generic,
+    "realestate" => TldKind::Generic,
+    "realtor" => TldKind::Generic,
+    "realty" => TldKind::Generic,
+    "recipes" => TldKind::Generic,
+    "red" => TldKind::Generic,
+    "redstone" => TldKind::Generic,
+    "redumbrella" => TldKind::Generic,
+    "rehab" => TldKind::Generic,
+    "reise" => TldKind::Generic,
+    "reisen" => TldKind::Generic,
+    "reit" => TldKind::Generic,
+    "reliance" => TldKind::Generic,
+    "ren" => TldKind::Generic,
+    "rent" => TldKind::Generic,
+    "rentals" => TldKind::Generic,
+    "repair" => TldKind::Generic,
+    "report" => TldKind::Generic,
+    "republican" => TldKind::Generic,
+    "rest" => TldKind::Generic,
+    "restaurant" => TldKind::Generic,
+    "review" => TldKind::Generic,
+    "reviews" => TldKind::Generic,
+    "rexroth" => TldKind::Generic,
+    "rich" => TldKind::Generic,
+    "richardli" => TldKind::Generic,
+    "ricoh" => TldKind::Generic,
+    "rightathome" => TldKind::Generic,
+    "ril" => TldKind::Generic,
+    "rio" => TldKind::Generic,
+    "rip" => TldKind::Generic,
+    "rmit" => TldKind::Generic,
+    "ro" => TldKind::Country,
+    "rocher" => TldKind::Generic,
+    "rocks" => TldKind::Generic,
+    "rodeo" => TldKind::Generic,
+    "rogers" => TldKind::Generic,
+    "room" => TldKind::Generic,
+    "rs" => TldKind::Country,
+    "rsvp" => TldKind::Generic,
+    "ru" => TldKind::Country,
+    "rugby" => TldKind::Generic,
+    "ruhr" => TldKind::Generic,
+    "run" => TldKind::Generic,
+    "rw" => TldKind::Country,
+    "rwe" => TldKind::Generic,
+    "ryukyu" => TldKind::Generic,
+    "sa" => TldKind::Country,
+    "saarland" => TldKind::Generic,
+    "safe" => TldKind::Generic,
+    "safety" => TldKind::Generic,
+    "sakura" => TldKind::Generic,
+    "sale" => TldKind::Generic,
+    "salon" => TldKind::Generic,
+    "samsclub" => TldKind::Generic,
+    "samsung" => TldKind::Generic,
+    "sandvik" => TldKind::Generic,
+    "sandvikcoromant" => TldKind::Generic,
+    "sanofi" => TldKind::Generic,
+    "sap" => TldKind::Generic,
+    "sapo" => TldKind::Generic,
+    "sarl" => TldKind::Generic,
+    "sas" => TldKind::Generic,
+    "save" => TldKind::Generic,
+    "saxo" => TldKind::Generic,
+    "sb" => TldKind::Country,
+    "sbi" => TldKind::Generic,
+    "sbs" => TldKind::Generic,
+    "sc" => TldKind::Country,
+    "sca" => TldKind::Generic,
+    "scb" => TldKind::Generic,
+    "schaeffler" => TldKind::Generic,
+    "schmidt" => TldKind::Generic,
+    "scholarships" => TldKind::Generic,
+    "school" => TldKind::Generic,
+    "schule" => TldKind::Generic,
+    "schwarz" => TldKind::Generic,
+    "science" => TldKind::Generic,
+    "scjohnson" => TldKind::Generic,
+    "scor" => TldKind::Generic,
+    "scot" => TldKind::Generic,
+    "sd" => TldKind::Country,
+    "se" => TldKind::Country,
+    "search" => TldKind::Generic,
+    "seat" => TldKind::Generic,
+    "secure" => TldKind::Generic,
+    "security" => TldKind::Generic,
+    "seek" => TldKind::Generic,
+    "select" => TldKind::Generic,
+    "sener" => TldKind::Generic,
+    "services" => TldKind::Generic,
+    "ses" => TldKind::Generic,
+    "seven" => TldKind::Generic,
+    "sew" => TldKind::Generic,
+    "sex" => TldKind::Generic,
+    "sexy" => TldKind::Generic,
+    "sfr" => TldKind::Generic,
+    "sg" => TldKind::Country,
+    "sh" => TldKind::Country,
+    "shangrila" => TldKind::Generic,
+    "sharp" => TldKind::Generic,
+    "shaw" => TldKind::Generic,
+    "shell" => TldKind::Generic,
+    "shia" => TldKind::Generic,
+    "shiksha" => TldKind::Generic,
+    "shoes" => TldKind::Generic,
+    "shop" => TldKind::Generic,
+    "shopping" => TldKind::Generic,
+    "shouji" => TldKind::Generic,
+    "show" => TldKind::Generic,
+    "showtime" => TldKind::Generic,
+    "shriram" => TldKind::Generic,
+    "si" => TldKind::Country,
+    "silk" => TldKind::Generic,
+    "sina" => TldKind::Generic,
+    "singles" => TldKind::Generic,
+    "site" => TldKind::Generic,
+    "sj" => TldKind::Country,
+    "sk" => TldKind::Country,
+    "ski" => TldKind::Generic,
+    "skin" => TldKind::Generic,
+    "sky" => TldKind::Generic,
+    "skype" => TldKind::Generic,
+    "sl" => TldKind::Country,
+    "sling" => TldKind::Generic,
+    "sm" => TldKind::Country,
+    "smart" => TldKind::Generic,
+    "smile" => TldKind::Generic,
+    "sn" => TldKind::Country,
+    "sncf" => TldKind::Generic,
+    "so" => TldKind::Country,
+    "soccer" => TldKind::Generic,
+    "social" => TldKind::Generic,
+    "softbank" => TldKind::Generic,
+    "software" => TldKind::Generic,
+    "sohu" => TldKind::Generic,
+    "solar" => TldKind::Generic,
+    "solutions" => TldKind::Generic,
+    "song" => TldKind::Generic,
+    "sony" => TldKind::Generic,
+    "soy" => TldKind::Generic,
+    "spa" => TldKind::Generic,
+    "space" => TldKind::Generic,
+    "spiegel" => TldKind::Generic,
+    "sport" => TldKind::Generic,
+    "spot" => TldKind::Generic,
+    "spreadbetting" => TldKind::Generic,
+    "sr" => TldKind::Country,
+    "srl" => TldKind::Generic,
+    "srt" => TldKind::Generic,
+    "ss" => TldKind::Country,
+    "st" => TldKind::Country,
+    "stada" => TldKind::Generic,
+    "staples" => TldKind::Generic,
+    "star" => TldKind::Generic,
+    "starhub" => TldKind::Generic,
+    "statebank" => TldKind::Generic,
+    "statefarm" => TldKind::Generic,
+    "statoil" => TldKind::Generic,
+    "stc" => TldKind::Generic,
+    "stcgroup" => TldKind::Generic,
+    "stockholm" => TldKind::Generic,
+    "storage" => TldKind::Generic,
+    "store" => TldKind::Generic,
+    "stream" => TldKind::Generic,
+    "studio" => TldKind::Generic,
+    "study" => TldKind::Generic,
+    "style" => TldKind::Generic,
+    "su" => TldKind::Country,
+    "sucks" => TldKind::Generic,
+    "supplies" => TldKind::Generic,
+    "supply" => TldKind::Generic,
+    "support" => TldKind::Generic,
+    "surf" => TldKind::Generic,
+    "surgery" => TldKind::Generic,
+    "suzuki" => TldKind::Generic,
+    "sv" => TldKind::Country,
+    "swatch" => TldKind::Generic,
+    "swiftcover" => TldKind::Generic,
+    "swiss" => TldKind::Generic,
+    "sx" => TldKind::Country,
+    "sy" => TldKind::Country,
+    "sydney" => TldKind::Generic,
+    "symantec" => TldKind::Generic,
+    "systems" => TldKind::Generic,
+    "sz" => TldKind::Country,
+    "tab" => TldKind::Generic,
+    "taipei" => TldKind::Generic,
+    "talk" => TldKind::Generic,
+    "taobao" => TldKind::Generic,
+    "target" => TldKind::Generic,
+    "tatamotors" => TldKind::Generic,
+    "tatar" => TldKind::Generic,
+    "tattoo" => TldKind::Generic,
+    "tax" => TldKind::Generic,
+    "taxi" => TldKind::Generic,
+    "tc" => TldKind::Country,
+    "tci" => TldKind::Generic,
+    "td" => TldKind::Country,
+    "tdk" => TldKind::Generic,
+    "team" => TldKind::Generic,
+    "tech" => TldKind::Generic,
+    "technology" => TldKind::Generic,
+    "tel" => TldKind::Sponsored,
+    "telecity" => TldKind::Generic,
+    "telefonica" => TldKind::Generic,
+    "temasek" => TldKind::Generic,
+    "tennis" => TldKind::Generic,
+    "teva" => TldKind::Generic,
+    "tf" => TldKind::Country,
+    "tg" => TldKind::Country,
+    "th" => TldKind::Country,
+    "thd" => TldKind::Generic,
+    "theater" => TldKind::Generic,
+    "theatre" => TldKind::Generic,
+    "tiaa" => TldKind::Generic,
+    "tickets" => TldKind::Generic,
+    "tienda" => TldKind::Generic,
+    "tiffany" => TldKind::Generic,
+    "tips" => TldKind::Generic,
+    "tires" => TldKind::Generic,
+    "tirol" => TldKind::Generic,
+    "tj" => TldKind::Country,
+    "tjmaxx" => TldKind::Generic,
+    "tjx" => TldKind::Generic,
+    "tk" => TldKind::Country,
+    "tkmaxx" => TldKind::Generic,
+    "tl" => TldKind::Country,
+    "tm" => TldKind::Country,
+    "tmall" => TldKind::Generic,
+    "tn" => TldKind::Country,
+    "to" => TldKind::Country,
+    "today" => TldKind::Generic,
+    "tokyo" => TldKind::Generic,
+    "tools" => TldKind::Generic,
+    "top" => TldKind::Generic,
+    "toray" => TldKind::Generic,
+    "toshiba" => TldKind::Generic,
+    "total" => TldKind::Generic,
+    "tours" => TldKind::Generic,
+    "town" => TldKind::Generic,
+    "toyota" => TldKind::Generic,
+    "toys" => TldKind::Generic,
+    "tp" => TldKind::Country,
+    "tr" => TldKind::Country,
+    "trade" => TldKind::Generic,
+    "trading" => TldKind::Generic,
+    "training" => TldKind::Generic,
+    "travel" => TldKind::Sponsored,
+    "travelchannel" => TldKind::Generic,
+    "travelers" => TldKind::Generic,
+    "travelersinsurance" => TldKind::Generic,
+    "trust" => TldKind::Generic,
+    "trv" => TldKind::Generic,
+    "tt" => TldKind::Country,
+    "tube" => TldKind::Generic,
+    "tui" => TldKind::Generic,
+    "tunes" => TldKind::Generic,
+    "tushu" => TldKind::Generic,
+    "tv" => TldKind::Country,
+    "tvs" => TldKind::Generic,
+    "tw" => TldKind::Country,
+    "tz" => TldKind::Country,
+    "ua" => TldKind::Country,
+    "ubank" => TldKind::Generic,
+    "ubs" => TldKind::Generic,
+    "uconnect" => TldKind::Generic,
+    "ug" => TldKind::Country,
+    "uk" => TldKind::Country,
+    "um" => TldKind::Country,
+    "unicom" => TldKind::Generic,
+    "university" => TldKind::Generic,
+    "uno" => TldKind::Generic,
+    "uol" => TldKind::Generic,
+    "ups" => TldKind::Generic,
+    "us" => TldKind::Country,
+    "uy" => TldKind::Country,
+    "uz" => TldKind::Country,
+    "va" => TldKind::Country,
+    "vacations" => TldKind::Generic,
+    "vana" => TldKind::Generic,
+    "vanguard" => TldKind::Generic,
+    "vc" => TldKind::Country,
+    "ve" => TldKind::Country,
+    "vegas" => TldKind::Generic,
+    "ventures" => TldKind::Generic,
+    "verisign" => TldKind::Generic,
+    "versicherung" => TldKind::Generic,
+    "vet" => TldKind::Generic,
+    "vg" => TldKind::Country,
+    "vi" => TldKind::Country,
+    "viajes" => TldKind::Generic,
+    "video" => TldKind::Generic,
+    "vig" => TldKind::Generic,
+    "viking" => TldKind::Generic,
+    "villas" => TldKind::Generic,
+    "vin" => TldKind::Generic,
+    "vip" => TldKind::Generic,
+    "virgin" => TldKind::Generic,
+    "visa" => TldKind::Generic,
+    "vision" => TldKind::Generic,
+    "vista" => TldKind::Generic,
+    "vistaprint" => TldKind::Generic,
+    "viva" => TldKind::Generic,
+    "vivo" => TldKind::Generic,
+    "vlaanderen" => TldKind::Generic,
+    "vn" => TldKind::Country,
+    "vodka" => TldKind::Generic,
+    "volkswagen" => TldKind::Generic,
+    "volvo" => TldKind::Generic,
+    "vote" => TldKind::Generic,
+    "voting" => TldKind::Generic,
+    "voto" => TldKind::Generic,
+    "voyage" => TldKind::Generic,
+    "vu" => TldKind::Country,
+    "vuelos" => TldKind::Generic,
+    "wales" => TldKind::Generic,
+    "walmart" => TldKind::Generic,
+    "walter" => TldKind::Generic,
+    "wang" => TldKind::Generic,
+    "wanggou" => TldKind::Generic,
+    "warman" => TldKind::Generic,
+    "watch" => TldKind::Generic,
+    "watches" => TldKind::Generic,
+    "weather" => TldKind::Generic,
+    "weatherchannel" => TldKind::Generic,
+    "webcam" => TldKind::Generic,
+    "weber" => TldKind::Generic,
+    "website" => TldKind::Generic,
+    "wed" => TldKind::Generic,
+    "wedding" => TldKind::Generic,
+    "weibo" => TldKind::Generic,
+    "weir" => TldKind::Generic,
+    "wf" => TldKind::Country,
+    "whoswho" => TldKind::Generic,
+    "wien" => TldKind::Generic,
+    "wiki" => TldKind::Generic,
+    "williamhill" => TldKind::Generic,
+    "win" => TldKind::Generic,
+    "windows" => TldKind::Generic,
+    "wine" => TldKind::Generic,
+    "winners" => TldKind::Generic,
+    "wme" => TldKind::Generic,
+    "wolterskluwer" => TldKind::Generic,
+    "woodside" => TldKind::Generic,
+    "work" => TldKind::Generic,
+    "works" => TldKind::Generic,
+    "world" => TldKind::Generic,
+    "wow" => TldKind::Generic,
+    "ws" => TldKind::Country,
+    "wtc" => TldKind::Generic,
+    "wtf" => TldKind::Generic,
+    "xbox" => TldKind::Generic,
+    "xerox" => TldKind::Generic,
+    "xfinity" => TldKind::Generic,
+    "xihuan" => TldKind::Generic,
+    "xin" => TldKind::Generic,
+    "xperia" => TldKind::Generic,
+    "xxx" => TldKind::Sponsored,
+    "xyz" => TldKind::Generic,
+    "yachts" => TldKind::Generic,
+    "yahoo" => TldKind::Generic,
+    "yamaxun" => TldKind::Generic,
+    "yandex" => TldKind::Generic,
+    "ye" => TldKind::Country,
+    "yodobashi" => TldKind::Generic,
+    "yoga" => TldKind::Generic,
+    "yokohama" => TldKind::Generic,
+    "you" => TldKind::Generic,
+    "youtube" => TldKind::Generic,
+    "yt" => TldKind::Country,
+    "yun" => TldKind::Generic,
+    "za" => TldKind::Country,
+    "zappos" => TldKind::Generic,
+    "zara" => TldKind::Generic,
+    "zero" => TldKind::Generic,
+    "zip" => TldKind::Generic,
+    "zippo" => TldKind::Generic,
+    "zm" => TldKind::Country,
+    "zone" => TldKind::Generic,
+    "zuerich" => TldKind::Generic,
+    "zw" => TldKind::Country,
+    "vermögensberater" => TldKind::Generic,
+    "vermögensberatung" => TldKind::Generic,
+    "ελ" => TldKind::Country,
+    "ευ" => TldKind::Generic,
+    "бг" => TldKind::Country,
+    "бел" => TldKind::Country,
+    "дети" => TldKind::Generic,
+    "ею" => TldKind::Country,
+    "католик" => TldKind::Generic,
+    "ком" => TldKind::Generic,
+    "мкд" => TldKind::Country,
+    "мон" => TldKind::Country,
+    "москва" => TldKind::Generic,
+    "онлайн" => TldKind::Generic,
+    "орг" => TldKind::Generic,
+    "рус" => TldKind::Generic,
+    "рф" => TldKind::Country,
+    "сайт" => TldKind::Generic,
+    "срб" => TldKind::Country,
+    "укр" => TldKind::Country,
+    "қаз" => TldKind::Country,
+    "հայ" => TldKind::Country,
+    "ישראל" => TldKind::Generic,
+    "קום" => TldKind::Generic,
+    "ابوظبي" => TldKind::Generic,
+    "اتصالات" => TldKind::Generic,
+    "ارامكو" => TldKind::Generic,
+    "الاردن" => TldKind::Country,
+    "البحرين" => TldKind::Generic,
+    "الجزائر" => TldKind::Country,
+    "السعودية" => TldKind::Country,
+    "العليان" => TldKind::Generic,
+    "المغرب" => TldKind::Country,
+    "امارات" => TldKind::Country,
+    "ایران" => TldKind::Country,
+    "بارت" => TldKind::Country,
+    "بازار" => TldKind::Generic,
+    "بيتك" => TldKind::Generic,
+    "بھارت" => TldKind::Country,
+    "تونس" => TldKind::Country,
+    "سودان" => TldKind::Country,
+    "سورية" => TldKind::Country,
+    "شبكة" => TldKind::Generic,
+    "عراق" => TldKind::Country,
+    "عرب" => TldKind::Generic,
+    "عمان" => TldKind::Country,
+    "فلسطين" => TldKind::Country,
+    "قطر" => TldKind::Country,
+    "كاثوليك" => TldKind::Generic,
+    "كوم" => TldKind::Generic,
+    "مصر" => TldKind::Country,
+    "مليسيا" => TldKind::Country,
+    "موبايلي" => TldKind::Generic,
+    "موريتانيا" => TldKind::Country,
+    "موقع" => TldKind::Generic,
+    "همراه" => TldKind::Generic,
+    "پاکستان" => TldKind::Country,
+    "ڀارت" => TldKind::Country,
+    "कॉम" => TldKind::Generic,
+    "नेट" => TldKind::Generic,
+    "भारत" => TldKind::Country,
+    "भारतम्" => TldKind::Country,
+    "भारोत" => TldKind::Country,
+    "संगठन" => TldKind::Generic,
+    "বাংলা" => TldKind::Country,
+    "ভারত" => TldKind::Country,
+    "ভাৰত" => TldKind::Country,
+    "ਭਾਰਤ" => TldKind::Country,
+    "ભારત" => TldKind::Country,
+    "ଭାରତ" => TldKind::Country,
+    "இந்தியா" => TldKind::Country,
+    "இலங்கை" => TldKind::Country,
+    "சிங்கப்பூர்" => TldKind::Country,
+    "భారత్" => TldKind::Country,
+    "ಭಾರತ" => TldKind::Country,
+    "ഭാരതം" => TldKind::Country,
+    "ලංකා" => TldKind::Country,
+    "คอม" => TldKind::Generic,
+    "ไทย" => TldKind::Country,
+    "ລາວ" => TldKind::Generic,
+    "გე" => TldKind::Country,
+    "みんな" => TldKind::Generic,
+    "アマゾン" => TldKind::Generic,
+    "クラウド" => TldKind::Generic,
+    "グーグル" => TldKind::Generic,
+    "コム" => TldKind::Generic,
+    "ストア" => TldKind::Generic,
+    "セール" => TldKind::Generic,
+    "ファッション" => TldKind::Generic,
+    "ポイント" => TldKind::Generic,
+    "世界" => TldKind::Generic,
+    "中信" => TldKind::Generic,
+    "中国" => TldKind::Country,
+    "中國" => TldKind::Country,
+    "中文网" => TldKind::Generic,
+    "亚马逊" => TldKind::Generic,
+    "企业" => TldKind::Generic,
+    "佛山" => TldKind::Generic,
+    "信息" => TldKind::Generic,
+    "健康" => TldKind::Generic,
+    "八卦" => TldKind::Generic,
+    "公司" => TldKind::Generic,
+    "公益" => TldKind::Generic,
+    "台湾" => TldKind::Country,
+    "台灣" => TldKind::Country,
+    "商城" => TldKind::Generic,
+    "商店" => TldKind::Generic,
+    "商标" => TldKind::Generic,
+    "嘉里" => TldKind::Generic,
+    "嘉里大酒店" => TldKind::Generic,
+    "在线" => TldKind::Generic,
+    "大众汽车" => TldKind::Generic,
+    "大拿" => TldKind::Generic,
+    "天主教" => TldKind::Generic,
+    "娱乐" => TldKind::Generic,
+    "家電" => TldKind::Generic,
+    "工行" => TldKind::Generic,
+    "广东" => TldKind::Generic,
+    "微博" => TldKind::Generic,
+    "慈善" => TldKind::Generic,
+    "我爱你" => TldKind::Generic,
+    "手机" => TldKind::Generic,
+    "手表" => TldKind::Generic,
+    "招聘" => TldKind::Generic,
+    "政务" => TldKind::Generic,
+    "政府" => TldKind::Generic,
+    "新加坡" => TldKind::Country,
+    "新闻" => TldKind::Generic,
+    "时尚" => TldKind::Generic,
+    "書籍" => TldKind::Generic,
+    "机构" => TldKind::Generic,
+    "淡马锡" => TldKind::Generic,
+    "游戏" => TldKind::Generic,
+    "澳門" => TldKind::Country,
+    "点看" => TldKind::Generic,
+    "珠宝" => TldKind::Generic,
+    "移动" => TldKind::Generic,
+    "组织机构" => TldKind::Generic,
+    "网址" => TldKind::Generic,
+    "网店" => TldKind::Generic,
+    "网站" => TldKind::Generic,
+    "网络" => TldKind::Generic,
+    "联通" => TldKind::Generic,
+    "诺基亚" => TldKind::Generic,
+    "谷歌" => TldKind::Generic,
+    "购物" => TldKind::Generic,
+    "通販" => TldKind::Generic,
+    "集团" => TldKind::Generic,
+    "電訊盈科" => TldKind::Generic,
+    "飞利浦" => TldKind::Generic,
+    "食品" => TldKind::Generic,
+    "餐厅" => TldKind::Generic,
+    "香格里拉" => TldKind::Generic,
+    "香港" => TldKind::Country,
+    "닷넷" => TldKind::Generic,
+    "닷컴" => TldKind::Generic,
+    "삼성" => TldKind::Generic,
+    "한국" => TldKind::Country,
     // Punycode TLDs (IDN TLDs in ASCII-compatible encoding)
-    "xn--11b4c3d",
-    "xn--1ck2e1b",
-    "xn--1qqw23a",
-    "xn--2scrj9c",
-    "xn--30rr7y",
-    "xn--3bst00m",
-    "xn--3ds443g",
-    "xn--3e0b707e",
-    "xn--3hcrj9c",
-    "xn--3oq18vl8pn36a",
-    "xn--3pxu8k",
-    "xn--42c2d9a",
-    "xn--45br5cyl",
-    "xn--45brj9c",
-    "xn--45q11c",
-    "xn--4dbrk0ce",
-    "xn--4gbrim",
-    "xn--54b7fta0cc",
-    "xn--55qw42g",
-    "xn--55qx5d",
-    "xn--5su34j936bgsg",
-    "xn--5tzm5g",
-    "xn--6frz82g",
-    "xn--6qq986b3xl",
-    "xn--80adxhks",
-    "xn--80ao21a",
-    "xn--80aqecdr1a",
-    "xn--80asehdb",
-    "xn--80aswg",
-    "xn--8y0a063a",
-    "xn--90a3ac",
-    "xn--90ae",
-    "xn--90ais",
-    "xn--9dbq2a",
-    "xn--9et52u",
-    "xn--9krt00a",
-    "xn--b4w605ferd",
-    "xn--bck1b9a5dre4c",
-    "xn--c1avg",
-    "xn--c2br7g",
-    "xn--cck2b3b",
-    "xn--cckwcxetd",
-    "xn--cg4bki",
-    "xn--clchc0ea0b2g2a9gcd",
-    "xn--czr694b",
-    "xn--czrs0t",
-    "xn--czru2d",
-    "xn--d1acj3b",
-    "xn--d1alf",
-    "xn--e1a4c",
-    "xn--eckvdtc9d",
-    "xn--efvy88h",
-    "xn--estv75g",
-    "xn--fct429k",
-    "xn--fhbei",
-    "xn--fiq228c5hs",
-    "xn--fiq64b",
-    "xn--fiqs8s",
-    "xn--fiqz9s",
-    "xn--fjq720a",
-    "xn--flw351e",
-    "xn--fpcrj9c3d",
-    "xn--fzc2c9e2c",
-    "xn--fzys8d69uvgm",
-    "xn--g2xx48c",
-    "xn--gckr3f0f",
-    "xn--gecrj9c",
-    "xn--gk3at1e",
-    "xn--h2breg3eve",
-    "xn--h2brj9c",
-    "xn--h2brj9c8c",
-    "xn--hxt814e",
-    "xn--i1b6b1a6a2e",
-    "xn--imr513n",
-    "xn--io0a7i",
-    "xn--j1aef",
-    "xn--j1amh",
-    "xn--j6w193g",
-    "xn--jlq480n2rg",
-    "xn--jlq61u9w7b",
-    "xn--jvr189m",
-    "xn--kcrx77d1x4a",
-    "xn--kprw13d",
-    "xn--kpry57d",
-    "xn--kpu716f",
-    "xn--kput3i",
-    "xn--l1acc",
-    "xn--lgbbat1ad8j",
-    "xn--mgb9awbf",
-    "xn--mgba3a3ejt",
-    "xn--mgba3a4f16a",
-    "xn--mgba7c0bbn0a",
-    "xn--mgbaakc7dvf",
-    "xn--mgbaam7a8h",
-    "xn--mgbab2bd",
-    "xn--mgbah1a3hjkrd",
-    "xn--mgbai9azgqp6j",
-    "xn--mgbayh7gpa",
-    "xn--mgbb9fbpob",
-    "xn--mgbbh1a",
-    "xn--mgbbh1a71e",
-    "xn--mgbc0a9azcg",
-    "xn--mgbca7dzdo",
-    "xn--mgbcpq6gpa1a",
-    "xn--mgberp4a5d4ar",
-    "xn--mgbgu82a",
-    "xn--mgbi4ecexp",
-    "xn--mgbpl2fh",
-    "xn--mgbt3dhd",
-    "xn--mgbtx2b",
-    "xn--mgbx4cd0ab",
-    "xn--mix891f",
-    "xn--mk1bu44c",
-    "xn--mxtq1m",
-    "xn--ngbc5azd",
-    "xn--ngbe9e0a",
-    "xn--ngbrx",
-    "xn--node",
-    "xn--nqv7f",
-    "xn--nqv7fs00ema",
-    "xn--nyqy26a",
-    "xn--o3cw4h",
-    "xn--ogbpf8fl",
-    "xn--otu796d",
-    "xn--p1acf",
-    "xn--p1ai",
-    "xn--pbt977c",
-    "xn--pgbs0dh",
-    "xn--pssy2u",
-    "xn--q7ce6a",
-    "xn--q9jyb4c",
-    "xn--qcka1pmc",
-    "xn--qxa6a",
-    "xn--qxam",
-    "xn--rhqv96g",
-    "xn--rovu88b",
-    "xn--rvc1e0am3e",
-    "xn--s9brj9c",
-    "xn--ses554g",
-    "xn--t60b56a",
-    "xn--tckwe",
-    "xn--tiq49xqyj",
-    "xn--unup4y",
-    "xn--vermgensberater-ctb",
-    "xn--vermgensberatung-pwb",
-    "xn--vhquv",
-    "xn--vuq861b",
-    "xn--w4r85el8fhu5dnra",
-    "xn--w4rs40l",
-    "xn--wgbh1c",
-    "xn--wgbl6a",
-    "xn--xhq521b",
-    "xn--xkc2al3hye2a",
-    "xn--xkc2dl3a5ee0h",
-    "xn--y9a3aq",
-    "xn--yfro4i67o",
-    "xn--ygbi2ammx",
-    "xn--zfr164b",
+    "xn--11b4c3d" => TldKind::Generic,
+    "xn--1ck2e1b" => TldKind::Generic,
+    "xn--1qqw23a" => TldKind::Generic,
+    "xn--2scrj9c" => TldKind::Country,
+    "xn--30rr7y" => TldKind::Generic,
+    "xn--3bst00m" => TldKind::Generic,
+    "xn--3ds443g" => TldKind::Generic,
+    "xn--3e0b707e" => TldKind::Country,
+    "xn--3hcrj9c" => TldKind::Country,
+    "xn--3oq18vl8pn36a" => TldKind::Generic,
+    "xn--3pxu8k" => TldKind::Generic,
+    "xn--42c2d9a" => TldKind::Generic,
+    "xn--45br5cyl" => TldKind::Country,
+    "xn--45brj9c" => TldKind::Country,
+    "xn--45q11c" => TldKind::Generic,
+    "xn--4dbrk0ce" => TldKind::Generic,
+    "xn--4gbrim" => TldKind::Generic,
+    "xn--54b7fta0cc" => TldKind::Country,
+    "xn--55qw42g" => TldKind::Generic,
+    "xn--55qx5d" => TldKind::Generic,
+    "xn--5su34j936bgsg" => TldKind::Generic,
+    "xn--5tzm5g" => TldKind::Generic,
+    "xn--6frz82g" => TldKind::Generic,
+    "xn--6qq986b3xl" => TldKind::Generic,
+    "xn--80adxhks" => TldKind::Generic,
+    "xn--80ao21a" => TldKind::Country,
+    "xn--80aqecdr1a" => TldKind::Generic,
+    "xn--80asehdb" => TldKind::Generic,
+    "xn--80aswg" => TldKind::Generic,
+    "xn--8y0a063a" => TldKind::Generic,
+    "xn--90a3ac" => TldKind::Country,
+    "xn--90ae" => TldKind::Country,
+    "xn--90ais" => TldKind::Country,
+    "xn--9dbq2a" => TldKind::Generic,
+    "xn--9et52u" => TldKind::Generic,
+    "xn--9krt00a" => TldKind::Generic,
+    "xn--b4w605ferd" => TldKind::Generic,
+    "xn--bck1b9a5dre4c" => TldKind::Generic,
+    "xn--c1avg" => TldKind::Generic,
+    "xn--c2br7g" => TldKind::Generic,
+    "xn--cck2b3b" => TldKind::Generic,
+    "xn--cckwcxetd" => TldKind::Generic,
+    "xn--cg4bki" => TldKind::Generic,
+    "xn--clchc0ea0b2g2a9gcd" => TldKind::Country,
+    "xn--czr694b" => TldKind::Generic,
+    "xn--czrs0t" => TldKind::Generic,
+    "xn--czru2d" => TldKind::Generic,
+    "xn--d1acj3b" => TldKind::Generic,
+    "xn--d1alf" => TldKind::Country,
+    "xn--e1a4c" => TldKind::Country,
+    "xn--eckvdtc9d" => TldKind::Generic,
+    "xn--efvy88h" => TldKind::Generic,
+    "xn--estv75g" => TldKind::Generic,
+    "xn--fct429k" => TldKind::Generic,
+    "xn--fhbei" => TldKind::Generic,
+    "xn--fiq228c5hs" => TldKind::Generic,
+    "xn--fiq64b" => TldKind::Generic,
+    "xn--fiqs8s" => TldKind::Country,
+    "xn--fiqz9s" => TldKind::Country,
+    "xn--fjq720a" => TldKind::Generic,
+    "xn--flw351e" => TldKind::Generic,
+    "xn--fpcrj9c3d" => TldKind::Country,
+    "xn--fzc2c9e2c" => TldKind::Country,
+    "xn--fzys8d69uvgm" => TldKind::Generic,
+    "xn--g2xx48c" => TldKind::Generic,
+    "xn--gckr3f0f" => TldKind::Generic,
+    "xn--gecrj9c" => TldKind::Country,
+    "xn--gk3at1e" => TldKind::Generic,
+    "xn--h2breg3eve" => TldKind::Country,
+    "xn--h2brj9c" => TldKind::Country,
+    "xn--h2brj9c8c" => TldKind::Country,
+    "xn--hxt814e" => TldKind::Generic,
+    "xn--i1b6b1a6a2e" => TldKind::Generic,
+    "xn--imr513n" => TldKind::Generic,
+    "xn--io0a7i" => TldKind::Generic,
+    "xn--j1aef" => TldKind::Generic,
+    "xn--j1amh" => TldKind::Country,
+    "xn--j6w193g" => TldKind::Country,
+    "xn--jlq480n2rg" => TldKind::Generic,
+    "xn--jlq61u9w7b" => TldKind::Generic,
+    "xn--jvr189m" => TldKind::Generic,
+    "xn--kcrx77d1x4a" => TldKind::Generic,
+    "xn--kprw13d" => TldKind::Country,
+    "xn--kpry57d" => TldKind::Country,
+    "xn--kpu716f" => TldKind::Generic,
+    "xn--kput3i" => TldKind::Generic,
+    "xn--l1acc" => TldKind::Country,
+    "xn--lgbbat1ad8j" => TldKind::Country,
+    "xn--mgb9awbf" => TldKind::Country,
+    "xn--mgba3a3ejt" => TldKind::Generic,
+    "xn--mgba3a4f16a" => TldKind::Country,
+    "xn--mgba7c0bbn0a" => TldKind::Generic,
+    "xn--mgbaakc7dvf" => TldKind::Generic,
+    "xn--mgbaam7a8h" => TldKind::Country,
+    "xn--mgbab2bd" => TldKind::Generic,
+    "xn--mgbah1a3hjkrd" => TldKind::Country,
+    "xn--mgbai9azgqp6j" => TldKind::Country,
+    "xn--mgbayh7gpa" => TldKind::Country,
+    "xn--mgbb9fbpob" => TldKind::Generic,
+    "xn--mgbbh1a" => TldKind::Country,
+    "xn--mgbbh1a71e" => TldKind::Country,
+    "xn--mgbc0a9azcg" => TldKind::Country,
+    "xn--mgbca7dzdo" => TldKind::Generic,
+    "xn--mgbcpq6gpa1a" => TldKind::Generic,
+    "xn--mgberp4a5d4ar" => TldKind::Country,
+    "xn--mgbgu82a" => TldKind::Country,
+    "xn--mgbi4ecexp" => TldKind::Generic,
+    "xn--mgbpl2fh" => TldKind::Country,
+    "xn--mgbt3dhd" => TldKind::Generic,
+    "xn--mgbtx2b" => TldKind::Country,
+    "xn--mgbx4cd0ab" => TldKind::Country,
+    "xn--mix891f" => TldKind::Country,
+    "xn--mk1bu44c" => TldKind::Generic,
+    "xn--mxtq1m" => TldKind::Generic,
+    "xn--ngbc5azd" => TldKind::Generic,
+    "xn--ngbe9e0a" => TldKind::Generic,
+    "xn--ngbrx" => TldKind::Generic,
+    "xn--node" => TldKind::Country,
+    "xn--nqv7f" => TldKind::Generic,
+    "xn--nqv7fs00ema" => TldKind::Generic,
+    "xn--nyqy26a" => TldKind::Generic,
+    "xn--o3cw4h" => TldKind::Country,
+    "xn--ogbpf8fl" => TldKind::Country,
+    "xn--otu796d" => TldKind::Generic,
+    "xn--p1acf" => TldKind::Generic,
+    "xn--p1ai" => TldKind::Country,
+    "xn--pbt977c" => TldKind::Generic,
+    "xn--pgbs0dh" => TldKind::Country,
+    "xn--pssy2u" => TldKind::Generic,
+    "xn--q7ce6a" => TldKind::Generic,
+    "xn--q9jyb4c" => TldKind::Generic,
+    "xn--qcka1pmc" => TldKind::Generic,
+    "xn--qxa6a" => TldKind::Generic,
+    "xn--qxam" => TldKind::Country,
+    "xn--rhqv96g" => TldKind::Generic,
+    "xn--rovu88b" => TldKind::Generic,
+    "xn--rvc1e0am3e" => TldKind::Country,
+    "xn--s9brj9c" => TldKind::Country,
+    "xn--ses554g" => TldKind::Generic,
+    "xn--t60b56a" => TldKind::Generic,
+    "xn--tckwe" => TldKind::Generic,
+    "xn--tiq49xqyj" => TldKind::Generic,
+    "xn--unup4y" => TldKind::Generic,
+    "xn--vermgensberater-ctb" => TldKind::Generic,
+    "xn--vermgensberatung-pwb" => TldKind::Generic,
+    "xn--vhquv" => TldKind::Generic,
+    "xn--vuq861b" => TldKind::Generic,
+    "xn--w4r85el8fhu5dnra" => TldKind::Generic,
+    "xn--w4rs40l" => TldKind::Generic,
+    "xn--wgbh1c" => TldKind::Country,
+    "xn--wgbl6a" => TldKind::Country,
+    "xn--xhq521b" => TldKind::Generic,
+    "xn--xkc2al3hye2a" => TldKind::Country,
+    "xn--xkc2dl3a5ee0h" => TldKind::Country,
+    "xn--y9a3aq" => TldKind::Country,
+    "xn--yfro4i67o" => TldKind::Country,
+    "xn--ygbi2ammx" => TldKind::Country,
+    "xn--zfr164b" => TldKind::Generic,
 };
 
 /// Check if a string is a valid TLD.
 /// The input should be lowercase.
 #[inline]
 pub fn is_valid_tld(tld: &str) -> bool {
-    TLDS.contains(tld)
+    TLDS.contains_key(tld)
 }
 
 /// Maximum TLD length in bytes (longest is "சிங்கப்பூர்" at 33 bytes)
@@ -1771,9 +1783,16 @@ const MAX_TLD_LEN: usize = 64;
 /// Uses a stack-allocated buffer for lowercase conversion to avoid heap allocations.
 #[inline]
 pub fn is_valid_tld_case_insensitive(tld: &str) -> bool {
+    tld_kind(tld).is_some()
+}
+
+/// Look up the category of a TLD, case-insensitively.
+/// Returns None if the string is not a valid TLD.
+#[inline]
+pub fn tld_kind(tld: &str) -> Option<TldKind> {
     // Fast path: if already lowercase ASCII, check directly
     if tld.bytes().all(|b| !b.is_ascii_uppercase()) {
-        return TLDS.contains(tld);
+        return TLDS.get(tld).copied();
     }
 
     // Need to lowercase - use stack buffer if small enough
@@ -1785,7 +1804,7 @@ pub fn is_valid_tld_case_insensitive(tld: &str) -> bool {
                 let len = lc.len_utf8();
                 if i + len > MAX_TLD_LEN {
                     // Overflow - fall back to heap allocation
-                    return TLDS.contains(&tld.to_lowercase());
+                    return TLDS.get(tld.to_lowercase().as_str()).copied();
                 }
                 lc.encode_utf8(&mut buf[i..]);
                 i += len;
@@ -1793,10 +1812,10 @@ pub fn is_valid_tld_case_insensitive(tld: &str) -> bool {
         }
         // Safety: we only wrote valid UTF-8 chars
         let lowered = unsafe { std::str::from_utf8_unchecked(&buf[..i]) };
-        TLDS.contains(lowered)
+        TLDS.get(lowered).copied()
     } else {
         // TLD too long - fall back to heap allocation (shouldn't happen with valid TLDs)
-        TLDS.contains(&tld.to_lowercase())
+        TLDS.get(tld.to_lowercase().as_str()).copied()
     }
 }
 
@@ -1827,6 +1846,17 @@ mod tests {
         assert!(is_valid_tld("xn--mgbaam7a8h")); // .امارات (UAE)
     }
 
+    #[test]
+    fn test_tld_kinds() {
+        assert_eq!(tld_kind("com"), Some(TldKind::Generic));
+        assert_eq!(tld_kind("CO"), Some(TldKind::Country));
+        assert_eq!(tld_kind("edu"), Some(TldKind::Sponsored));
+        assert_eq!(tld_kind("рф"), Some(TldKind::Country));
+        assert_eq!(tld_kind("xn--p1ai"), Some(TldKind::Country));
+        assert_eq!(tld_kind("みんな"), Some(TldKind::Generic));
+        assert_eq!(tld_kind("notarealtld"), None);
+    }
+
     #[test]
     fn test_invalid_tlds() {
         assert!(!is_valid_tld("invalid"));
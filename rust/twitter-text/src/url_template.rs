@@ -0,0 +1,88 @@
+// Copyright 2025 Robert Sayre
+// Licensed under the Apache License, Version 2.0
+// http://www.apache.org/licenses/LICENSE-2.0
+
+//! Href templates for the autolinkers.
+//!
+//! A template is a URL with named placeholders, such as
+//! `https://x.com/hashtag/{tag}?src=hashtag_click`. Each placeholder is
+//! replaced with its value, percent-encoded so that it stays a single path
+//! segment or query value. Braces that don't name a known placeholder are
+//! copied as they are.
+
+/// Expand `template`, replacing each `{name}` in `values` with the
+/// percent-encoded value.
+pub(crate) fn expand(template: &str, values: &[(&str, &str)]) -> String {
+    let mut buf = String::with_capacity(template.len() + 16);
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        buf.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let value = after.find('}').and_then(|close| {
+            let name = &after[..close];
+            values
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, value)| (close, *value))
+        });
+        match value {
+            Some((close, value)) => {
+                percent_encode(value, &mut buf);
+                rest = &after[close + 1..];
+            }
+            None => {
+                buf.push('{');
+                rest = after;
+            }
+        }
+    }
+    buf.push_str(rest);
+    buf
+}
+
+/// Percent-encode everything but the unreserved characters of RFC 3986.
+pub(crate) fn percent_encode(value: &str, buf: &mut String) {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    for &b in value.as_bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            buf.push(b as char);
+        } else {
+            buf.push('%');
+            buf.push(HEX[(b >> 4) as usize] as char);
+            buf.push(HEX[(b & 0xf) as usize] as char);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand() {
+        assert_eq!(
+            expand(
+                "https://x.com/hashtag/{tag}?src=hashtag_click",
+                &[("tag", "日本語")]
+            ),
+            "https://x.com/hashtag/%E6%97%A5%E6%9C%AC%E8%AA%9E?src=hashtag_click"
+        );
+        assert_eq!(
+            expand(
+                "https://{domain}/@{user}",
+                &[("domain", "b.social"), ("user", "a_b")]
+            ),
+            "https://b.social/@a_b"
+        );
+        // Reserved characters in the value are encoded.
+        assert_eq!(
+            expand("https://e.com/s?q={tag}", &[("tag", "a&b c/d")]),
+            "https://e.com/s?q=a%26b%20c%2Fd"
+        );
+        // Unknown and unclosed placeholders are left alone.
+        assert_eq!(
+            expand("https://e.com/{x}/{tag}{", &[("tag", "t")]),
+            "https://e.com/{x}/t{"
+        );
+    }
+}
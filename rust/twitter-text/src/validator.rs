@@ -3,8 +3,9 @@
 // http://www.apache.org/licenses/LICENSE-2.0
 
 use crate::extractor::{Extract, Extractor};
-use crate::nom_parser::url::{parse_url, parse_url_without_protocol};
+use crate::nom_parser::url::{parse_url, parse_url_with_tlds, parse_url_without_protocol};
 use crate::parse_with_parser_backend;
+use crate::tld_set::TldSet;
 use crate::ParserBackend;
 use twitter_text_config;
 
@@ -105,6 +106,9 @@ impl Validator {
     }
 
     pub fn is_valid_url(&self, s: &str) -> bool {
+        if self.rejects_tld(s) {
+            return false;
+        }
         match self.parser_backend {
             ParserBackend::Nom => {
                 // Use Nom parser
@@ -121,6 +125,9 @@ impl Validator {
     }
 
     pub fn is_valid_url_without_protocol(&self, s: &str) -> bool {
+        if self.rejects_tld(s) {
+            return false;
+        }
         match self.parser_backend {
             ParserBackend::Nom => {
                 // Use Nom parser
@@ -136,6 +143,33 @@ impl Validator {
         }
     }
 
+    // The URL parsers accept any well-formed TLD, so a TLD set with a custom
+    // list, an allowlist or a denylist is checked against the host here.
+    fn rejects_tld(&self, s: &str) -> bool {
+        let tlds = self.extractor.get_tld_set();
+        if tlds.is_unmodified_builtin() {
+            return false;
+        }
+        let host = match parse_url_with_tlds(s, tlds).or_else(|_| parse_url_without_protocol(s)) {
+            Ok((_, (_, start, end))) => &s[start..end],
+            Err(_) => return false,
+        };
+        if host.starts_with('[') || host.parse::<std::net::Ipv4Addr>().is_ok() {
+            return false;
+        }
+        let host = host.strip_suffix('.').unwrap_or(host);
+        !tlds.contains(host.rsplit('.').next().unwrap_or(host))
+    }
+
+    pub fn get_tld_set(&self) -> &TldSet {
+        self.extractor.get_tld_set()
+    }
+
+    /// Set the TLDs used for URL validation, including any allowlist or denylist.
+    pub fn set_tld_set(&mut self, tld_set: TldSet) {
+        self.extractor.set_tld_set(tld_set);
+    }
+
     pub fn get_max_tweet_length(&self) -> i32 {
         MAX_TWEET_LENGTH
     }
@@ -156,3 +190,47 @@ impl Validator {
         self.short_url_length_https = short_url_length_https;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_denied_tlds_are_invalid_urls() {
+        let tlds = TldSet::default().with_denied(["zip", "mov"]).unwrap();
+        for backend in [ParserBackend::Nom, ParserBackend::External] {
+            let mut validator = Validator::with_parser_backend(backend);
+            assert!(validator.is_valid_url("https://example.zip"));
+            validator.set_tld_set(tlds.clone());
+            assert!(!validator.is_valid_url("https://example.zip"));
+            assert!(!validator.is_valid_url("https://user@example.MOV:8080/a"));
+            assert!(!validator.is_valid_url_without_protocol("example.zip"));
+            assert!(validator.is_valid_url("https://example.com/file.zip"));
+        }
+    }
+
+    #[test]
+    fn test_allowed_tlds_are_valid_urls() {
+        let tlds = TldSet::from_tlds(["org"])
+            .unwrap()
+            .with_allowed(["corp", "internal"])
+            .unwrap();
+        for backend in [ParserBackend::Nom, ParserBackend::External] {
+            let mut validator = Validator::with_parser_backend(backend);
+            assert!(validator.is_valid_url("https://example.com"));
+            validator.set_tld_set(tlds.clone());
+            assert!(
+                validator.is_valid_url("https://wiki.corp/page"),
+                "{:?}",
+                backend
+            );
+            assert!(validator.is_valid_url("https://build.INTERNAL:8080/job"));
+            assert!(validator.is_valid_url("https://example.org"));
+            assert!(validator.is_valid_url_without_protocol("wiki.corp"));
+            assert!(validator.is_valid_url("http://192.168.0.1/"));
+            assert!(!validator.is_valid_url("https://example.com"));
+            assert!(!validator.is_valid_url("https://user@example.com/a.corp"));
+            assert!(!validator.is_valid_url_without_protocol("example.com"));
+        }
+    }
+}
@@ -1,29 +1,11 @@
-use twitter_text::autolinker::{
-    Autolinker as RustAutolinker, DEFAULT_CASHTAG_CLASS, DEFAULT_CASHTAG_URL_BASE,
-    DEFAULT_HASHTAG_CLASS, DEFAULT_HASHTAG_URL_BASE, DEFAULT_INVISIBLE_TAG_ATTRS,
-    DEFAULT_LIST_CLASS, DEFAULT_LIST_URL_BASE, DEFAULT_USERNAME_CLASS, DEFAULT_USERNAME_URL_BASE,
-};
-use twitter_text::extractor::{Extract, Extractor};
+use js_sys::Array;
+use twitter_text::autolinker::{AutolinkOptions, Autolinker as RustAutolinker, EmojiRendering};
+use twitter_text::segment::Segment as RustSegment;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
 pub struct Autolinker {
-    no_follow: bool,
-    url_class: String,
-    url_target: String,
-    symbol_tag: String,
-    text_with_symbol_tag: String,
-    list_class: String,
-    username_class: String,
-    hashtag_class: String,
-    cashtag_class: String,
-    username_url_base: String,
-    list_url_base: String,
-    hashtag_url_base: String,
-    cashtag_url_base: String,
-    invisible_tag_attrs: String,
-    username_include_symbol: bool,
-    include_data_screen_name: bool,
+    options: AutolinkOptions,
 }
 
 #[wasm_bindgen]
@@ -31,153 +13,230 @@ impl Autolinker {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Autolinker {
         Autolinker {
-            no_follow: false,
-            url_class: String::new(),
-            url_target: String::new(),
-            symbol_tag: String::new(),
-            text_with_symbol_tag: String::new(),
-            list_class: DEFAULT_LIST_CLASS.to_string(),
-            username_class: DEFAULT_USERNAME_CLASS.to_string(),
-            hashtag_class: DEFAULT_HASHTAG_CLASS.to_string(),
-            cashtag_class: DEFAULT_CASHTAG_CLASS.to_string(),
-            username_url_base: DEFAULT_USERNAME_URL_BASE.to_string(),
-            list_url_base: DEFAULT_LIST_URL_BASE.to_string(),
-            hashtag_url_base: DEFAULT_HASHTAG_URL_BASE.to_string(),
-            cashtag_url_base: DEFAULT_CASHTAG_URL_BASE.to_string(),
-            invisible_tag_attrs: DEFAULT_INVISIBLE_TAG_ATTRS.to_string(),
-            username_include_symbol: false,
-            include_data_screen_name: false,
+            options: AutolinkOptions::default(),
         }
     }
 
+    /// An Autolinker with options from JSON, using the camelCase property
+    /// names. Missing options take their defaults.
+    #[wasm_bindgen(js_name = "fromJson")]
+    pub fn from_json(json: &str) -> Result<Autolinker, JsValue> {
+        match AutolinkOptions::try_from_json(json) {
+            Ok(options) => Ok(Autolinker { options }),
+            Err(e) => Err(JsValue::from_str(&format!(
+                "Failed to parse autolink options: {}",
+                e
+            ))),
+        }
+    }
+
+    /// The options as JSON.
+    #[wasm_bindgen(js_name = "toJson")]
+    pub fn to_json(&self) -> String {
+        self.options.to_json()
+    }
+
     #[wasm_bindgen(getter, js_name = "noFollow")]
     pub fn no_follow(&self) -> bool {
-        self.no_follow
+        self.options.no_follow
     }
 
     #[wasm_bindgen(setter, js_name = "noFollow")]
     pub fn set_no_follow(&mut self, no_follow: bool) {
-        self.no_follow = no_follow;
+        self.options.no_follow = no_follow;
     }
 
     #[wasm_bindgen(getter, js_name = "urlClass")]
     pub fn url_class(&self) -> String {
-        self.url_class.clone()
+        self.options.url_class.clone()
     }
 
     #[wasm_bindgen(setter, js_name = "urlClass")]
     pub fn set_url_class(&mut self, url_class: String) {
-        self.url_class = url_class;
+        self.options.url_class = url_class;
     }
 
     #[wasm_bindgen(getter, js_name = "urlTarget")]
     pub fn url_target(&self) -> String {
-        self.url_target.clone()
+        self.options.url_target.clone()
     }
 
     #[wasm_bindgen(setter, js_name = "urlTarget")]
     pub fn set_url_target(&mut self, url_target: String) {
-        self.url_target = url_target;
+        self.options.url_target = url_target;
     }
 
     #[wasm_bindgen(getter, js_name = "listClass")]
     pub fn list_class(&self) -> String {
-        self.list_class.clone()
+        self.options.list_class.clone()
     }
 
     #[wasm_bindgen(setter, js_name = "listClass")]
     pub fn set_list_class(&mut self, list_class: String) {
-        self.list_class = list_class;
+        self.options.list_class = list_class;
     }
 
     #[wasm_bindgen(getter, js_name = "usernameClass")]
     pub fn username_class(&self) -> String {
-        self.username_class.clone()
+        self.options.username_class.clone()
     }
 
     #[wasm_bindgen(setter, js_name = "usernameClass")]
     pub fn set_username_class(&mut self, username_class: String) {
-        self.username_class = username_class;
+        self.options.username_class = username_class;
     }
 
     #[wasm_bindgen(getter, js_name = "hashtagClass")]
     pub fn hashtag_class(&self) -> String {
-        self.hashtag_class.clone()
+        self.options.hashtag_class.clone()
     }
 
     #[wasm_bindgen(setter, js_name = "hashtagClass")]
     pub fn set_hashtag_class(&mut self, hashtag_class: String) {
-        self.hashtag_class = hashtag_class;
+        self.options.hashtag_class = hashtag_class;
     }
 
     #[wasm_bindgen(getter, js_name = "cashtagClass")]
     pub fn cashtag_class(&self) -> String {
-        self.cashtag_class.clone()
+        self.options.cashtag_class.clone()
     }
 
     #[wasm_bindgen(setter, js_name = "cashtagClass")]
     pub fn set_cashtag_class(&mut self, cashtag_class: String) {
-        self.cashtag_class = cashtag_class;
+        self.options.cashtag_class = cashtag_class;
     }
 
     #[wasm_bindgen(getter, js_name = "usernameUrlBase")]
     pub fn username_url_base(&self) -> String {
-        self.username_url_base.clone()
+        self.options.username_url_base.clone()
     }
 
     #[wasm_bindgen(setter, js_name = "usernameUrlBase")]
     pub fn set_username_url_base(&mut self, base: String) {
-        self.username_url_base = base;
+        self.options.username_url_base = base;
     }
 
     #[wasm_bindgen(getter, js_name = "listUrlBase")]
     pub fn list_url_base(&self) -> String {
-        self.list_url_base.clone()
+        self.options.list_url_base.clone()
     }
 
     #[wasm_bindgen(setter, js_name = "listUrlBase")]
     pub fn set_list_url_base(&mut self, base: String) {
-        self.list_url_base = base;
+        self.options.list_url_base = base;
     }
 
     #[wasm_bindgen(getter, js_name = "hashtagUrlBase")]
     pub fn hashtag_url_base(&self) -> String {
-        self.hashtag_url_base.clone()
+        self.options.hashtag_url_base.clone()
     }
 
     #[wasm_bindgen(setter, js_name = "hashtagUrlBase")]
     pub fn set_hashtag_url_base(&mut self, base: String) {
-        self.hashtag_url_base = base;
+        self.options.hashtag_url_base = base;
     }
 
     #[wasm_bindgen(getter, js_name = "cashtagUrlBase")]
     pub fn cashtag_url_base(&self) -> String {
-        self.cashtag_url_base.clone()
+        self.options.cashtag_url_base.clone()
     }
 
     #[wasm_bindgen(setter, js_name = "cashtagUrlBase")]
     pub fn set_cashtag_url_base(&mut self, base: String) {
-        self.cashtag_url_base = base;
+        self.options.cashtag_url_base = base;
+    }
+
+    #[wasm_bindgen(getter, js_name = "federatedMentionClass")]
+    pub fn federated_mention_class(&self) -> String {
+        self.options.federated_mention_class.clone()
+    }
+
+    #[wasm_bindgen(setter, js_name = "federatedMentionClass")]
+    pub fn set_federated_mention_class(&mut self, class: String) {
+        self.options.federated_mention_class = class;
+    }
+
+    #[wasm_bindgen(getter, js_name = "federatedMentionUrlTemplate")]
+    pub fn federated_mention_url_template(&self) -> String {
+        self.options.federated_mention_url_template.clone()
+    }
+
+    #[wasm_bindgen(setter, js_name = "federatedMentionUrlTemplate")]
+    pub fn set_federated_mention_url_template(&mut self, template: String) {
+        self.options.federated_mention_url_template = template;
+    }
+
+    #[wasm_bindgen(getter, js_name = "federatedMentionHCard")]
+    pub fn federated_mention_h_card(&self) -> bool {
+        self.options.federated_mention_h_card
+    }
+
+    #[wasm_bindgen(setter, js_name = "federatedMentionHCard")]
+    pub fn set_federated_mention_h_card(&mut self, h_card: bool) {
+        self.options.federated_mention_h_card = h_card;
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn strict(&self) -> bool {
+        self.options.strict
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_strict(&mut self, strict: bool) {
+        self.options.strict = strict;
+    }
+
+    /// "none", "image" or "span".
+    #[wasm_bindgen(getter, js_name = "emojiRendering")]
+    pub fn emoji_rendering(&self) -> String {
+        self.options.emoji_rendering.name().to_string()
+    }
+
+    #[wasm_bindgen(setter, js_name = "emojiRendering")]
+    pub fn set_emoji_rendering(&mut self, rendering: String) -> Result<(), JsValue> {
+        self.options.emoji_rendering = EmojiRendering::from_name(&rendering)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown emoji rendering: {}", rendering)))?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(getter, js_name = "emojiClass")]
+    pub fn emoji_class(&self) -> String {
+        self.options.emoji_class.clone()
+    }
+
+    #[wasm_bindgen(setter, js_name = "emojiClass")]
+    pub fn set_emoji_class(&mut self, class: String) {
+        self.options.emoji_class = class;
+    }
+
+    #[wasm_bindgen(getter, js_name = "emojiImageUrlTemplate")]
+    pub fn emoji_image_url_template(&self) -> String {
+        self.options.emoji_image_url_template.clone()
+    }
+
+    #[wasm_bindgen(setter, js_name = "emojiImageUrlTemplate")]
+    pub fn set_emoji_image_url_template(&mut self, template: String) {
+        self.options.emoji_image_url_template = template;
     }
 
     #[wasm_bindgen(getter, js_name = "usernameIncludeSymbol")]
     pub fn username_include_symbol(&self) -> bool {
-        self.username_include_symbol
+        self.options.username_include_symbol
     }
 
     #[wasm_bindgen(setter, js_name = "usernameIncludeSymbol")]
     pub fn set_username_include_symbol(&mut self, include: bool) {
-        self.username_include_symbol = include;
+        self.options.username_include_symbol = include;
     }
 
     #[wasm_bindgen(getter, js_name = "includeDataScreenName")]
     pub fn include_data_screen_name(&self) -> bool {
-        self.include_data_screen_name
+        self.options.include_data_screen_name
     }
 
     #[wasm_bindgen(setter, js_name = "includeDataScreenName")]
     pub fn set_include_data_screen_name(&mut self, include: bool) {
-        self.include_data_screen_name = include;
+        self.options.include_data_screen_name = include;
     }
 
     pub fn autolink(&self, text: &str) -> String {
@@ -203,34 +262,227 @@ impl Autolinker {
     pub fn autolink_cashtags(&self, text: &str) -> String {
         self.to_rust_autolinker().autolink_cashtags(text)
     }
+
+    #[wasm_bindgen(js_name = "autolinkFederated")]
+    pub fn autolink_federated(&self, text: &str) -> String {
+        self.to_rust_autolinker().autolink_federated(text)
+    }
+
+    /// Split the text into an array of Segment objects.
+    pub fn segments(&self, text: &str) -> Array {
+        self.to_rust_autolinker()
+            .segments(text)
+            .iter()
+            .map(|s| JsValue::from(Segment::from(s)))
+            .collect()
+    }
+}
+
+/// A piece of auto-linked text. `kind` is "text", "hashtag", "cashtag",
+/// "mention", "list", "federatedMention" or "url"; fields that don't apply to a kind are undefined.
+#[wasm_bindgen]
+#[derive(Clone, Default)]
+pub struct Segment {
+    kind: String,
+    text: String,
+    start: i32,
+    end: i32,
+    href: Option<String>,
+    value: Option<String>,
+    symbol: Option<String>,
+    list_slug: Option<String>,
+    domain: Option<String>,
+    include_symbol: Option<bool>,
+    rtl: Option<bool>,
+    display: Option<String>,
+    expanded: Option<String>,
+    display_before: Option<String>,
+    display_after: Option<String>,
+    leading_ellipsis: Option<bool>,
+    trailing_ellipsis: Option<bool>,
+}
+
+#[wasm_bindgen]
+impl Segment {
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> String {
+        self.kind.clone()
+    }
+
+    /// The source text of the segment.
+    #[wasm_bindgen(getter)]
+    pub fn text(&self) -> String {
+        self.text.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn start(&self) -> i32 {
+        self.start
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn end(&self) -> i32 {
+        self.end
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn href(&self) -> Option<String> {
+        self.href.clone()
+    }
+
+    /// The tag, screen name, federated user or URL, without its symbol.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> Option<String> {
+        self.value.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn symbol(&self) -> Option<String> {
+        self.symbol.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = "listSlug")]
+    pub fn list_slug(&self) -> Option<String> {
+        self.list_slug.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn domain(&self) -> Option<String> {
+        self.domain.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = "includeSymbol")]
+    pub fn include_symbol(&self) -> Option<bool> {
+        self.include_symbol
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rtl(&self) -> Option<bool> {
+        self.rtl
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn display(&self) -> Option<String> {
+        self.display.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn expanded(&self) -> Option<String> {
+        self.expanded.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = "displayBefore")]
+    pub fn display_before(&self) -> Option<String> {
+        self.display_before.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = "displayAfter")]
+    pub fn display_after(&self) -> Option<String> {
+        self.display_after.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = "leadingEllipsis")]
+    pub fn leading_ellipsis(&self) -> Option<bool> {
+        self.leading_ellipsis
+    }
+
+    #[wasm_bindgen(getter, js_name = "trailingEllipsis")]
+    pub fn trailing_ellipsis(&self) -> Option<bool> {
+        self.trailing_ellipsis
+    }
+}
+
+impl From<&RustSegment> for Segment {
+    fn from(segment: &RustSegment) -> Self {
+        let base = Segment {
+            text: segment.text().to_string(),
+            start: segment.start(),
+            end: segment.end(),
+            href: segment.href().map(str::to_string),
+            ..Segment::default()
+        };
+        match segment {
+            RustSegment::Text { .. } => Segment {
+                kind: "text".to_string(),
+                ..base
+            },
+            RustSegment::Hashtag {
+                symbol, tag, rtl, ..
+            } => Segment {
+                kind: "hashtag".to_string(),
+                value: Some(tag.clone()),
+                symbol: Some(symbol.to_string()),
+                rtl: Some(*rtl),
+                ..base
+            },
+            RustSegment::Cashtag { tag, .. } => Segment {
+                kind: "cashtag".to_string(),
+                value: Some(tag.clone()),
+                symbol: Some("$".to_string()),
+                ..base
+            },
+            RustSegment::Mention {
+                symbol,
+                screen_name,
+                include_symbol,
+                ..
+            } => Segment {
+                kind: "mention".to_string(),
+                value: Some(screen_name.clone()),
+                symbol: Some(symbol.to_string()),
+                include_symbol: Some(*include_symbol),
+                ..base
+            },
+            RustSegment::List {
+                symbol,
+                screen_name,
+                list_slug,
+                include_symbol,
+                ..
+            } => Segment {
+                kind: "list".to_string(),
+                value: Some(screen_name.clone()),
+                symbol: Some(symbol.to_string()),
+                list_slug: Some(list_slug.clone()),
+                include_symbol: Some(*include_symbol),
+                ..base
+            },
+            RustSegment::FederatedMention {
+                symbol,
+                user,
+                domain,
+                ..
+            } => Segment {
+                kind: "federatedMention".to_string(),
+                value: Some(user.clone()),
+                symbol: Some(symbol.to_string()),
+                domain: Some(domain.clone()),
+                ..base
+            },
+            RustSegment::Url {
+                href,
+                display,
+                expanded,
+                display_parts,
+                ..
+            } => Segment {
+                kind: "url".to_string(),
+                value: Some(href.clone()),
+                display: Some(display.clone()),
+                expanded: Some(expanded.clone()),
+                display_before: display_parts.as_ref().map(|p| p.before.clone()),
+                display_after: display_parts.as_ref().map(|p| p.after.clone()),
+                leading_ellipsis: display_parts.as_ref().map(|p| p.leading_ellipsis),
+                trailing_ellipsis: display_parts.as_ref().map(|p| p.trailing_ellipsis),
+                ..base
+            },
+        }
+    }
 }
 
 impl Autolinker {
     fn to_rust_autolinker(&self) -> RustAutolinker<'_> {
-        let mut extractor = Extractor::new();
-        extractor.set_extract_url_without_protocol(false);
-
-        RustAutolinker {
-            no_follow: self.no_follow,
-            url_class: &self.url_class,
-            url_target: &self.url_target,
-            symbol_tag: &self.symbol_tag,
-            text_with_symbol_tag: &self.text_with_symbol_tag,
-            list_class: &self.list_class,
-            username_class: &self.username_class,
-            hashtag_class: &self.hashtag_class,
-            cashtag_class: &self.cashtag_class,
-            username_url_base: &self.username_url_base,
-            list_url_base: &self.list_url_base,
-            hashtag_url_base: &self.hashtag_url_base,
-            cashtag_url_base: &self.cashtag_url_base,
-            invisible_tag_attrs: &self.invisible_tag_attrs,
-            username_include_symbol: self.username_include_symbol,
-            include_data_screen_name: self.include_data_screen_name,
-            extractor,
-            link_attribute_modifier: None,
-            link_text_modifier: None,
-        }
+        self.options.autolinker()
     }
 }
 
@@ -492,5 +492,93 @@ describe("Tweet Parser Tests", () => {
   });
 });
 
+describe("Autolinker Options Tests", () => {
+  test("builds an autolinker from JSON", () => {
+    const autolinker = wasm.Autolinker.fromJson(
+      '{"noFollow": true, "hashtagUrlTemplate": "https://x.com/hashtag/{tag}"}',
+    );
+    assert.strictEqual(autolinker.noFollow, true);
+    assert.strictEqual(
+      autolinker.autolinkHashtags("#日本"),
+      '<a href="https://x.com/hashtag/%E6%97%A5%E6%9C%AC" title="#日本" class="tweet-url hashtag" rel="nofollow">#日本</a>',
+    );
+    assert.ok(JSON.parse(autolinker.toJson()).noFollow);
+    assert.throws(() => wasm.Autolinker.fromJson('{"noFolow": true}'));
+    autolinker.free();
+  });
+});
+
+describe("Autolinker Strict Mode Tests", () => {
+  test("escapes all text", () => {
+    const autolinker = new wasm.Autolinker();
+    assert.strictEqual(autolinker.strict, false);
+    autolinker.strict = true;
+    assert.ok(
+      autolinker
+        .autolink('"Tom & Jerry" #tag')
+        .startsWith("&quot;Tom &amp; Jerry&quot; <a "),
+    );
+    autolinker.free();
+  });
+});
+
+describe("Autolinker Emoji Tests", () => {
+  test("wraps emoji in spans or images", () => {
+    const autolinker = new wasm.Autolinker();
+    assert.strictEqual(autolinker.emojiRendering, "none");
+    autolinker.emojiRendering = "span";
+    assert.strictEqual(
+      autolinker.autolink("hi \u{1F600}"),
+      'hi <span class="emoji">\u{1F600}</span>',
+    );
+    autolinker.emojiRendering = "image";
+    autolinker.emojiImageUrlTemplate = "/emoji/{codepoints}.png";
+    const result = autolinker.autolink("\u2764\uFE0F");
+    assert.ok(result.includes('src="/emoji/2764.png"'));
+    assert.ok(result.includes('alt="\u2764\uFE0F"'));
+    assert.throws(() => {
+      autolinker.emojiRendering = "svg";
+    });
+    autolinker.free();
+  });
+});
+
+describe("Autolinker Federated Mention Tests", () => {
+  test("links federated mentions", () => {
+    const autolinker = new wasm.Autolinker();
+    assert.strictEqual(
+      autolinker.autolinkFederated("hi @alice@example.social"),
+      'hi <a class="tweet-url federated-mention" href="https://example.social/@alice">@alice@example.social</a>',
+    );
+    autolinker.federatedMentionUrlTemplate = "https://{domain}/users/{user}";
+    autolinker.federatedMentionHCard = true;
+    const html = autolinker.autolinkFederated("@alice@example.social");
+    assert.ok(html.startsWith('<span class="h-card">'));
+    assert.ok(html.includes('href="https://example.social/users/alice"'));
+    autolinker.free();
+  });
+});
+
+describe("Autolinker Segment Tests", () => {
+  test("segments split text and links", () => {
+    const autolinker = new wasm.Autolinker();
+    const segments = autolinker.segments("hi @user #tag https://example.com");
+    assert.deepStrictEqual(
+      segments.map((s) => s.kind),
+      ["text", "mention", "text", "hashtag", "text", "url"],
+    );
+    assert.strictEqual(segments[1].value, "user");
+    assert.strictEqual(segments[1].href, "https://twitter.com/user");
+    assert.strictEqual(segments[1].start, 3);
+    assert.strictEqual(segments[1].end, 8);
+    assert.strictEqual(segments[3].symbol, "#");
+    assert.strictEqual(segments[5].display, "https://example.com");
+    assert.strictEqual(segments[0].href, undefined);
+    assert.strictEqual(segments.map((s) => s.text).join(""), "hi @user #tag https://example.com");
+    segments.forEach((s) => s.free());
+    autolinker.free();
+  });
+});
+
 console.log("\nTwitter Text WASM Conformance Tests");
 console.log("====================================\n");
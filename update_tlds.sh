@@ -6,7 +6,7 @@
 # Regenerate TLD files from tld_lib.yml.
 # Requires: Python 3, PyYAML
 #
-# By default, only regenerates tlds.rs (the PHF hash set used at runtime).
+# By default, only regenerates tlds.rs (the PHF hash map used at runtime).
 # The Pest grammar uses character-class-based TLD matching and does not
 # need regeneration when TLDs change.
 #
@@ -51,7 +51,7 @@ if [ "$UPDATE_PEST" = true ]; then
         "$PEST_FILE"
 fi
 
-echo "==> Generating tlds.rs PHF hash set..."
+echo "==> Generating tlds.rs PHF hash map..."
 python3 "$TLD_GEN_PHF" \
     --input "$TLD_YAML" \
     --output "$TLDS_RS"